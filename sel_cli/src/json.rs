@@ -0,0 +1,223 @@
+use sel_common::sel_types::associative_list::AssociativeList;
+use sel_common::sel_types::list::List;
+use sel_common::sel_types::pair::Pair;
+use sel_common::sel_types::range::Range;
+use sel_common::sel_types::symbol::Symbol;
+use sel_common::{from_value_data, DataType, SELContext, SELValue};
+use serde_json::{Map, Number, Value};
+
+// object keys are added to the context's symbol table
+// so they match symbols in source compiled with the same context
+pub fn json_to_sel_value(json: &Value, context: &mut SELContext) -> SELValue {
+    return match json {
+        Value::Null => SELValue::new(),
+        Value::Bool(b) => SELValue::new_from_boolean(*b),
+        Value::Number(number) => match number.as_i64() {
+            Some(integer) => SELValue::new_from_int(integer),
+            None => SELValue::new_from_decimal(number.as_f64().unwrap_or(0.0)),
+        },
+        Value::String(s) => SELValue::new_from_string(s),
+        Value::Array(items) => {
+            let mut list = List::new();
+
+            for item in items {
+                list.push(json_to_sel_value(item, context));
+            }
+
+            SELValue::new_from_list(list)
+        }
+        Value::Object(fields) => {
+            let mut list = AssociativeList::new();
+
+            for (key, field) in fields {
+                let symbol = Symbol::new(key.clone(), context.add_symbol(key));
+
+                list.push(SELValue::new_from_pair(Pair::new(
+                    SELValue::new_from_symbol(symbol),
+                    json_to_sel_value(field, context),
+                )));
+            }
+
+            SELValue::new_from_associative_list(list)
+        }
+    };
+}
+
+fn symbol_key(value: &SELValue) -> Option<String> {
+    if value.get_type() != DataType::Symbol {
+        return None;
+    }
+
+    let symbol: Symbol = from_value_data(value.get_value().unwrap());
+
+    return Some(symbol.get_identifier().clone());
+}
+
+// pairs with a symbol on the left become single field objects
+fn pair_to_json(pair: &Pair) -> Value {
+    return match symbol_key(pair.get_left()) {
+        Some(key) => {
+            let mut object = Map::new();
+            object.insert(key, sel_value_to_json(pair.get_right()));
+
+            Value::Object(object)
+        }
+        None => Value::Array(vec![
+            sel_value_to_json(pair.get_left()),
+            sel_value_to_json(pair.get_right()),
+        ]),
+    };
+}
+
+// associative lists become objects when every item has a symbol key
+fn associative_list_to_json(list: &AssociativeList) -> Value {
+    let mut object = Map::new();
+
+    for item in list.get_list().get_values() {
+        if item.get_type() != DataType::Pair {
+            return list_to_json(list.get_list());
+        }
+
+        let pair: Pair = from_value_data(item.get_value().unwrap());
+
+        match symbol_key(pair.get_left()) {
+            Some(key) => {
+                object.insert(key, sel_value_to_json(pair.get_right()));
+            }
+            None => return list_to_json(list.get_list()),
+        }
+    }
+
+    return Value::Object(object);
+}
+
+fn list_to_json(list: &List) -> Value {
+    return Value::Array(list.get_values().iter().map(sel_value_to_json).collect());
+}
+
+pub fn sel_value_to_json(value: &SELValue) -> Value {
+    let data = match value.get_value() {
+        Some(data) => data,
+        None => return Value::Null,
+    };
+
+    return match value.get_type() {
+        DataType::Integer => Value::from(from_value_data::<i64>(data)),
+        // NaN and infinity have no json representation
+        DataType::Decimal => {
+            Number::from_f64(from_value_data::<f64>(data)).map_or(Value::Null, Value::Number)
+        }
+        DataType::Boolean => Value::Bool(from_value_data(data)),
+        DataType::String => Value::String(from_value_data(data)),
+        DataType::Symbol => Value::String(symbol_key(value).unwrap_or_default()),
+        DataType::Range => {
+            let range: Range = from_value_data(data);
+
+            Value::Array(vec![
+                Value::from(range.get_lower()),
+                Value::from(range.get_upper()),
+            ])
+        }
+        DataType::Pair => pair_to_json(&from_value_data(data)),
+        DataType::List => list_to_json(&from_value_data(data)),
+        DataType::AssociativeList => associative_list_to_json(&from_value_data(data)),
+        DataType::Error => {
+            let error = value.get_error().unwrap();
+
+            let mut details = Map::new();
+            details.insert(
+                String::from("kind"),
+                Value::String(format!("{}", error.get_kind())),
+            );
+            details.insert(
+                String::from("message"),
+                Value::String(error.get_message().clone()),
+            );
+
+            let mut object = Map::new();
+            object.insert(String::from("error"), Value::Object(details));
+
+            Value::Object(object)
+        }
+        // expressions, streams and partial applications have no data representation
+        _ => Value::String(format!("{}", value)),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn converts_scalars() {
+        let mut context = SELContext::new();
+
+        assert_eq!(
+            json_to_sel_value(&json!(10), &mut context),
+            SELValue::new_from_int(10)
+        );
+        assert_eq!(
+            json_to_sel_value(&json!(2.5), &mut context),
+            SELValue::new_from_decimal(2.5)
+        );
+        assert_eq!(
+            json_to_sel_value(&json!(true), &mut context),
+            SELValue::new_from_boolean(true)
+        );
+        assert_eq!(
+            json_to_sel_value(&json!("panda"), &mut context),
+            SELValue::new_from_string(&String::from("panda"))
+        );
+        assert_eq!(
+            json_to_sel_value(&json!(null), &mut context),
+            SELValue::new()
+        );
+    }
+
+    #[test]
+    fn converts_objects_with_symbol_keys() {
+        let mut context = SELContext::new();
+
+        let value = json_to_sel_value(&json!({"name": "Panda"}), &mut context);
+        let list: AssociativeList = from_value_data(value.get_value().unwrap());
+
+        let index = *context
+            .get_symbol_table()
+            .get_value(&String::from("name"))
+            .unwrap();
+
+        assert_eq!(value.get_type(), DataType::AssociativeList);
+        assert_eq!(
+            list.get_by_association_index(index),
+            Some(SELValue::new_from_string(&String::from("Panda")))
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let json = json!({"name": "Panda", "scores": [1, 2.5, false, null], "inner": {"a": []}});
+
+        let mut context = SELContext::new();
+
+        assert_eq!(
+            sel_value_to_json(&json_to_sel_value(&json, &mut context)),
+            json
+        );
+    }
+
+    #[test]
+    fn pairs_and_ranges() {
+        let mut list = List::new();
+        list.push(SELValue::new_from_range(1, 5));
+        list.push(SELValue::new_from_pair(Pair::new(
+            SELValue::new_from_int(1),
+            SELValue::new_from_int(2),
+        )));
+
+        assert_eq!(
+            sel_value_to_json(&SELValue::new_from_list(list)),
+            json!([[1, 5], [1, 2]])
+        );
+    }
+}
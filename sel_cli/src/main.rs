@@ -0,0 +1,56 @@
+mod json;
+mod repl;
+mod run;
+mod test_runner;
+
+use sel_common::SELContext;
+use std::env;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(|arg| arg.as_str()) {
+        None => repl::run(SELContext::new()),
+        Some("run") => match run::parse_run_args(&args[1..]) {
+            Ok(options) => {
+                let output = run::run(&options);
+
+                match output.get_code() {
+                    run::EXIT_SUCCESS | run::EXIT_RUNTIME_ERROR => {
+                        println!("{}", output.get_output())
+                    }
+                    _ => eprintln!("{}", output.get_output()),
+                }
+
+                process::exit(output.get_code());
+            }
+            Err(message) => {
+                eprintln!("{}\n{}", message, run::USAGE);
+                process::exit(run::EXIT_INVALID_ARGUMENTS);
+            }
+        },
+        Some("test") => match &args[1..] {
+            [file] => {
+                let output = test_runner::test_file(file);
+
+                match output.get_code() {
+                    run::EXIT_SUCCESS | run::EXIT_RUNTIME_ERROR => {
+                        println!("{}", output.get_output())
+                    }
+                    _ => eprintln!("{}", output.get_output()),
+                }
+
+                process::exit(output.get_code());
+            }
+            _ => {
+                eprintln!("{}", run::USAGE);
+                process::exit(run::EXIT_INVALID_ARGUMENTS);
+            }
+        },
+        Some(_) => {
+            eprintln!("{}", run::USAGE);
+            process::exit(run::EXIT_INVALID_ARGUMENTS);
+        }
+    }
+}
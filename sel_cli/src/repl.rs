@@ -0,0 +1,436 @@
+use sel_common::{SELContext, SELTree};
+use sel_compiler::{CompileErrorKind, Compiler};
+use sel_executor::{execute_sel_tree, SELExecutionContext};
+use sel_tokenizer::Tokenizer;
+use std::fs;
+use std::io::{self, BufRead, Write};
+
+const PROMPT: &str = "sel> ";
+const CONTINUE_PROMPT: &str = "...> ";
+
+const HELP: &str = "\
+:tree [source]    dump nodes of source or the last entry
+:tokens [source]  list tokens of source or the last entry
+:input <value>    evaluate value and use it as $
+:load <file>      run a file as an entry
+:reset            clear results, input and symbols
+:help             show this message
+:quit             exit";
+
+// entry is incomplete when a group, list or block is left open
+fn is_unclosed(kind: CompileErrorKind) -> bool {
+    return matches!(
+        kind,
+        CompileErrorKind::UnclosedGroup
+            | CompileErrorKind::UnclosedAssociativeList
+            | CompileErrorKind::UnclosedExpressionBlock
+    );
+}
+
+pub struct Repl {
+    compiler: Compiler,
+    // context given at start, restored on reset
+    initial_context: SELContext,
+    // symbols added by entries are kept so their indexes stay valid
+    context: SELContext,
+    execution_context: SELExecutionContext,
+    pending: String,
+    last_source: Option<String>,
+    last_tree: Option<SELTree>,
+    done: bool,
+}
+
+impl Repl {
+    pub fn new(context: SELContext) -> Self {
+        return Repl {
+            compiler: Compiler::new(),
+            execution_context: SELExecutionContext::from(&context),
+            initial_context: context.clone(),
+            context,
+            pending: String::new(),
+            last_source: None,
+            last_tree: None,
+            done: false,
+        };
+    }
+
+    pub fn is_continuing(&self) -> bool {
+        return !self.pending.is_empty();
+    }
+
+    pub fn is_done(&self) -> bool {
+        return self.done;
+    }
+
+    pub fn get_prompt(&self) -> &str {
+        return if self.is_continuing() {
+            CONTINUE_PROMPT
+        } else {
+            PROMPT
+        };
+    }
+
+    // handles one line of input
+    // returns lines to print
+    pub fn handle_line(&mut self, line: &str) -> Vec<String> {
+        if !self.is_continuing() {
+            let trimmed = line.trim();
+
+            if trimmed.is_empty() {
+                return vec![];
+            }
+
+            if let Some(output) = self.handle_command(trimmed) {
+                return output;
+            }
+        }
+
+        self.pending.push_str(line);
+        self.pending.push('\n');
+
+        let source = self.pending.clone();
+
+        return match self.compiler.try_compile(&source) {
+            Err(errors) if errors.iter().any(|error| is_unclosed(error.get_kind())) => vec![],
+            _ => {
+                self.pending.clear();
+                self.run_entry(&source)
+            }
+        };
+    }
+
+    // None if line is not a meta command
+    // other lines starting with ':' are symbols
+    fn handle_command(&mut self, command: &str) -> Option<Vec<String>> {
+        let (name, argument) = match command.find(char::is_whitespace) {
+            Some(index) => (&command[..index], command[index..].trim()),
+            None => (command, ""),
+        };
+
+        let output = match name {
+            ":tree" => self.dump_tree(argument),
+            ":tokens" => self.dump_tokens(argument),
+            ":input" => self.set_input(argument),
+            ":load" => match fs::read_to_string(argument) {
+                Ok(source) => self.run_entry(&source),
+                Err(error) => vec![format!("could not read {}: {}", argument, error)],
+            },
+            ":reset" => {
+                self.context = self.initial_context.clone();
+                self.execution_context = SELExecutionContext::from(&self.initial_context);
+                self.last_source = None;
+                self.last_tree = None;
+
+                vec![String::from("reset")]
+            }
+            ":help" => HELP.lines().map(String::from).collect(),
+            ":quit" => {
+                self.done = true;
+
+                vec![]
+            }
+            _ => return None,
+        };
+
+        return Some(output);
+    }
+
+    fn compile(&mut self, source: &str) -> Result<SELTree, Vec<String>> {
+        return match self
+            .compiler
+            .try_compile_with_context(source, self.context.clone())
+        {
+            Ok(tree) => {
+                self.context = tree.get_context().clone();
+
+                Ok(tree)
+            }
+            Err(errors) => Err(errors
+                .iter()
+                .map(|error| format!("error {}", error))
+                .collect()),
+        };
+    }
+
+    // runs source and keeps its results as current results for later entries
+    fn run_entry(&mut self, source: &str) -> Vec<String> {
+        let tree = match self.compile(source) {
+            Ok(tree) => tree,
+            Err(errors) => return errors,
+        };
+
+        let previous_count = self.execution_context.get_results().len();
+        let results = execute_sel_tree(&tree, &self.execution_context);
+
+        let mut output: Vec<String> = vec![];
+
+        for result in results.into_iter().skip(previous_count) {
+            output.push(format!("{}", result.get_sel_value()));
+
+            self.execution_context.push_result(result);
+        }
+
+        self.last_source = Some(String::from(source.trim_end()));
+        self.last_tree = Some(tree);
+
+        return output;
+    }
+
+    fn set_input(&mut self, source: &str) -> Vec<String> {
+        if source.is_empty() {
+            return vec![String::from("usage :input <value>")];
+        }
+
+        let tree = match self.compile(source) {
+            Ok(tree) => tree,
+            Err(errors) => return errors,
+        };
+
+        let input = match execute_sel_tree(&tree, &self.execution_context).last() {
+            Some(result) => result.get_sel_value().clone(),
+            None => return vec![],
+        };
+
+        let output = vec![format!("input {}", input)];
+
+        self.execution_context.set_input(input);
+
+        return output;
+    }
+
+    fn source_or_last(&self, source: &str) -> Option<String> {
+        return if source.is_empty() {
+            self.last_source.clone()
+        } else {
+            Some(String::from(source))
+        };
+    }
+
+    fn dump_tokens(&self, source: &str) -> Vec<String> {
+        let source = match self.source_or_last(source) {
+            Some(source) => source,
+            None => return vec![String::from("no entry to tokenize")],
+        };
+
+        return Tokenizer::new(&source)
+            .map(|token| {
+                format!(
+                    "{:?} {:?} {}",
+                    token.get_token_type(),
+                    token.get_token_str(),
+                    token.get_span()
+                )
+            })
+            .collect();
+    }
+
+    fn dump_tree(&mut self, source: &str) -> Vec<String> {
+        let tree = if source.is_empty() {
+            match &self.last_tree {
+                Some(tree) => tree,
+                None => return vec![String::from("no entry to show")],
+            }
+        } else {
+            match self
+                .compiler
+                .try_compile_with_context(source, self.context.clone())
+            {
+                Ok(tree) => {
+                    self.last_tree = Some(tree);
+                    self.last_tree.as_ref().unwrap()
+                }
+                Err(errors) => {
+                    return errors
+                        .iter()
+                        .map(|error| format!("error {}", error))
+                        .collect()
+                }
+            }
+        };
+
+        let mut roots = vec![];
+        if !tree.get_nodes().is_empty() {
+            roots.push(tree.get_root().get_own_index());
+            roots.extend(tree.get_sub_roots());
+        }
+
+        return tree
+            .get_nodes()
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                format!(
+                    "{}{} {:?} {:?} left {:?} right {:?} value {:?}",
+                    if roots.contains(&index) { "*" } else { " " },
+                    index,
+                    node.get_operation(),
+                    node.get_data_type(),
+                    node.get_left(),
+                    node.get_right(),
+                    tree.get_value_data_of(node)
+                )
+            })
+            .collect();
+    }
+}
+
+// reads entries from stdin until end of input or :quit
+pub fn run(context: SELContext) {
+    let mut repl = Repl::new(context);
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    while !repl.is_done() {
+        print!("{}", repl.get_prompt());
+        io::stdout().flush().unwrap();
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            _ => break,
+        };
+
+        for output in repl.handle_line(&line) {
+            println!("{}", output);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn entered(repl: &mut Repl, lines: &[&str]) -> Vec<String> {
+        let mut output = vec![];
+
+        for line in lines {
+            output.extend(repl.handle_line(line));
+        }
+
+        return output;
+    }
+
+    #[test]
+    fn prints_results() {
+        let mut repl = Repl::new(SELContext::new());
+
+        assert_eq!(
+            entered(&mut repl, &["5 + 10\n'a' + 'b'\n[:name = 'Panda']"]),
+            vec!["15", "\"ab\"", "[ :name = \"Panda\" ]"]
+        );
+    }
+
+    #[test]
+    fn keeps_results_across_entries() {
+        let mut repl = Repl::new(SELContext::new());
+
+        assert_eq!(
+            entered(&mut repl, &["5 + 10", "? * 2", "? + 1"]),
+            vec!["15", "30", "31"]
+        );
+    }
+
+    #[test]
+    fn continues_unclosed_entries() {
+        let mut repl = Repl::new(SELContext::new());
+
+        assert!(repl.handle_line("(5 +").is_empty());
+        assert!(repl.is_continuing());
+        assert_eq!(repl.get_prompt(), CONTINUE_PROMPT);
+
+        assert!(repl.handle_line("[1,").is_empty());
+
+        assert_eq!(repl.handle_line("2].1)"), vec!["7"]);
+        assert!(!repl.is_continuing());
+    }
+
+    #[test]
+    fn continues_blocks() {
+        let mut repl = Repl::new(SELContext::new());
+
+        assert_eq!(entered(&mut repl, &["10 -> {", "  $ * 2", "}"]), vec!["20"]);
+    }
+
+    #[test]
+    fn reports_compile_errors() {
+        let mut repl = Repl::new(SELContext::new());
+
+        let output = repl.handle_line("5 +");
+
+        assert_eq!(output.len(), 1);
+        assert!(output[0].starts_with("error"));
+        assert!(!repl.is_continuing());
+    }
+
+    #[test]
+    fn sets_input() {
+        let mut repl = Repl::new(SELContext::new());
+
+        assert_eq!(
+            entered(&mut repl, &[":input 5 * 2", "$ + 1"]),
+            vec!["input 10", "11"]
+        );
+    }
+
+    #[test]
+    fn resets() {
+        let mut repl = Repl::new(SELContext::new());
+
+        assert_eq!(
+            entered(&mut repl, &[":input 5", "10", ":reset", "?"]),
+            vec!["input 5", "10", "reset", "()"]
+        );
+    }
+
+    #[test]
+    fn lists_tokens() {
+        let mut repl = Repl::new(SELContext::new());
+
+        let output = entered(&mut repl, &["5 + 10", ":tokens"]);
+
+        assert_eq!(output.len(), 4);
+        assert!(output[1].starts_with("Integer \"5\""));
+        assert!(output[2].starts_with("PlusSign \"+\""));
+    }
+
+    #[test]
+    fn dumps_tree() {
+        let mut repl = Repl::new(SELContext::new());
+
+        let output = repl.handle_line(":tree 5 + 10");
+
+        assert_eq!(output.len(), 3);
+        assert!(output[1].starts_with("*1 Addition"));
+    }
+
+    #[test]
+    fn loads_file() {
+        let path = env::temp_dir().join("sel_repl_load.sel");
+        fs::write(&path, "5 + 10\n? * 2").unwrap();
+
+        let mut repl = Repl::new(SELContext::new());
+
+        let output = repl.handle_line(&format!(":load {}", path.display()));
+
+        assert_eq!(output, vec!["15", "30"]);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn symbols_are_not_commands() {
+        let mut repl = Repl::new(SELContext::new());
+
+        assert_eq!(repl.handle_line(":other"), vec![":other"]);
+    }
+
+    #[test]
+    fn quits() {
+        let mut repl = Repl::new(SELContext::new());
+
+        repl.handle_line(":quit");
+
+        assert!(repl.is_done());
+    }
+}
@@ -0,0 +1,316 @@
+use crate::json::{json_to_sel_value, sel_value_to_json};
+use sel_common::SELContext;
+use sel_compiler::Compiler;
+use sel_executor::{execute_sel_tree, SELExecutionContext};
+use serde_json::Value;
+use std::fs;
+use std::io::{self, Read};
+
+pub const EXIT_SUCCESS: i32 = 0;
+// any result is an error, or any test failed
+pub const EXIT_RUNTIME_ERROR: i32 = 1;
+pub const EXIT_COMPILE_ERROR: i32 = 2;
+// bad arguments, unreadable file or invalid input
+pub const EXIT_INVALID_ARGUMENTS: i32 = 3;
+
+pub const USAGE: &str = "\
+usage: sel                                       start an interactive session
+       sel run <file> [--input <file>] [--last]  run a file and print results as json
+       sel test <file>                           run the file's @Test expressions
+
+  --input <file>  json to use as $, - reads from stdin
+  --last          only print the last result";
+
+#[derive(PartialEq, Debug)]
+pub struct RunOptions {
+    file: String,
+    input: Option<String>,
+    last: bool,
+}
+
+// arguments after `run`
+pub fn parse_run_args(args: &[String]) -> Result<RunOptions, String> {
+    let mut file: Option<String> = None;
+    let mut input: Option<String> = None;
+    let mut last = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => match args.next() {
+                Some(path) => input = Some(path.clone()),
+                None => return Err(String::from("--input requires a file")),
+            },
+            "--last" => last = true,
+            _ if arg.starts_with("--") => return Err(format!("unknown option {}", arg)),
+            _ if file.is_none() => file = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+
+    return match file {
+        Some(file) => Ok(RunOptions { file, input, last }),
+        None => Err(String::from("missing file to run")),
+    };
+}
+
+fn read_input(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut input = String::new();
+
+        return io::stdin()
+            .read_to_string(&mut input)
+            .map(|_| input)
+            .map_err(|error| format!("could not read stdin: {}", error));
+    }
+
+    return fs::read_to_string(path).map_err(|error| format!("could not read {}: {}", path, error));
+}
+
+pub struct RunOutput {
+    code: i32,
+    output: String,
+}
+
+impl RunOutput {
+    pub fn new(code: i32, output: String) -> Self {
+        return RunOutput { code, output };
+    }
+
+    pub fn get_code(&self) -> i32 {
+        return self.code;
+    }
+
+    // json for success and runtime errors, messages otherwise
+    pub fn get_output(&self) -> &String {
+        return &self.output;
+    }
+}
+
+// file names the source in compile errors
+pub fn run_source(file: &str, source: &str, input: Option<&Value>, last: bool) -> RunOutput {
+    let mut context = SELContext::new();
+
+    let input = input.map(|json| json_to_sel_value(json, &mut context));
+
+    let tree = match Compiler::new().try_compile_with_context(source, context) {
+        Ok(tree) => tree,
+        Err(errors) => {
+            return RunOutput {
+                code: EXIT_COMPILE_ERROR,
+                output: errors
+                    .iter()
+                    .map(|error| error.display_with_file(file))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            }
+        }
+    };
+
+    let mut execution_context = SELExecutionContext::from(tree.get_context());
+    if let Some(input) = input {
+        // input must match the file's @Input
+        if let Err(error) = tree.validate_input(&input) {
+            return RunOutput {
+                code: EXIT_INVALID_ARGUMENTS,
+                output: format!("invalid input: {}", error),
+            };
+        }
+
+        execution_context.set_input(input);
+    }
+
+    let mut results = execute_sel_tree(&tree, &execution_context);
+
+    if last {
+        results = results.pop().into_iter().collect();
+    }
+
+    let code = if results.iter().any(|result| result.is_error()) {
+        EXIT_RUNTIME_ERROR
+    } else {
+        EXIT_SUCCESS
+    };
+
+    let mut values: Vec<Value> = results
+        .iter()
+        .map(|result| sel_value_to_json(result.get_sel_value()))
+        .collect();
+
+    let json = if last {
+        values.pop().unwrap_or(Value::Null)
+    } else {
+        Value::Array(values)
+    };
+
+    return RunOutput {
+        code,
+        output: json.to_string(),
+    };
+}
+
+pub fn run(options: &RunOptions) -> RunOutput {
+    let invalid = |output: String| RunOutput {
+        code: EXIT_INVALID_ARGUMENTS,
+        output,
+    };
+
+    let source = match fs::read_to_string(&options.file) {
+        Ok(source) => source,
+        Err(error) => return invalid(format!("could not read {}: {}", options.file, error)),
+    };
+
+    let input = match &options.input {
+        Some(path) => match read_input(path).and_then(|text| {
+            serde_json::from_str::<Value>(&text)
+                .map_err(|error| format!("invalid json input: {}", error))
+        }) {
+            Ok(json) => Some(json),
+            Err(message) => return invalid(message),
+        },
+        None => None,
+    };
+
+    return run_source(&options.file, &source, input.as_ref(), options.last);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::env;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        return values.iter().map(|value| String::from(*value)).collect();
+    }
+
+    #[test]
+    fn parses_args() {
+        assert_eq!(
+            parse_run_args(&args(&["file.sel", "--input", "input.json", "--last"])),
+            Ok(RunOptions {
+                file: String::from("file.sel"),
+                input: Some(String::from("input.json")),
+                last: true,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_bad_args() {
+        assert!(parse_run_args(&args(&[])).is_err());
+        assert!(parse_run_args(&args(&["file.sel", "--input"])).is_err());
+        assert!(parse_run_args(&args(&["file.sel", "--other"])).is_err());
+        assert!(parse_run_args(&args(&["file.sel", "other.sel"])).is_err());
+    }
+
+    #[test]
+    fn prints_all_results() {
+        let output = run_source("main.sel", "5 + 10\n? * 2", None, false);
+
+        assert_eq!(output.get_code(), EXIT_SUCCESS);
+        assert_eq!(output.get_output(), "[15,30]");
+    }
+
+    #[test]
+    fn prints_last_result() {
+        let output = run_source("main.sel", "5 + 10\n? * 2", None, true);
+
+        assert_eq!(output.get_code(), EXIT_SUCCESS);
+        assert_eq!(output.get_output(), "30");
+    }
+
+    #[test]
+    fn pipes_into_named_expression() {
+        let output = run_source("main.sel", "#sum $.0 + $.1\n\n10 -> sum(5)", None, false);
+
+        assert_eq!(output.get_code(), EXIT_SUCCESS);
+        assert_eq!(
+            serde_json::from_str::<Value>(output.get_output()).unwrap(),
+            json!([15])
+        );
+    }
+
+    #[test]
+    fn uses_json_input() {
+        let input = json!({"user": {"name": "Panda"}, "scores": [10, 20]});
+
+        let output = run_source(
+            "main.sel",
+            "$.user.name\n$.scores.1 + 5",
+            Some(&input),
+            false,
+        );
+
+        assert_eq!(output.get_code(), EXIT_SUCCESS);
+        assert_eq!(output.get_output(), "[\"Panda\",25]");
+    }
+
+    #[test]
+    fn rejects_input_of_other_shape() {
+        let input = json!({"user": {"name": 5}});
+
+        let output = run_source(
+            "main.sel",
+            "@Input([user: [name: string]])\n$.user.name",
+            Some(&input),
+            false,
+        );
+
+        assert_eq!(output.get_code(), EXIT_INVALID_ARGUMENTS);
+        assert_eq!(
+            output.get_output(),
+            "invalid input: $.user.name: expected string, found Integer"
+        );
+    }
+
+    #[test]
+    fn runtime_error() {
+        let output = run_source("main.sel", "5 // 0", None, true);
+
+        assert_eq!(output.get_code(), EXIT_RUNTIME_ERROR);
+        assert_eq!(
+            serde_json::from_str::<Value>(output.get_output()).unwrap()["error"]["kind"],
+            json!("divide by zero")
+        );
+    }
+
+    #[test]
+    fn compile_error() {
+        let output = run_source("main.sel", "(5 + 10", None, false);
+
+        assert_eq!(output.get_code(), EXIT_COMPILE_ERROR);
+        assert_eq!(
+            output.get_output(),
+            "main.sel:1:1-1:2: '(' is never closed, expected ')'"
+        );
+    }
+
+    #[test]
+    fn runs_files() {
+        let directory = env::temp_dir();
+        let file = directory.join("sel_run_file.sel");
+        let input = directory.join("sel_run_input.json");
+
+        fs::write(&file, "$.value * 2").unwrap();
+        fs::write(&input, "{\"value\": 21}").unwrap();
+
+        let options = RunOptions {
+            file: file.display().to_string(),
+            input: Some(input.display().to_string()),
+            last: true,
+        };
+
+        let output = run(&options);
+
+        assert_eq!(output.get_code(), EXIT_SUCCESS);
+        assert_eq!(output.get_output(), "42");
+
+        fs::write(&input, "{").unwrap();
+
+        assert_eq!(run(&options).get_code(), EXIT_INVALID_ARGUMENTS);
+
+        fs::remove_file(file).unwrap();
+        fs::remove_file(input).unwrap();
+    }
+}
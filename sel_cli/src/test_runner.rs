@@ -0,0 +1,111 @@
+use crate::run::{
+    RunOutput, EXIT_COMPILE_ERROR, EXIT_INVALID_ARGUMENTS, EXIT_RUNTIME_ERROR, EXIT_SUCCESS,
+};
+use sel_compiler::Compiler;
+use sel_executor::{run_tests, SELExecutionContext, TestResult};
+use std::fs;
+
+// one line per test, i.e. `ok 4:1` or `FAILED 7:1 expected true, found false`
+fn report(result: &TestResult) -> String {
+    let location = result.get_span().get_start();
+
+    if result.is_passed() {
+        return format!("ok {}", location);
+    }
+
+    return match result.get_result().get_error() {
+        Some(error) => format!("FAILED {} {}", location, error),
+        None => format!(
+            "FAILED {} expected true, found {}",
+            location,
+            result.get_result()
+        ),
+    };
+}
+
+// file names the source in compile errors
+pub fn test_source(file: &str, source: &str) -> RunOutput {
+    let tree = match Compiler::new().try_compile(source) {
+        Ok(tree) => tree,
+        Err(errors) => {
+            return RunOutput::new(
+                EXIT_COMPILE_ERROR,
+                errors
+                    .iter()
+                    .map(|error| error.display_with_file(file))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            )
+        }
+    };
+
+    let results = run_tests(&tree, &SELExecutionContext::from(tree.get_context()));
+
+    let failed = results.iter().filter(|result| !result.is_passed()).count();
+
+    let mut lines: Vec<String> = results.iter().map(report).collect();
+    lines.push(format!(
+        "{} passed, {} failed",
+        results.len() - failed,
+        failed
+    ));
+
+    let code = if failed > 0 {
+        EXIT_RUNTIME_ERROR
+    } else {
+        EXIT_SUCCESS
+    };
+
+    return RunOutput::new(code, lines.join("\n"));
+}
+
+pub fn test_file(file: &str) -> RunOutput {
+    return match fs::read_to_string(file) {
+        Ok(source) => test_source(file, &source),
+        Err(error) => RunOutput::new(
+            EXIT_INVALID_ARGUMENTS,
+            format!("could not read {}: {}", file, error),
+        ),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_passing_tests() {
+        let output = test_source("main.sel", "$ * 2\n\n@Test(4)\n$ == 8\n\n@Test(1)\n$ == 2");
+
+        assert_eq!(output.get_code(), EXIT_SUCCESS);
+        assert_eq!(output.get_output(), "ok 3:1\nok 6:1\n2 passed, 0 failed");
+    }
+
+    #[test]
+    fn reports_failing_tests() {
+        let output = test_source("main.sel", "$ * 2\n\n@Test(4)\n$ == 9\n\n@Test(4)\n$ // 0");
+
+        assert_eq!(output.get_code(), EXIT_RUNTIME_ERROR);
+
+        let lines: Vec<&str> = output.get_output().lines().collect();
+
+        assert_eq!(lines[0], "FAILED 3:1 expected true, found false");
+        assert!(lines[1].starts_with("FAILED 6:1 divide by zero"));
+        assert_eq!(lines[2], "0 passed, 2 failed");
+    }
+
+    #[test]
+    fn compile_error() {
+        let output = test_source("main.sel", "@Test\n(5 + 10");
+
+        assert_eq!(output.get_code(), EXIT_COMPILE_ERROR);
+        assert_eq!(
+            output.get_output(),
+            "main.sel:2:1-2:2: '(' is never closed, expected ')'"
+        );
+        assert_eq!(
+            test_file("missing_file.sel").get_code(),
+            EXIT_INVALID_ARGUMENTS
+        );
+    }
+}
@@ -1,14 +1,87 @@
-#[derive(Debug)]
+use crate::Span;
+
+// value given to an annotation, i.e. @Shape(MyShape, [name: string, scores: [int...]])
+// arguments are not evaluated, so runtimes get the shape as written
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AnnotationArgument {
+    Identifier(String),
+    Integer(i64),
+    Decimal(f64),
+    String(String),
+    Boolean(bool),
+    Unit,
+    Symbol(String),
+    // `name: value`
+    Field(String, Box<AnnotationArgument>),
+    // `left = right`
+    Pair(Box<AnnotationArgument>, Box<AnnotationArgument>),
+    // `value...`, any number of the value
+    Repeated(Box<AnnotationArgument>),
+    // values in brackets
+    List(Vec<AnnotationArgument>),
+    // values in parenthesis
+    Group(Vec<AnnotationArgument>),
+    // anything else, kept as its source text
+    Other(String),
+}
+
+impl AnnotationArgument {
+    // name of an identifier argument
+    pub fn get_identifier(&self) -> Option<&String> {
+        return match self {
+            AnnotationArgument::Identifier(name) => Some(name),
+            _ => None,
+        };
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Annotation {
     name: String,
+    arguments: Vec<AnnotationArgument>,
+    // root of the expression that follows the annotation
+    // None when nothing follows it
+    target: Option<usize>,
+    span: Option<Span>,
 }
 
 impl Annotation {
     pub fn new(name: String) -> Self {
-        return Annotation { name };
+        return Annotation {
+            name,
+            arguments: vec![],
+            target: None,
+            span: None,
+        };
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        return self;
+    }
+
+    pub fn set_arguments(&mut self, arguments: Vec<AnnotationArgument>) {
+        self.arguments = arguments;
+    }
+
+    pub fn set_target(&mut self, target: Option<usize>) {
+        self.target = target;
     }
 
     pub fn get_name(&self) -> &String {
         return &self.name;
     }
+
+    pub fn get_arguments(&self) -> &Vec<AnnotationArgument> {
+        return &self.arguments;
+    }
+
+    pub fn get_target(&self) -> Option<usize> {
+        return self.target;
+    }
+
+    // span of the annotation's name
+    pub fn get_span(&self) -> Option<Span> {
+        return self.span;
+    }
 }
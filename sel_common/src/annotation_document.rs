@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct AnnotationDocument {
     lines: Vec<String>,
 }
@@ -0,0 +1,169 @@
+use bincode::serialize;
+use serde::Serialize;
+use std::fmt;
+
+// every serialized tree starts with these bytes
+pub const ARTIFACT_MAGIC: [u8; 4] = *b"SELT";
+
+// bump whenever the layout of any serialized type changes
+// artifacts written with a different version are rejected
+pub const ARTIFACT_FORMAT_VERSION: u32 = 3;
+
+// magic followed by the little endian format version
+pub const ARTIFACT_HEADER_LENGTH: usize = 8;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ArtifactErrorKind {
+    InvalidHeader,
+    IncompatibleVersion,
+    InvalidData,
+    WriteFailed,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct ArtifactError {
+    kind: ArtifactErrorKind,
+    message: String,
+}
+
+impl ArtifactError {
+    pub fn new(kind: ArtifactErrorKind, message: String) -> Self {
+        return ArtifactError { kind, message };
+    }
+
+    pub fn get_kind(&self) -> ArtifactErrorKind {
+        return self.kind;
+    }
+
+    pub fn get_message(&self) -> &String {
+        return &self.message;
+    }
+}
+
+impl std::fmt::Display for ArtifactError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+pub fn write_header(bytes: &mut Vec<u8>) {
+    bytes.extend_from_slice(&ARTIFACT_MAGIC);
+    bytes.extend_from_slice(&ARTIFACT_FORMAT_VERSION.to_le_bytes());
+}
+
+// header followed by the serialized value
+pub fn write_artifact<T: Serialize>(value: &T) -> Result<Vec<u8>, ArtifactError> {
+    let mut bytes = vec![];
+    write_header(&mut bytes);
+
+    let mut body = serialize(value).map_err(|error| {
+        ArtifactError::new(
+            ArtifactErrorKind::WriteFailed,
+            format!("compiled SEL tree could not be written: {}", error),
+        )
+    })?;
+
+    bytes.append(&mut body);
+
+    return Ok(bytes);
+}
+
+// returns bytes after the header
+pub fn read_header(bytes: &[u8]) -> Result<&[u8], ArtifactError> {
+    if bytes.len() < ARTIFACT_HEADER_LENGTH || bytes[0..4] != ARTIFACT_MAGIC {
+        return Err(ArtifactError::new(
+            ArtifactErrorKind::InvalidHeader,
+            String::from("not a compiled SEL tree, header is missing"),
+        ));
+    }
+
+    let mut version_bytes = [0; 4];
+    version_bytes.copy_from_slice(&bytes[4..ARTIFACT_HEADER_LENGTH]);
+
+    let version = u32::from_le_bytes(version_bytes);
+
+    if version != ARTIFACT_FORMAT_VERSION {
+        return Err(ArtifactError::new(
+            ArtifactErrorKind::IncompatibleVersion,
+            format!(
+                "compiled SEL tree has format version {}, expected version {}",
+                version, ARTIFACT_FORMAT_VERSION
+            ),
+        ));
+    }
+
+    return Ok(&bytes[ARTIFACT_HEADER_LENGTH..]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::ser::{Error, Serializer};
+
+    struct Unwritable;
+
+    impl Serialize for Unwritable {
+        fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+            return Err(S::Error::custom("value is unwritable"));
+        }
+    }
+
+    #[test]
+    fn writes_header_before_value() {
+        let bytes = write_artifact(&10u8).unwrap();
+
+        assert_eq!(read_header(&bytes).unwrap(), &[10]);
+    }
+
+    #[test]
+    fn reports_value_that_cannot_be_written() {
+        let error = write_artifact(&Unwritable).unwrap_err();
+
+        assert_eq!(error.get_kind(), ArtifactErrorKind::WriteFailed);
+        assert_eq!(
+            format!("{}", error),
+            "compiled SEL tree could not be written: value is unwritable"
+        );
+    }
+
+    #[test]
+    fn reads_written_header() {
+        let mut bytes = vec![];
+        write_header(&mut bytes);
+        bytes.push(10);
+
+        assert_eq!(read_header(&bytes).unwrap(), &[10]);
+    }
+
+    #[test]
+    fn rejects_missing_header() {
+        let error = read_header(&[1, 2, 3]).unwrap_err();
+
+        assert_eq!(error.get_kind(), ArtifactErrorKind::InvalidHeader);
+    }
+
+    #[test]
+    fn rejects_wrong_magic() {
+        let error = read_header(b"ABCD\x01\x00\x00\x00").unwrap_err();
+
+        assert_eq!(error.get_kind(), ArtifactErrorKind::InvalidHeader);
+    }
+
+    #[test]
+    fn rejects_other_version() {
+        let mut bytes = ARTIFACT_MAGIC.to_vec();
+        bytes.extend_from_slice(&(ARTIFACT_FORMAT_VERSION + 1).to_le_bytes());
+
+        let error = read_header(&bytes).unwrap_err();
+
+        assert_eq!(error.get_kind(), ArtifactErrorKind::IncompatibleVersion);
+        assert_eq!(
+            format!("{}", error),
+            format!(
+                "compiled SEL tree has format version {}, expected version {}",
+                ARTIFACT_FORMAT_VERSION + 1,
+                ARTIFACT_FORMAT_VERSION
+            )
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::sel_types::error::{RuntimeErrorKind, SELRuntimeError};
+use crate::{Operation, SELValue};
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+// stops an evaluation from another thread
+// clones share the same flag
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    // token also trips once this passes
+    deadline: Option<Instant>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        return CancellationToken::default();
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        return self.cancelled.load(Ordering::SeqCst);
+    }
+
+    // same flag, tripping at deadline as well
+    // the sooner deadline is kept if the token already has one
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(self.deadline.map_or(deadline, |own| own.min(deadline)));
+        return self;
+    }
+
+    pub fn get_deadline(&self) -> Option<Instant> {
+        return self.deadline;
+    }
+
+    pub fn is_timed_out(&self) -> bool {
+        return matches!(self.deadline, Some(deadline) if Instant::now() >= deadline);
+    }
+
+    // cancelled or past its deadline
+    pub fn is_interrupted(&self) -> bool {
+        return self.is_cancelled() || self.is_timed_out();
+    }
+
+    // token of the evaluation calling a host function on this thread
+    // with the evaluation's deadline, if it has one
+    // outside of a call it is a token that is never cancelled
+    pub fn current() -> CancellationToken {
+        return CURRENT.with(|current| current.borrow().clone().unwrap_or_default());
+    }
+
+    // runs func with this token as the current one
+    pub fn scope<R, F: FnOnce() -> R>(&self, func: F) -> R {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+
+        // restored even if func panics
+        let _restore = Restore { previous };
+
+        return func();
+    }
+}
+
+struct Restore {
+    previous: Option<CancellationToken>,
+}
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+
+        CURRENT.with(|current| current.replace(previous));
+    }
+}
+
+// result for host functions that stop early because they were cancelled
+pub fn cancelled_value() -> SELValue {
+    return SELValue::new_from_error(SELRuntimeError::new(
+        RuntimeErrorKind::Cancelled,
+        Operation::None,
+        vec![],
+        String::from("evaluation was cancelled"),
+    ));
+}
+
+// result for host functions that stop early because the deadline passed
+pub fn timed_out_value() -> SELValue {
+    return SELValue::new_from_error(SELRuntimeError::new(
+        RuntimeErrorKind::TimedOut,
+        Operation::None,
+        vec![],
+        String::from("evaluation passed its deadline"),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn clones_share_cancellation() {
+        let token = CancellationToken::new();
+        let other = token.clone();
+
+        assert!(!other.is_cancelled());
+
+        token.cancel();
+
+        assert!(other.is_cancelled());
+    }
+
+    #[test]
+    fn current_token_is_scoped() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        assert!(!CancellationToken::current().is_cancelled());
+        assert!(token.scope(|| CancellationToken::current().is_cancelled()));
+        assert!(!CancellationToken::current().is_cancelled());
+    }
+
+    #[test]
+    fn trips_at_deadline() {
+        let token = CancellationToken::new();
+        let later = Instant::now() + Duration::from_secs(60);
+
+        assert!(!token.clone().with_deadline(later).is_interrupted());
+
+        let passed = token.clone().with_deadline(Instant::now());
+
+        assert!(passed.is_timed_out());
+        assert!(passed.is_interrupted());
+        assert!(!passed.is_cancelled());
+        assert!(passed.with_deadline(later).is_timed_out());
+
+        assert_eq!(token.get_deadline(), None);
+        assert!(!token.is_interrupted());
+    }
+}
@@ -1,12 +1,18 @@
-use crate::{from_byte_vec, to_byte_vec, SELValue, SymbolTable};
+use crate::function::{typed_function, Arguments, Parameter, SELFunction};
+use crate::{from_value_data, to_value_data, SELValue, SymbolTable};
 use std::collections::HashMap;
 use std::fmt::{Debug, Error, Formatter};
+use std::sync::Arc;
 
-pub type SELFunction = fn(SELValue, &SymbolTable) -> SELValue;
-
+// functions are not serialized
+// hosts register them again after loading a tree
+// symbol values are compiled into trees as constants
+// values that change between evaluations belong to the execution context
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SELContext {
     symbol_table: SymbolTable,
     symbol_values: HashMap<usize, SELValue>,
+    #[serde(skip)]
     functions: HashMap<String, SELFunction>,
 }
 
@@ -63,7 +69,7 @@ impl SELContext {
             .symbol_values
             .get(&index)
             .and_then(|val| val.get_value())
-            .map(|bytes| from_byte_vec(bytes));
+            .map(|bytes| from_value_data(bytes));
     }
 
     pub fn get_integer_value_with_key(&self, key: &String) -> Option<i64> {
@@ -73,10 +79,27 @@ impl SELContext {
             .and_then(|index| self.get_integer_value(*index));
     }
 
-    pub fn register_function(&mut self, name: &str, func: SELFunction) {
+    // closures may capture state, shared between threads executing the tree
+    pub fn register_function<F>(&mut self, name: &str, func: F)
+    where
+        F: Fn(SELValue, &SymbolTable) -> SELValue + Send + Sync + 'static,
+    {
+        self.register_shared_function(name, Arc::new(func));
+    }
+
+    pub fn register_shared_function(&mut self, name: &str, func: SELFunction) {
         self.functions.insert(String::from(name), func);
     }
 
+    // arguments are bound to parameters before calling
+    // see typed_function
+    pub fn register_typed_function<F>(&mut self, name: &str, parameters: Vec<Parameter>, func: F)
+    where
+        F: Fn(&Arguments) -> SELValue + Send + Sync + 'static,
+    {
+        self.register_shared_function(name, typed_function(parameters, func));
+    }
+
     pub fn get_functions(&self) -> &HashMap<String, SELFunction> {
         return &self.functions;
     }
@@ -85,7 +108,7 @@ impl SELContext {
 #[cfg(test)]
 mod tests {
     use crate::context::SELContext;
-    use crate::from_byte_vec;
+    use crate::from_value_data;
 
     #[test]
     fn create() {
@@ -99,7 +122,7 @@ mod tests {
         let sel_value = context.symbol_values.get(&insert_index).unwrap();
         let value: i64 = sel_value
             .get_value()
-            .map_or(0, |bytes| from_byte_vec(bytes));
+            .map_or(0, |bytes| from_value_data(bytes));
 
         assert_eq!(value, 10);
     }
@@ -1,10 +1,10 @@
-use super::utils::{from_byte_vec, to_byte_vec};
-use super::DataType;
+use super::utils::{from_value_data, to_value_data};
+use super::{DataType, ValueData};
 use std::str::FromStr;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataHeap {
-    data: Vec<Vec<u8>>,
+    data: Vec<ValueData>,
 }
 
 impl DataHeap {
@@ -17,11 +17,11 @@ impl DataHeap {
             DataType::Integer => self.insert_integer(value_str.parse::<i64>().unwrap()),
             DataType::Decimal => {
                 let num = value_str.parse::<f64>().unwrap();
-                self.data.push(to_byte_vec(num));
+                self.data.push(to_value_data(num));
                 Some(self.data.len() - 1)
             }
             DataType::String => {
-                self.data.push(to_byte_vec(value_str));
+                self.data.push(to_value_data(value_str));
                 Some(self.data.len() - 1)
             }
             DataType::Boolean => {
@@ -30,7 +30,7 @@ impl DataHeap {
                     Err(_) => false, // probably panic?
                 };
 
-                self.data.push(to_byte_vec(b));
+                self.data.push(to_value_data(b));
                 Some(self.data.len() - 1)
             }
             _ => None,
@@ -38,16 +38,16 @@ impl DataHeap {
     }
 
     pub fn insert_integer(&mut self, value: i64) -> Option<usize> {
-        self.data.push(to_byte_vec(value));
+        self.data.push(to_value_data(value));
         Some(self.data.len() - 1)
     }
 
     pub fn insert_usize(&mut self, value: usize) -> Option<usize> {
-        self.data.push(to_byte_vec(value));
+        self.data.push(to_value_data(value));
         Some(self.data.len() - 1)
     }
 
-    pub fn get_bytes(&self, index: usize) -> Option<Vec<u8>> {
+    pub fn get_data(&self, index: usize) -> Option<ValueData> {
         return match self.data.get(index) {
             Some(datum) => Some(datum.clone()),
             None => None,
@@ -55,33 +55,33 @@ impl DataHeap {
     }
 
     pub fn get_usize(&self, index: usize) -> Option<usize> {
-        return self.data.get(index).map(|datum| from_byte_vec(datum));
+        return self.data.get(index).map(|datum| from_value_data(datum));
     }
 
     pub fn get_integer(&self, index: usize) -> Option<i64> {
         return match self.data.get(index) {
-            Some(datum) => Some(from_byte_vec(datum)),
+            Some(datum) => Some(from_value_data(datum)),
             None => None,
         };
     }
 
     pub fn get_decimal(&self, index: usize) -> Option<f64> {
         return match self.data.get(index) {
-            Some(datum) => Some(from_byte_vec(datum)),
+            Some(datum) => Some(from_value_data(datum)),
             None => None,
         };
     }
 
     pub fn get_string(&self, index: usize) -> Option<String> {
         return match self.data.get(index) {
-            Some(datum) => Some(from_byte_vec(datum)),
+            Some(datum) => Some(from_value_data(datum)),
             None => None,
         };
     }
 
     pub fn get_boolean(&self, index: usize) -> Option<bool> {
         return match self.data.get(index) {
-            Some(datum) => Some(from_byte_vec(datum)),
+            Some(datum) => Some(from_value_data(datum)),
             None => None,
         };
     }
@@ -17,6 +17,8 @@ pub enum DataType {
     Expression,
     Stream,
     StreamInstruction,
+    PartialApplication,
+    Error,
 }
 
 impl std::fmt::Display for DataType {
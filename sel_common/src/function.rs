@@ -0,0 +1,475 @@
+use crate::sel_types::error::{RuntimeErrorKind, SELRuntimeError};
+use crate::{
+    from_value_data, DataType, FromValueData, Operation, SELValue, SymbolTable, ValueData,
+};
+use std::sync::Arc;
+
+pub type SELFunction = Arc<dyn Fn(SELValue, &SymbolTable) -> SELValue + Send + Sync>;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Parameter {
+    name: String,
+    // None accepts any type
+    data_type: Option<DataType>,
+    default: Option<SELValue>,
+}
+
+impl Parameter {
+    pub fn new(name: &str, data_type: DataType) -> Self {
+        return Parameter {
+            name: String::from(name),
+            data_type: Some(data_type),
+            default: None,
+        };
+    }
+
+    pub fn any(name: &str) -> Self {
+        return Parameter {
+            name: String::from(name),
+            data_type: None,
+            default: None,
+        };
+    }
+
+    // parameter may be left out, default is used instead
+    pub fn with_default(mut self, default: SELValue) -> Self {
+        self.default = Some(default);
+        return self;
+    }
+
+    pub fn get_name(&self) -> &String {
+        return &self.name;
+    }
+
+    pub fn get_data_type(&self) -> Option<DataType> {
+        return self.data_type;
+    }
+
+    pub fn get_default(&self) -> Option<&SELValue> {
+        return self.default.as_ref();
+    }
+
+    // integers are accepted for decimal parameters
+    // and associative lists for list parameters, as their values
+    fn accept(&self, value: SELValue) -> Result<SELValue, String> {
+        return match (self.data_type, value.get_value()) {
+            (None, _) => Ok(value),
+            (Some(DataType::Decimal), Some(ValueData::Integer(integer))) => {
+                Ok(SELValue::new_from_decimal(*integer as f64))
+            }
+            (Some(DataType::List), Some(ValueData::AssociativeList(list))) => {
+                Ok(SELValue::new_from_list(list.get_list().clone()))
+            }
+            (Some(data_type), _) if data_type == value.get_type() => Ok(value),
+            (Some(data_type), _) => Err(format!(
+                "parameter '{}' expects {}, found {}",
+                self.name,
+                data_type,
+                value.get_type()
+            )),
+        };
+    }
+}
+
+// arguments of a typed function in parameter order
+pub struct Arguments {
+    names: Vec<String>,
+    values: Vec<SELValue>,
+}
+
+impl Arguments {
+    pub fn get_values(&self) -> &Vec<SELValue> {
+        return &self.values;
+    }
+
+    pub fn get_value(&self, name: &str) -> Option<&SELValue> {
+        return self
+            .names
+            .iter()
+            .position(|parameter| parameter == name)
+            .and_then(|index| self.values.get(index));
+    }
+
+    // None if there is no parameter with the name or its value has no data
+    pub fn get<T: FromValueData>(&self, name: &str) -> Option<T> {
+        return self
+            .get_value(name)
+            .and_then(|value| value.get_value())
+            .map(|data| from_value_data(data));
+    }
+}
+
+// identifier of a pair keyed by symbol
+fn named(value: &SELValue) -> Option<(String, SELValue)> {
+    if let Some(ValueData::Pair(pair)) = value.get_value() {
+        if let Some(ValueData::Symbol(symbol)) = pair.get_left().get_value() {
+            return Some((symbol.get_identifier().clone(), pair.get_right().clone()));
+        }
+    }
+
+    return None;
+}
+
+// values of a list or associative list given as arguments
+fn collection_values(value: &SELValue) -> Option<&Vec<SELValue>> {
+    return match value.get_value() {
+        Some(ValueData::List(list)) => Some(list.get_values()),
+        Some(ValueData::AssociativeList(list)) => Some(list.get_list().get_values()),
+        _ => None,
+    };
+}
+
+// collection is given whole to a single parameter of its type or any type
+// unless it names the parameter, i.e. count(items = [1, 2], 3)
+fn takes_whole(parameters: &[Parameter], value: &SELValue) -> bool {
+    let parameter = match parameters {
+        [parameter] => parameter,
+        _ => return false,
+    };
+
+    let values = match collection_values(value) {
+        Some(values) => values,
+        None => return false,
+    };
+
+    let names_parameter = values
+        .iter()
+        .filter_map(named)
+        .any(|(name, _)| &name == parameter.get_name());
+
+    return !names_parameter
+        && match parameter.get_data_type() {
+            None | Some(DataType::List) => true,
+            Some(data_type) => data_type == value.get_type(),
+        };
+}
+
+// splits call value into positional and named arguments
+// group calls, pipes and partial applications all give one of these shapes
+fn split_call_value(
+    parameters: &[Parameter],
+    value: SELValue,
+) -> (Vec<SELValue>, Vec<(String, SELValue)>) {
+    if takes_whole(parameters, &value) {
+        return (vec![value], vec![]);
+    }
+
+    let values: Vec<SELValue> = match value.get_type() {
+        DataType::Unit => vec![],
+        DataType::List | DataType::AssociativeList => {
+            collection_values(&value).map_or(vec![], |values| values.clone())
+        }
+        // single pair is only named when it names a parameter
+        // otherwise it is passed as is
+        DataType::Pair => match named(&value) {
+            Some((name, _)) if parameters.iter().any(|p| p.get_name() == &name) => vec![value],
+            _ => return (vec![value], vec![]),
+        },
+        _ => vec![value],
+    };
+
+    let mut positional = vec![];
+    let mut by_name = vec![];
+
+    for value in values {
+        match named(&value) {
+            Some(named) => by_name.push(named),
+            None => positional.push(value),
+        }
+    }
+
+    return (positional, by_name);
+}
+
+// named arguments are matched first
+// positional arguments fill the remaining parameters in order
+// so piped values go to the first or last parameter not given by name
+pub fn bind_arguments(parameters: &[Parameter], value: SELValue) -> Result<Arguments, String> {
+    let (positional, by_name) = split_call_value(parameters, value);
+
+    let mut values: Vec<Option<SELValue>> = vec![None; parameters.len()];
+
+    for (name, value) in by_name {
+        let index = match parameters.iter().position(|p| p.get_name() == &name) {
+            Some(index) => index,
+            None => return Err(format!("no parameter named '{}'", name)),
+        };
+
+        if values[index].is_some() {
+            return Err(format!("parameter '{}' given more than once", name));
+        }
+
+        values[index] = Some(parameters[index].accept(value)?);
+    }
+
+    let open: Vec<usize> = (0..parameters.len())
+        .filter(|index| values[*index].is_none())
+        .collect();
+
+    if positional.len() > open.len() {
+        return Err(format!(
+            "expected at most {} arguments, found {}",
+            parameters.len(),
+            parameters.len() - open.len() + positional.len()
+        ));
+    }
+
+    for (index, value) in open.into_iter().zip(positional) {
+        values[index] = Some(parameters[index].accept(value)?);
+    }
+
+    let mut bound = vec![];
+
+    for (parameter, value) in parameters.iter().zip(values) {
+        match value.or_else(|| parameter.get_default().cloned()) {
+            Some(value) => bound.push(value),
+            None => return Err(format!("missing argument '{}'", parameter.get_name())),
+        }
+    }
+
+    return Ok(Arguments {
+        names: parameters.iter().map(|p| p.get_name().clone()).collect(),
+        values: bound,
+    });
+}
+
+// wraps a function of declared parameters as a registered function
+// mismatched arguments result in an error value instead of calling it
+// the operation is filled in with the calling node by the executor
+pub fn typed_function<F>(parameters: Vec<Parameter>, func: F) -> SELFunction
+where
+    F: Fn(&Arguments) -> SELValue + Send + Sync + 'static,
+{
+    return Arc::new(move |value: SELValue, _symbol_table: &SymbolTable| {
+        let operand_type = value.get_type();
+
+        return match bind_arguments(&parameters, value) {
+            Ok(arguments) => func(&arguments),
+            Err(message) => SELValue::new_from_error(SELRuntimeError::new(
+                RuntimeErrorKind::ArgumentMismatch,
+                Operation::None,
+                vec![operand_type],
+                message,
+            )),
+        };
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sel_types::associative_list::AssociativeList;
+    use crate::sel_types::list::List;
+    use crate::sel_types::pair::Pair;
+    use crate::sel_types::symbol::Symbol;
+
+    fn clamp_parameters() -> Vec<Parameter> {
+        return vec![
+            Parameter::new("num", DataType::Integer),
+            Parameter::new("min", DataType::Integer),
+            Parameter::new("max", DataType::Integer),
+        ];
+    }
+
+    fn named_value(name: &str, value: i64) -> SELValue {
+        return SELValue::new_from_pair(Pair::new(
+            SELValue::new_from_symbol(Symbol::new(String::from(name), 0)),
+            SELValue::new_from_int(value),
+        ));
+    }
+
+    fn list_of(values: Vec<SELValue>) -> SELValue {
+        let mut list = List::new();
+
+        for value in values {
+            list.push(value);
+        }
+
+        return SELValue::new_from_list(list);
+    }
+
+    fn bound(value: SELValue) -> Result<Vec<i64>, String> {
+        return bind_arguments(&clamp_parameters(), value).map(|arguments| {
+            arguments
+                .get_values()
+                .iter()
+                .map(|value| from_value_data(value.get_value().unwrap()))
+                .collect()
+        });
+    }
+
+    #[test]
+    fn binds_positional() {
+        assert_eq!(
+            bound(list_of(vec![
+                SELValue::new_from_int(3),
+                SELValue::new_from_int(10),
+                SELValue::new_from_int(20)
+            ])),
+            Ok(vec![3, 10, 20])
+        );
+    }
+
+    #[test]
+    fn binds_named_in_any_order() {
+        let mut list = AssociativeList::new();
+        list.push(named_value("max", 20));
+        list.push(named_value("num", 3));
+        list.push(named_value("min", 10));
+
+        assert_eq!(
+            bound(SELValue::new_from_associative_list(list)),
+            Ok(vec![3, 10, 20])
+        );
+    }
+
+    #[test]
+    fn positional_fill_parameters_not_named() {
+        let mut list = AssociativeList::new();
+        list.push(named_value("num", 3));
+        list.push(named_value("min", 10));
+        list.push(SELValue::new_from_int(20));
+
+        assert_eq!(
+            bound(SELValue::new_from_associative_list(list)),
+            Ok(vec![3, 10, 20])
+        );
+    }
+
+    #[test]
+    fn uses_defaults() {
+        let parameters = vec![
+            Parameter::new("num", DataType::Integer),
+            Parameter::new("step", DataType::Integer).with_default(SELValue::new_from_int(1)),
+        ];
+
+        let arguments = bind_arguments(&parameters, SELValue::new_from_int(5)).unwrap();
+
+        assert_eq!(arguments.get::<i64>("num"), Some(5));
+        assert_eq!(arguments.get::<i64>("step"), Some(1));
+        assert_eq!(arguments.get::<i64>("other"), None);
+    }
+
+    #[test]
+    fn integers_are_decimals() {
+        let parameters = vec![Parameter::new("angle", DataType::Decimal)];
+
+        let arguments = bind_arguments(&parameters, SELValue::new_from_int(2)).unwrap();
+
+        assert_eq!(arguments.get::<f64>("angle"), Some(2.0));
+    }
+
+    #[test]
+    fn single_parameter_takes_collection_whole() {
+        let parameters = vec![Parameter::new("items", DataType::AssociativeList)];
+
+        let mut list = AssociativeList::new();
+        list.push(named_value("a", 1));
+        list.push(named_value("b", 2));
+
+        let items = SELValue::new_from_associative_list(list);
+        let arguments = bind_arguments(&parameters, items.clone()).unwrap();
+
+        assert_eq!(arguments.get_value("items"), Some(&items));
+
+        let parameters = vec![Parameter::new("items", DataType::List)];
+
+        let items = list_of(vec![SELValue::new_from_int(1), SELValue::new_from_int(2)]);
+        let arguments = bind_arguments(&parameters, items.clone()).unwrap();
+
+        assert_eq!(arguments.get_value("items"), Some(&items));
+    }
+
+    #[test]
+    fn associative_lists_are_lists() {
+        let parameters = vec![Parameter::new("items", DataType::List)];
+
+        let mut list = AssociativeList::new();
+        list.push(SELValue::new_from_int(1));
+        list.push(SELValue::new_from_int(2));
+
+        let arguments =
+            bind_arguments(&parameters, SELValue::new_from_associative_list(list)).unwrap();
+
+        assert_eq!(
+            arguments.get_value("items"),
+            Some(&list_of(vec![
+                SELValue::new_from_int(1),
+                SELValue::new_from_int(2)
+            ]))
+        );
+    }
+
+    #[test]
+    fn single_pair_is_any_value() {
+        let parameters = vec![Parameter::any("value")];
+
+        let pair = named_value("other", 1);
+        let arguments = bind_arguments(&parameters, pair.clone()).unwrap();
+
+        assert_eq!(arguments.get_value("value"), Some(&pair));
+    }
+
+    #[test]
+    fn reports_mismatches() {
+        assert_eq!(
+            bound(SELValue::new_from_int(3)),
+            Err(String::from("missing argument 'min'"))
+        );
+        assert_eq!(
+            bound(list_of(vec![
+                SELValue::new_from_int(3),
+                SELValue::new_from_string(&String::from("10")),
+                SELValue::new_from_int(20)
+            ])),
+            Err(String::from(
+                "parameter 'min' expects Integer, found String"
+            ))
+        );
+        assert_eq!(
+            bound(list_of(vec![SELValue::new_from_int(1); 4])),
+            Err(String::from("expected at most 3 arguments, found 4"))
+        );
+
+        let mut list = AssociativeList::new();
+        list.push(named_value("num", 3));
+        list.push(named_value("low", 10));
+
+        assert_eq!(
+            bound(SELValue::new_from_associative_list(list)),
+            Err(String::from("no parameter named 'low'"))
+        );
+    }
+
+    #[test]
+    fn typed_function_returns_error_values() {
+        let clamp = typed_function(clamp_parameters(), |arguments| {
+            let num: i64 = arguments.get("num").unwrap();
+            let min: i64 = arguments.get("min").unwrap();
+            let max: i64 = arguments.get("max").unwrap();
+
+            SELValue::new_from_int(num.max(min).min(max))
+        });
+
+        let symbol_table = SymbolTable::new();
+
+        assert_eq!(
+            clamp(
+                list_of(vec![
+                    SELValue::new_from_int(30),
+                    SELValue::new_from_int(10),
+                    SELValue::new_from_int(20)
+                ]),
+                &symbol_table
+            ),
+            SELValue::new_from_int(20)
+        );
+
+        let error = clamp(SELValue::new(), &symbol_table);
+
+        assert_eq!(
+            error.get_error().unwrap().get_kind(),
+            RuntimeErrorKind::ArgumentMismatch
+        );
+    }
+}
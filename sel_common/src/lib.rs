@@ -2,29 +2,42 @@
 extern crate serde_derive;
 
 pub mod annotation;
+pub mod artifact;
+pub mod cancellation;
 pub mod annotation_document;
 mod context;
 mod data_heap;
 mod data_type;
+pub mod function;
 pub mod named_expression;
 mod operation;
 mod sel_tree;
 pub mod sel_types;
+pub mod shape;
 mod sel_value;
+mod span;
 mod sub_tree;
 mod symbol_table;
+pub mod test_case;
 mod utils;
+mod value_data;
+mod value_serde;
 
-pub use context::{SELContext, SELFunction};
+pub use cancellation::CancellationToken;
+pub use context::SELContext;
+pub use function::SELFunction;
 pub use data_heap::DataHeap;
 pub use data_type::DataType;
 pub use operation::Operation;
 pub use sel_tree::{NodeSide, SELTree, SELTreeNode};
 //pub use sel_types::{AssociativeList, Expression, List, Pair, Range, Symbol};
 pub use sel_value::SELValue;
+pub use span::{Location, Span};
 pub use sub_tree::SELSubTree;
 pub use symbol_table::SymbolTable;
-pub use utils::{from_byte_vec, to_byte_vec, FromByteVec, ToByteVec};
+pub use utils::{from_value_data, to_value_data, FromValueData, ToValueData};
+pub use value_data::ValueData;
+pub use value_serde::{from_sel_value, to_sel_value, to_sel_value_with_context, ConversionError};
 
 #[cfg(test)]
 mod tests {}
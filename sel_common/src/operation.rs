@@ -1,4 +1,4 @@
-#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum Operation {
     Touch,
     Input,
@@ -53,6 +53,7 @@ pub enum Operation {
     Stream,
     Collect,
     CollectInit,
+    Combine,
     PartialApplication,
     InfixCall,
     None,
@@ -3,12 +3,16 @@ use super::operation::Operation;
 use super::DataHeap;
 use crate::annotation::Annotation;
 use crate::annotation_document::AnnotationDocument;
+use crate::artifact::{read_header, write_artifact, ArtifactError, ArtifactErrorKind};
 use crate::named_expression::NamedExpression;
+use crate::shape::{ShapeDefinitions, ShapeError};
 use crate::symbol_table::SymbolTable;
-use crate::{SELContext, SELSubTree};
+use crate::test_case::{Mock, TestCase};
+use crate::{SELContext, SELSubTree, SELValue, Span, ValueData};
+use bincode::deserialize;
 use std::collections::HashMap;
 
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct SELTreeNode {
     operation: Operation,
     value: Option<usize>,
@@ -17,6 +21,9 @@ pub struct SELTreeNode {
     parent: Option<usize>,
     left: Option<usize>,
     right: Option<usize>,
+    span: Option<Span>,
+    // identifier written with a tag, i.e. #name(5)
+    tagged: bool,
 }
 
 impl SELTreeNode {
@@ -34,6 +41,8 @@ impl SELTreeNode {
             parent: None,
             data_type,
             own_index,
+            span: None,
+            tagged: false,
         };
     }
 
@@ -65,6 +74,14 @@ impl SELTreeNode {
         return self.parent;
     }
 
+    pub fn get_span(&self) -> Option<Span> {
+        return self.span;
+    }
+
+    pub fn is_tagged(&self) -> bool {
+        return self.tagged;
+    }
+
     pub fn set_left(&mut self, left: Option<usize>) {
         self.left = left;
     }
@@ -84,9 +101,19 @@ impl SELTreeNode {
     pub fn set_operation(&mut self, op: Operation) {
         self.operation = op;
     }
+
+    pub fn set_span(&mut self, span: Option<Span>) {
+        self.span = span;
+    }
+
+    pub fn set_tagged(&mut self, tagged: bool) {
+        self.tagged = tagged;
+    }
 }
 
-#[derive(Debug)]
+// compiled program, never changed after compiling
+// shared between threads that execute it with their own execution contexts
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SELTree {
     data: DataHeap,
     root: usize,
@@ -98,6 +125,11 @@ pub struct SELTree {
     documents: Vec<AnnotationDocument>,
     named_expressions: HashMap<usize, NamedExpression>,
     namespaces: HashMap<usize, Vec<String>>,
+    // tests are omitted from compiled output
+    #[serde(skip)]
+    tests: Vec<TestCase>,
+    #[serde(skip)]
+    mocks: Vec<Mock>,
 }
 
 impl SELTree {
@@ -124,9 +156,17 @@ impl SELTree {
             documents,
             named_expressions,
             namespaces: name_spaces,
+            tests: vec![],
+            mocks: vec![],
         };
     }
 
+    pub fn with_tests(mut self, tests: Vec<TestCase>, mocks: Vec<Mock>) -> Self {
+        self.tests = tests;
+        self.mocks = mocks;
+        return self;
+    }
+
     pub fn get_nodes(&self) -> &Vec<SELTreeNode> {
         return &self.nodes;
     }
@@ -158,9 +198,9 @@ impl SELTree {
         };
     }
 
-    pub fn get_value_bytes_of(&self, node: &SELTreeNode) -> Option<Vec<u8>> {
+    pub fn get_value_data_of(&self, node: &SELTreeNode) -> Option<ValueData> {
         return match node.get_value() {
-            Some(value_index) => self.data.get_bytes(value_index),
+            Some(value_index) => self.data.get_data(value_index),
             None => None,
         };
     }
@@ -207,6 +247,42 @@ impl SELTree {
         return &self.annotations;
     }
 
+    // annotations directly before the expression with this root
+    pub fn annotations_for(&self, root: usize) -> Vec<&Annotation> {
+        return self
+            .annotations
+            .iter()
+            .filter(|annotation| annotation.get_target() == Some(root))
+            .collect();
+    }
+
+    // checks a value against the @Input of the tree's root before it is evaluated
+    // trees without one accept any input
+    pub fn validate_input(&self, input: &SELValue) -> Result<(), ShapeError> {
+        let annotation = match self
+            .annotations_for(self.root)
+            .into_iter()
+            .find(|annotation| annotation.get_name() == "Input")
+        {
+            Some(annotation) => annotation,
+            None => return Ok(()),
+        };
+
+        let shape = ShapeDefinitions::from_annotations(&self.annotations)
+            .resolve_annotation(annotation)
+            .map_err(ShapeError::new)?;
+
+        return shape.validate(input);
+    }
+
+    pub fn get_tests(&self) -> &Vec<TestCase> {
+        return &self.tests;
+    }
+
+    pub fn get_mocks(&self) -> &Vec<Mock> {
+        return &self.mocks;
+    }
+
     pub fn get_named_expressions(&self) -> &HashMap<usize, NamedExpression> {
         return &self.named_expressions;
     }
@@ -214,6 +290,23 @@ impl SELTree {
     pub fn get_namespaces_for_symbol(&self) -> &HashMap<usize, Vec<String>> {
         return &self.namespaces;
     }
+
+    // compiled tree as a versioned binary artifact
+    // registered functions are not included
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ArtifactError> {
+        return write_artifact(self);
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<SELTree, ArtifactError> {
+        let body = read_header(bytes)?;
+
+        return deserialize(body).map_err(|error| {
+            ArtifactError::new(
+                ArtifactErrorKind::InvalidData,
+                format!("compiled SEL tree could not be read: {}", error),
+            )
+        });
+    }
 }
 
 #[derive(PartialEq, Debug, Clone, Copy)]
@@ -2,10 +2,10 @@ use crate::sel_types::list::List;
 use crate::sel_types::pair::Pair;
 use crate::sel_types::symbol::Symbol;
 use crate::sel_value::*;
-use crate::{from_byte_vec, DataType, SELValue};
+use crate::{DataType, SELValue, ValueData};
 use std::collections::HashMap;
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
 pub struct AssociativeList {
     list: List,
     associations: HashMap<usize, usize>,
@@ -19,7 +19,7 @@ impl AssociativeList {
         };
     }
 
-    pub fn from(list: List) -> Self {
+    pub fn from(list: &List) -> Self {
         let mut associative_list = AssociativeList::new();
 
         for value in list.get_values() {
@@ -39,15 +39,17 @@ impl AssociativeList {
 
     pub fn push(&mut self, value: SELValue) {
         if value.get_type() == DataType::Pair {
-            let pair: Pair = from_byte_vec(value.get_value().unwrap());
-
-            if pair.get_left().get_type() == DataType::Symbol {
-                let symbol: Symbol = from_byte_vec(pair.get_left().get_value().unwrap());
+            let symbol_index = value
+                .get_value()
+                .and_then(ValueData::as_pair)
+                .and_then(|pair| pair.get_left().get_value())
+                .and_then(ValueData::as_symbol)
+                .map(|symbol| symbol.get_table_index());
 
+            if let Some(symbol_index) = symbol_index {
                 let new_index = self.list.get_values().len();
-                self.list.push(SELValue::new_from_pair(pair.clone()));
-                self.associations
-                    .insert(symbol.get_table_index(), new_index);
+                self.list.push(value);
+                self.associations.insert(symbol_index, new_index);
             }
         } else {
             self.list.push(value);
@@ -63,7 +65,43 @@ impl AssociativeList {
             .associations
             .get(&index)
             .and_then(|associated_index| self.list.get_values().get(*associated_index))
-            .map(|sel_value| from_byte_vec::<Pair>(sel_value.get_value().unwrap()))
+            .and_then(|sel_value| sel_value.get_value())
+            .and_then(ValueData::as_pair)
             .map(|pair| pair.get_right().clone());
     }
+
+    pub fn get_by_identifier(&self, identifier: &String) -> Option<SELValue> {
+        return self
+            .list
+            .get_values()
+            .iter()
+            .filter_map(|value| value.get_value().and_then(ValueData::as_pair))
+            .find(|pair| symbol_identifier(pair) == Some(identifier))
+            .map(|pair| pair.get_right().clone());
+    }
+
+    // symbols from another symbol table may have a different index
+    // so the associated pair must have the same identifier
+    // otherwise pairs are searched by identifier
+    pub fn get_by_symbol(&self, symbol: &Symbol) -> Option<SELValue> {
+        let associated = self
+            .associations
+            .get(&symbol.get_table_index())
+            .and_then(|associated_index| self.list.get_values().get(*associated_index))
+            .and_then(|sel_value| sel_value.get_value())
+            .and_then(ValueData::as_pair)
+            .filter(|pair| symbol_identifier(pair) == Some(symbol.get_identifier()));
+
+        return match associated {
+            Some(pair) => Some(pair.get_right().clone()),
+            None => self.get_by_identifier(symbol.get_identifier()),
+        };
+    }
+}
+
+fn symbol_identifier(pair: &Pair) -> Option<&String> {
+    return match pair.get_left().get_value() {
+        Some(ValueData::Symbol(symbol)) => Some(symbol.get_identifier()),
+        _ => None,
+    };
 }
@@ -0,0 +1,116 @@
+use crate::{DataType, Operation};
+use std::fmt;
+
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum RuntimeErrorKind {
+    TypeMismatch,
+    DivideByZero,
+    UnknownFunction,
+    IndexOutOfRange,
+    Overflow,
+    ArgumentMismatch,
+    LimitExceeded,
+    Cancelled,
+    TimedOut,
+    MissingOperand,
+}
+
+impl std::fmt::Display for RuntimeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            RuntimeErrorKind::TypeMismatch => "type mismatch",
+            RuntimeErrorKind::DivideByZero => "divide by zero",
+            RuntimeErrorKind::UnknownFunction => "unknown function",
+            RuntimeErrorKind::IndexOutOfRange => "index out of range",
+            RuntimeErrorKind::Overflow => "overflow",
+            RuntimeErrorKind::ArgumentMismatch => "argument mismatch",
+            RuntimeErrorKind::LimitExceeded => "limit exceeded",
+            RuntimeErrorKind::Cancelled => "cancelled",
+            RuntimeErrorKind::TimedOut => "timed out",
+            RuntimeErrorKind::MissingOperand => "missing operand",
+        };
+
+        write!(f, "{}", description)
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct SELRuntimeError {
+    kind: RuntimeErrorKind,
+    operation: Operation,
+    operand_types: Vec<DataType>,
+    message: String,
+}
+
+impl SELRuntimeError {
+    pub fn new(
+        kind: RuntimeErrorKind,
+        operation: Operation,
+        operand_types: Vec<DataType>,
+        message: String,
+    ) -> Self {
+        return SELRuntimeError {
+            kind,
+            operation,
+            operand_types,
+            message,
+        };
+    }
+
+    pub fn get_kind(&self) -> RuntimeErrorKind {
+        return self.kind;
+    }
+
+    pub fn get_operation(&self) -> Operation {
+        return self.operation;
+    }
+
+    pub fn get_operand_types(&self) -> &Vec<DataType> {
+        return &self.operand_types;
+    }
+
+    pub fn get_message(&self) -> &String {
+        return &self.message;
+    }
+}
+
+impl std::fmt::Display for SELRuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let types: Vec<String> = self
+            .operand_types
+            .iter()
+            .map(|data_type| format!("{}", data_type))
+            .collect();
+
+        write!(
+            f,
+            "{} in {:?}({}): {}",
+            self.kind,
+            self.operation,
+            types.join(", "),
+            self.message
+        )
+    }
+}
+
+impl std::error::Error for SELRuntimeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_error() {
+        let error = SELRuntimeError::new(
+            RuntimeErrorKind::TypeMismatch,
+            Operation::Addition,
+            vec![DataType::Integer, DataType::Range],
+            String::from("cannot add Integer and Range"),
+        );
+
+        assert_eq!(
+            format!("{}", error),
+            "type mismatch in Addition(Integer, Range): cannot add Integer and Range"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
 pub struct Expression {
     root: Option<usize>,
 }
@@ -1,6 +1,6 @@
 use crate::SELValue;
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
 pub struct List {
     values: Vec<SELValue>,
 }
@@ -1,7 +1,9 @@
 pub mod associative_list;
+pub mod error;
 pub mod expression;
 pub mod list;
 pub mod pair;
+pub mod partial_application;
 pub mod range;
 pub mod stream;
 pub mod stream_instruction;
@@ -1,6 +1,6 @@
 use crate::{DataType, SELValue};
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Pair {
     left: SELValue,
     right: SELValue,
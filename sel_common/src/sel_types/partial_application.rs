@@ -0,0 +1,235 @@
+use crate::sel_types::associative_list::AssociativeList;
+use crate::sel_types::list::List;
+use crate::sel_types::pair::Pair;
+use crate::{from_value_data, DataType, SELValue, ValueData};
+
+// a function with some of its arguments already supplied
+// unit arguments are skipped slots
+// filled by the next values that are applied
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct PartialApplication {
+    function: String,
+    arguments: Vec<SELValue>,
+    named_arguments: Vec<SELValue>,
+}
+
+impl PartialApplication {
+    pub fn new(function: &str) -> Self {
+        return PartialApplication {
+            function: String::from(function),
+            arguments: vec![],
+            named_arguments: vec![],
+        };
+    }
+
+    pub fn get_function(&self) -> &String {
+        return &self.function;
+    }
+
+    pub fn get_arguments(&self) -> &Vec<SELValue> {
+        return &self.arguments;
+    }
+
+    pub fn get_named_arguments(&self) -> &Vec<SELValue> {
+        return &self.named_arguments;
+    }
+
+    // new partial application with given values applied
+    // values go into skipped slots first, then appended
+    // pairs keyed by symbols are applied by name
+    pub fn apply(&self, value: &SELValue) -> Self {
+        let mut applied = self.clone();
+
+        // only slots skipped by previous applications are filled
+        // skipping again leaves the slot open
+        let mut open_slots = self
+            .arguments
+            .iter()
+            .enumerate()
+            .filter(|(_, existing)| existing.get_type() == DataType::Unit)
+            .map(|(slot, _)| slot);
+
+        for argument in split_arguments(value) {
+            if is_named(&argument) {
+                applied.named_arguments.push(argument);
+                continue;
+            }
+
+            match open_slots.next() {
+                Some(slot) => applied.arguments[slot] = argument,
+                None => applied.arguments.push(argument),
+            }
+        }
+
+        return applied;
+    }
+
+    // same as apply except values go at the end
+    // a trailing skipped slot is filled first
+    pub fn apply_last(&self, value: &SELValue) -> Self {
+        let mut applied = self.clone();
+
+        let mut trailing_slot = match applied.arguments.last() {
+            Some(last) if last.get_type() == DataType::Unit => Some(applied.arguments.len() - 1),
+            _ => None,
+        };
+
+        for argument in split_arguments(value) {
+            if is_named(&argument) {
+                applied.named_arguments.push(argument);
+                continue;
+            }
+
+            match trailing_slot.take() {
+                Some(slot) => applied.arguments[slot] = argument,
+                None => applied.arguments.push(argument),
+            }
+        }
+
+        return applied;
+    }
+
+    // value passed to the function when called
+    // same shape as a group call with the same arguments
+    pub fn get_call_value(&self) -> SELValue {
+        if self.named_arguments.is_empty() {
+            return match self.arguments.len() {
+                0 => SELValue::new(),
+                1 => self.arguments[0].clone(),
+                _ => {
+                    let mut list = List::new();
+
+                    for argument in self.arguments.iter() {
+                        list.push(argument.clone());
+                    }
+
+                    SELValue::new_from_list(list)
+                }
+            };
+        }
+
+        let mut list = AssociativeList::new();
+
+        for argument in self.arguments.iter().chain(self.named_arguments.iter()) {
+            list.push(argument.clone());
+        }
+
+        return SELValue::new_from_associative_list(list);
+    }
+}
+
+fn is_named(value: &SELValue) -> bool {
+    if value.get_type() != DataType::Pair {
+        return false;
+    }
+
+    let pair: Pair = from_value_data(value.get_value().unwrap());
+
+    return pair.get_left().get_type() == DataType::Symbol;
+}
+
+// arguments given as a list are applied one by one
+// anything else is a single argument
+fn split_arguments(value: &SELValue) -> Vec<SELValue> {
+    return match value.get_value() {
+        Some(ValueData::List(list)) => list.get_values().clone(),
+        Some(ValueData::AssociativeList(list)) => list.get_list().get_values().clone(),
+        _ => vec![value.clone()],
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sel_types::symbol::Symbol;
+
+    fn int_list(values: Vec<SELValue>) -> SELValue {
+        let mut list = List::new();
+
+        for value in values {
+            list.push(value);
+        }
+
+        return SELValue::new_from_list(list);
+    }
+
+    fn ints(values: &[SELValue]) -> Vec<i64> {
+        return values
+            .iter()
+            .map(|value| from_value_data(value.get_value().unwrap()))
+            .collect();
+    }
+
+    #[test]
+    fn applies_single_value() {
+        let partial = PartialApplication::new("clamp").apply(&SELValue::new_from_int(10));
+
+        assert_eq!(partial.get_function(), "clamp");
+        assert_eq!(ints(partial.get_arguments()), vec![10]);
+    }
+
+    #[test]
+    fn applies_into_skipped_slot() {
+        let partial = PartialApplication::new("clamp")
+            .apply(&int_list(vec![SELValue::new(), SELValue::new_from_int(5)]))
+            .apply(&int_list(vec![
+                SELValue::new_from_int(10),
+                SELValue::new_from_int(15),
+            ]));
+
+        assert_eq!(ints(partial.get_arguments()), vec![10, 5, 15]);
+    }
+
+    #[test]
+    fn applies_last() {
+        let partial = PartialApplication::new("clamp")
+            .apply(&int_list(vec![SELValue::new(), SELValue::new_from_int(5)]))
+            .apply_last(&SELValue::new_from_int(10));
+
+        assert_eq!(partial.get_arguments().len(), 3);
+        assert_eq!(partial.get_arguments()[0].get_type(), DataType::Unit);
+        assert_eq!(ints(&partial.get_arguments()[1..]), vec![5, 10]);
+    }
+
+    #[test]
+    fn applies_named() {
+        let partial = PartialApplication::new("clamp").apply(&SELValue::new_from_pair(Pair::new(
+            SELValue::new_from_symbol(Symbol::new(String::from("min"), 0)),
+            SELValue::new_from_int(5),
+        )));
+
+        assert_eq!(partial.get_arguments().len(), 0);
+        assert_eq!(partial.get_named_arguments().len(), 1);
+
+        let call_value = partial.get_call_value();
+
+        assert_eq!(call_value.get_type(), DataType::AssociativeList);
+
+        let list: AssociativeList = from_value_data(call_value.get_value().unwrap());
+
+        assert_eq!(
+            from_value_data::<i64>(
+                list.get_by_association_index(0)
+                    .unwrap()
+                    .get_value()
+                    .unwrap()
+            ),
+            5
+        );
+    }
+
+    #[test]
+    fn call_value_shapes() {
+        let partial = PartialApplication::new("clamp");
+
+        assert_eq!(partial.get_call_value().get_type(), DataType::Unit);
+
+        let partial = partial.apply(&SELValue::new_from_int(1));
+
+        assert_eq!(partial.get_call_value().get_type(), DataType::Integer);
+
+        let partial = partial.apply(&SELValue::new_from_int(2));
+
+        assert_eq!(partial.get_call_value().get_type(), DataType::List);
+    }
+}
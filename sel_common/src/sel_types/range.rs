@@ -1,6 +1,6 @@
 use crate::{DataType, SELValue};
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Range {
     lower: i64,
     upper: i64,
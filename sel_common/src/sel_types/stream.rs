@@ -1,57 +1,174 @@
 use crate::sel_types::list::List;
-use crate::{from_byte_vec, DataType, SELValue};
+use crate::{DataType, SELValue, ValueData};
 use std::path::Iter;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+// combined streams have no processor
+// their source is a list of the streams being combined
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SELStream {
     source: SELValue,
-    processor: usize,
+    processor: Option<usize>,
 }
 
 impl SELStream {
     pub fn new(source: SELValue, processor: usize) -> Self {
-        return SELStream { source, processor };
+        return SELStream {
+            source,
+            processor: Some(processor),
+        };
+    }
+
+    pub fn combined(sources: List) -> Self {
+        return SELStream {
+            source: SELValue::new_from_list(sources),
+            processor: None,
+        };
     }
 
     pub fn empty() -> Self {
         return SELStream {
             source: SELValue::new(),
-            processor: 0,
+            processor: None,
         };
     }
 
+    // raw source values, processor is not run
+    // executor evaluates the processor for each of these
     pub fn iter(&self) -> SELStreamIterator {
-        return SELStreamIterator {
-            source: self.source.clone(),
-            current: 0,
-        };
+        return SELStreamIterator::new(self.source.clone());
     }
 
-    pub fn get_processor_root(&self) -> usize {
+    pub fn get_source(&self) -> &SELValue {
+        return &self.source;
+    }
+
+    pub fn get_processor_root(&self) -> Option<usize> {
         return self.processor;
     }
+
+    pub fn is_combined(&self) -> bool {
+        return self.processor.is_none() && self.source.get_type() == DataType::List;
+    }
 }
 
 pub struct SELStreamIterator {
     source: SELValue,
     current: usize,
+    // byte offset of the next character when streaming a string
+    offset: usize,
+}
+
+impl SELStreamIterator {
+    // iterates values of a list, associative list, range or string
+    // strings produce each character as a string
+    // any other value produces no values
+    pub fn new(source: SELValue) -> Self {
+        return SELStreamIterator {
+            source,
+            current: 0,
+            offset: 0,
+        };
+    }
 }
 
 impl Iterator for SELStreamIterator {
     type Item = SELValue;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.source.get_type() {
-            DataType::List => {
-                let list: List = from_byte_vec(self.source.get_value().unwrap());
-
-                let val = list.get_values().get(self.current).map(|v| v.to_owned());
-
-                self.current += 1;
+        // items are read from the shared source, only the item is copied
+        let val = match self.source.get_value() {
+            Some(ValueData::List(list)) => list.get_values().get(self.current).cloned(),
+            Some(ValueData::AssociativeList(list)) => list.get_by_index(self.current),
+            Some(ValueData::Range(range)) => {
+                let value = range.get_lower() + self.current as i64;
 
-                val
+                if value < range.get_upper() {
+                    Some(SELValue::new_from_int(value))
+                } else {
+                    None
+                }
+            }
+            Some(ValueData::String(s)) => {
+                match s.get(self.offset..).and_then(|rest| rest.chars().next()) {
+                    Some(c) => {
+                        self.offset += c.len_utf8();
+                        Some(SELValue::new_from_string(&c.to_string()))
+                    }
+                    None => None,
+                }
             }
             _ => None,
-        }
+        };
+
+        self.current += 1;
+
+        val
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_value_data;
+    use crate::sel_types::associative_list::AssociativeList;
+    use crate::sel_types::pair::Pair;
+    use crate::sel_types::symbol::Symbol;
+
+    fn collect_ints(iter: SELStreamIterator) -> Vec<i64> {
+        return iter
+            .map(|value| from_value_data::<i64>(value.get_value().unwrap()))
+            .collect();
+    }
+
+    #[test]
+    fn iterates_list() {
+        let mut list = List::new();
+        list.push(SELValue::new_from_int(10));
+        list.push(SELValue::new_from_int(20));
+
+        let iter = SELStreamIterator::new(SELValue::new_from_list(list));
+
+        assert_eq!(collect_ints(iter), vec![10, 20]);
+    }
+
+    #[test]
+    fn iterates_range() {
+        let iter = SELStreamIterator::new(SELValue::new_from_range(3, 6));
+
+        assert_eq!(collect_ints(iter), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn iterates_associative_list() {
+        let mut list = AssociativeList::new();
+        list.push(SELValue::new_from_int(10));
+        list.push(SELValue::new_from_pair(Pair::new(
+            SELValue::new_from_symbol(Symbol::new(String::from("value"), 0)),
+            SELValue::new_from_int(20),
+        )));
+
+        let values: Vec<SELValue> =
+            SELStreamIterator::new(SELValue::new_from_associative_list(list)).collect();
+
+        assert_eq!(values.len(), 2);
+        assert_eq!(values.get(0).unwrap().get_type(), DataType::Integer);
+        assert_eq!(values.get(1).unwrap().get_type(), DataType::Pair);
+    }
+
+    #[test]
+    fn iterates_string() {
+        let values: Vec<String> =
+            SELStreamIterator::new(SELValue::new_from_string(&String::from("héy")))
+                .map(|value| from_value_data::<String>(value.get_value().unwrap()))
+                .collect();
+
+        assert_eq!(values, vec!["h", "é", "y"]);
+    }
+
+    #[test]
+    fn iterates_nothing_for_single_value() {
+        let iter = SELStreamIterator::new(SELValue::new_from_int(10));
+
+        assert_eq!(collect_ints(iter), Vec::<i64>::new());
     }
 }
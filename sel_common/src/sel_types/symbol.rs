@@ -1,4 +1,4 @@
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Symbol {
     identifier: String,
     table_index: usize,
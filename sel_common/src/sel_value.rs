@@ -1,16 +1,20 @@
-use super::{from_byte_vec, to_byte_vec, DataType};
+use super::{from_value_data, to_value_data, DataType, ValueData};
 use crate::sel_types::associative_list::AssociativeList;
+use crate::sel_types::error::SELRuntimeError;
 use crate::sel_types::list::List;
 use crate::sel_types::pair::Pair;
+use crate::sel_types::partial_application::PartialApplication;
 use crate::sel_types::range::Range;
 use crate::sel_types::symbol::Symbol;
+use bincode::{deserialize, serialize};
 use core::fmt::Debug;
 use std::fmt;
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct SELValue {
+    // unit and unknown values have no payload to tell them apart
     data_type: DataType,
-    value: Option<Vec<u8>>,
+    value: Option<ValueData>,
 }
 
 impl SELValue {
@@ -28,7 +32,7 @@ impl SELValue {
         };
     }
 
-    pub fn new_from_raw(data_type: DataType, v: Option<Vec<u8>>) -> Self {
+    pub fn new_from_raw(data_type: DataType, v: Option<ValueData>) -> Self {
         return SELValue {
             data_type,
             value: v,
@@ -38,64 +42,88 @@ impl SELValue {
     pub fn new_from_int(num: i64) -> Self {
         return SELValue {
             data_type: DataType::Integer,
-            value: Some(to_byte_vec(num)),
+            value: Some(to_value_data(num)),
         };
     }
 
     pub fn new_from_decimal(num: f64) -> Self {
         return SELValue {
             data_type: DataType::Decimal,
-            value: Some(to_byte_vec(num)),
+            value: Some(to_value_data(num)),
         };
     }
 
     pub fn new_from_string(s: &String) -> Self {
         return SELValue {
             data_type: DataType::String,
-            value: Some(to_byte_vec(s)),
+            value: Some(to_value_data(s)),
         };
     }
 
     pub fn new_from_boolean(b: bool) -> Self {
         return SELValue {
             data_type: DataType::Boolean,
-            value: Some(to_byte_vec(b)),
+            value: Some(to_value_data(b)),
         };
     }
 
     pub fn new_from_range(lower: i64, upper: i64) -> Self {
         return SELValue {
             data_type: DataType::Range,
-            value: Some(to_byte_vec(Range::new(lower, upper))),
+            value: Some(to_value_data(Range::new(lower, upper))),
         };
     }
 
     pub fn new_from_pair(pair: Pair) -> Self {
         return SELValue {
             data_type: DataType::Pair,
-            value: Some(to_byte_vec(pair)),
+            value: Some(to_value_data(pair)),
         };
     }
 
     pub fn new_from_symbol(symbol: Symbol) -> Self {
         return SELValue {
             data_type: DataType::Symbol,
-            value: Some(to_byte_vec(symbol)),
+            value: Some(to_value_data(symbol)),
         };
     }
 
     pub fn new_from_list(list: List) -> Self {
         return SELValue {
             data_type: DataType::List,
-            value: Some(to_byte_vec(list)),
+            value: Some(to_value_data(list)),
         };
     }
 
     pub fn new_from_associative_list(list: AssociativeList) -> Self {
-        println!("new {:?}", list);
         return SELValue {
             data_type: DataType::AssociativeList,
-            value: Some(to_byte_vec(list)),
+            value: Some(to_value_data(list)),
+        };
+    }
+
+    pub fn new_from_partial_application(partial: PartialApplication) -> Self {
+        return SELValue {
+            data_type: DataType::PartialApplication,
+            value: Some(to_value_data(partial)),
+        };
+    }
+
+    pub fn new_from_error(error: SELRuntimeError) -> Self {
+        return SELValue {
+            data_type: DataType::Error,
+            value: Some(to_value_data(error)),
+        };
+    }
+
+    pub fn is_error(&self) -> bool {
+        return self.data_type == DataType::Error;
+    }
+
+    pub fn get_error(&self) -> Option<SELRuntimeError> {
+        return match (self.data_type, &self.value) {
+            (DataType::Error, Some(v)) => Some(from_value_data(v)),
+            _ => None,
         };
     }
 
@@ -103,12 +131,21 @@ impl SELValue {
         return self.data_type;
     }
 
-    pub fn get_value(&self) -> Option<&Vec<u8>> {
+    pub fn get_value(&self) -> Option<&ValueData> {
         return match &self.value {
             Some(v) => Some(&v),
             None => None,
         };
     }
+
+    // values are only encoded when they leave the process
+    pub fn to_bytes(&self) -> Vec<u8> {
+        return serialize(self).unwrap_or(vec![]);
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        return deserialize(bytes).ok();
+    }
 }
 
 impl std::fmt::Debug for SELValue {
@@ -116,7 +153,7 @@ impl std::fmt::Debug for SELValue {
         match self.data_type {
             DataType::Symbol => {
                 let val = self.get_value();
-                let symbol: Symbol = from_byte_vec(val.unwrap());
+                let symbol: Symbol = from_value_data(val.unwrap());
                 write!(
                     f,
                     "{:?}({}) - :{}",
@@ -137,20 +174,20 @@ impl std::fmt::Display for SELValue {
         let val = self.get_value();
 
         let val_str = match self.data_type {
-            DataType::String => format!("\"{}\"", from_byte_vec::<String>(val.unwrap())),
-            DataType::Integer => format!("{}", from_byte_vec::<i32>(val.unwrap())),
-            DataType::Decimal => format!("{}", from_byte_vec::<f64>(val.unwrap())),
-            DataType::Boolean => format!("{}", from_byte_vec::<bool>(val.unwrap())),
+            DataType::String => format!("\"{}\"", from_value_data::<String>(val.unwrap())),
+            DataType::Integer => format!("{}", from_value_data::<i64>(val.unwrap())),
+            DataType::Decimal => format!("{}", from_value_data::<f64>(val.unwrap())),
+            DataType::Boolean => format!("{}", from_value_data::<bool>(val.unwrap())),
             DataType::Symbol => {
-                let symbol: Symbol = from_byte_vec(val.unwrap());
+                let symbol: Symbol = from_value_data(val.unwrap());
                 format!(":{}", symbol.get_identifier())
             }
             DataType::Range => {
-                let range: Range = from_byte_vec(val.unwrap());
+                let range: Range = from_value_data(val.unwrap());
                 format!("{}..{}", range.get_lower(), range.get_upper())
             }
             DataType::Pair => {
-                let pair: Pair = from_byte_vec(val.unwrap());
+                let pair: Pair = from_value_data(val.unwrap());
                 format!(
                     "{} = {}",
                     wrap_format(&pair.get_left(), DataType::Pair),
@@ -158,7 +195,7 @@ impl std::fmt::Display for SELValue {
                 )
             }
             DataType::List => {
-                let list: List = from_byte_vec(val.unwrap());
+                let list = val.and_then(ValueData::as_list).unwrap();
                 let mut item_strs: Vec<String> = vec![];
 
                 for item in list.get_values() {
@@ -168,7 +205,7 @@ impl std::fmt::Display for SELValue {
                 format!("{}", item_strs.join(", "))
             }
             DataType::AssociativeList => {
-                let list: AssociativeList = from_byte_vec(val.unwrap());
+                let list = val.and_then(ValueData::as_associative_list).unwrap();
                 let mut item_strs: Vec<String> = vec![];
 
                 for item in list.get_list().get_values() {
@@ -178,6 +215,14 @@ impl std::fmt::Display for SELValue {
                 format!("[ {} ]", item_strs.join(", "))
             }
             DataType::Unit => String::from("()"),
+            DataType::PartialApplication => {
+                let partial: PartialApplication = from_value_data(val.unwrap());
+                format!("{} ~ ({})", partial.get_function(), partial.get_call_value())
+            }
+            DataType::Error => {
+                let error: SELRuntimeError = from_value_data(val.unwrap());
+                format!("{}", error)
+            }
             _ => none_str,
         };
 
@@ -199,6 +244,8 @@ fn wrap_format(value: &SELValue, data_type: DataType) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sel_types::error::RuntimeErrorKind;
+    use crate::Operation;
 
     #[test]
     fn display_str() {
@@ -341,6 +388,51 @@ mod tests {
         assert_eq!(formatted, "()");
     }
 
+    #[test]
+    fn display_error() {
+        let result = SELValue::new_from_error(SELRuntimeError::new(
+            RuntimeErrorKind::DivideByZero,
+            Operation::Division,
+            vec![DataType::Integer, DataType::Integer],
+            String::from("cannot divide by zero"),
+        ));
+
+        let formatted = format!("{}", result);
+
+        assert_eq!(
+            formatted,
+            "divide by zero in Division(Integer, Integer): cannot divide by zero"
+        );
+    }
+
+    #[test]
+    fn get_error() {
+        let result = SELValue::new_from_error(SELRuntimeError::new(
+            RuntimeErrorKind::Overflow,
+            Operation::Addition,
+            vec![DataType::Integer, DataType::Integer],
+            String::from("integer overflow"),
+        ));
+
+        let error = result.get_error().unwrap();
+
+        assert!(result.is_error());
+        assert_eq!(error.get_kind(), RuntimeErrorKind::Overflow);
+        assert_eq!(error.get_operation(), Operation::Addition);
+        assert_eq!(
+            error.get_operand_types(),
+            &vec![DataType::Integer, DataType::Integer]
+        );
+    }
+
+    #[test]
+    fn get_error_of_non_error() {
+        let result = SELValue::new_from_int(10);
+
+        assert!(!result.is_error());
+        assert!(result.get_error().is_none());
+    }
+
     #[test]
     fn debug_str() {
         let result = SELValue::new_from_string(&String::from("Hello World"));
@@ -484,4 +576,43 @@ mod tests {
 
         assert_eq!(formatted, "Unit - ()");
     }
+
+    #[test]
+    fn clone_shares_list() {
+        let mut list = List::new();
+        list.push(SELValue::new_from_int(10));
+
+        let value = SELValue::new_from_list(list);
+        let cloned = value.clone();
+
+        match (value.get_value(), cloned.get_value()) {
+            (Some(ValueData::List(original)), Some(ValueData::List(copy))) => {
+                assert!(std::sync::Arc::ptr_eq(original, copy))
+            }
+            _ => panic!("Expected list values"),
+        }
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let mut list = List::new();
+        list.push(SELValue::new_from_string(&String::from("value")));
+        list.push(SELValue::new_from_decimal(2.5));
+        list.push(SELValue::new_from_pair(Pair::new(
+            SELValue::new_from_symbol(Symbol::new(String::from("field"), 0)),
+            SELValue::new_from_int(50),
+        )));
+
+        let value = SELValue::new_from_list(list);
+
+        let decoded = SELValue::from_bytes(&value.to_bytes()).unwrap();
+
+        assert!(decoded == value);
+        assert_eq!(format!("{}", decoded), "\"value\", 2.5, :field = 50");
+    }
+
+    #[test]
+    fn from_invalid_bytes() {
+        assert!(SELValue::from_bytes(&[255, 255, 255]).is_none());
+    }
 }
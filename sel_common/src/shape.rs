@@ -0,0 +1,437 @@
+use crate::annotation::{Annotation, AnnotationArgument};
+use crate::{DataType, SELValue, ValueData};
+use std::collections::HashMap;
+use std::fmt;
+
+// names of types in shapes, first name of a type is used when displaying it
+const TYPE_NAMES: [(&str, DataType); 12] = [
+    ("int", DataType::Integer),
+    ("integer", DataType::Integer),
+    ("decimal", DataType::Decimal),
+    ("string", DataType::String),
+    ("bool", DataType::Boolean),
+    ("boolean", DataType::Boolean),
+    ("unit", DataType::Unit),
+    ("symbol", DataType::Symbol),
+    ("range", DataType::Range),
+    ("pair", DataType::Pair),
+    ("list", DataType::List),
+    ("expression", DataType::Expression),
+];
+
+// values described by @Shape, @Input and @Result
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    // `any`, or a value that could not be inferred
+    Any,
+    Type(DataType),
+    // `[int, string, bool]`
+    Tuple(Vec<Shape>),
+    // `[int...]`
+    Repeated(Box<Shape>),
+    // `[name: string, age: int]`
+    Fields(Vec<(String, Shape)>),
+}
+
+impl Shape {
+    // type of the values of this shape
+    // bracketed shapes are lists, shapes with fields are associative lists
+    pub fn get_data_type(&self) -> DataType {
+        return match self {
+            Shape::Any => DataType::Unknown,
+            Shape::Type(data_type) => *data_type,
+            Shape::Tuple(_) | Shape::Repeated(_) => DataType::List,
+            Shape::Fields(_) => DataType::AssociativeList,
+        };
+    }
+
+    // if values of the type may be of this shape
+    // integers are accepted as decimals
+    // and bracketed values may be lists or associative lists
+    pub fn accepts_type(&self, data_type: DataType) -> bool {
+        return match (self, data_type) {
+            (Shape::Any, _) | (_, DataType::Unknown) => true,
+            (Shape::Type(DataType::Decimal), DataType::Integer) => true,
+            (Shape::Type(expected), _) => *expected == data_type,
+            (Shape::Tuple(_), _) | (Shape::Repeated(_), _) => {
+                data_type == DataType::List || data_type == DataType::AssociativeList
+            }
+            (Shape::Fields(_), _) => data_type == DataType::AssociativeList,
+        };
+    }
+}
+
+// step from a value to one of its items
+#[derive(PartialEq, Debug, Clone)]
+pub enum PathSegment {
+    Field(String),
+    Index(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            PathSegment::Field(name) => write!(f, ".{}", name),
+            PathSegment::Index(index) => write!(f, "[{}]", index),
+        };
+    }
+}
+
+// value that doesn't match a shape
+#[derive(PartialEq, Debug, Clone)]
+pub struct ShapeError {
+    message: String,
+    // field names and list indexes leading to the value that failed
+    path: Vec<PathSegment>,
+}
+
+impl ShapeError {
+    pub fn new(message: String) -> Self {
+        return ShapeError {
+            message,
+            path: vec![],
+        };
+    }
+
+    pub fn get_message(&self) -> &String {
+        return &self.message;
+    }
+
+    pub fn get_path(&self) -> &Vec<PathSegment> {
+        return &self.path;
+    }
+
+    // segments are added while unwinding so outer ones go first
+    fn at(mut self, segment: PathSegment) -> Self {
+        self.path.insert(0, segment);
+        return self;
+    }
+}
+
+// path starts at the input, i.e. $.address.zip or $.scores[1]
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path: String = self
+            .path
+            .iter()
+            .map(|segment| segment.to_string())
+            .collect();
+
+        write!(f, "${}: {}", path, self.message)
+    }
+}
+
+impl std::error::Error for ShapeError {}
+
+// items of lists and associative lists
+fn values_of(value: &SELValue) -> Option<&[SELValue]> {
+    return match value.get_value() {
+        Some(ValueData::List(list)) => Some(list.get_values()),
+        Some(ValueData::AssociativeList(list)) => Some(list.get_list().get_values()),
+        _ => None,
+    };
+}
+
+impl Shape {
+    // stops at the first value that doesn't match
+    pub fn validate(&self, value: &SELValue) -> Result<(), ShapeError> {
+        if !self.accepts_type(value.get_type()) {
+            return Err(ShapeError::new(format!(
+                "expected {}, found {}",
+                self,
+                value.get_type()
+            )));
+        }
+
+        return match self {
+            Shape::Any | Shape::Type(_) => Ok(()),
+            Shape::Tuple(shapes) => {
+                let values = values_of(value).unwrap_or(&[]);
+
+                if values.len() != shapes.len() {
+                    return Err(ShapeError::new(format!(
+                        "expected {} values, found {}",
+                        shapes.len(),
+                        values.len()
+                    )));
+                }
+
+                for (index, (shape, value)) in shapes.iter().zip(values.iter()).enumerate() {
+                    shape
+                        .validate(value)
+                        .map_err(|error| error.at(PathSegment::Index(index)))?;
+                }
+
+                Ok(())
+            }
+            Shape::Repeated(shape) => {
+                for (index, value) in values_of(value).unwrap_or(&[]).iter().enumerate() {
+                    shape
+                        .validate(value)
+                        .map_err(|error| error.at(PathSegment::Index(index)))?;
+                }
+
+                Ok(())
+            }
+            Shape::Fields(fields) => {
+                let list = match value.get_value().and_then(ValueData::as_associative_list) {
+                    Some(list) => list,
+                    None => return Ok(()),
+                };
+
+                for (name, shape) in fields {
+                    match list.get_by_identifier(name) {
+                        Some(value) => shape
+                            .validate(&value)
+                            .map_err(|error| error.at(PathSegment::Field(name.clone())))?,
+                        None => {
+                            return Err(ShapeError::new(format!("missing field '{}'", name)));
+                        }
+                    }
+                }
+
+                Ok(())
+            }
+        };
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Shape::Any => write!(f, "any"),
+            Shape::Type(data_type) => match TYPE_NAMES.iter().find(|(_, t)| t == data_type) {
+                Some((name, _)) => write!(f, "{}", name),
+                None => write!(f, "{}", data_type),
+            },
+            Shape::Tuple(shapes) => {
+                let shapes: Vec<String> = shapes.iter().map(|shape| shape.to_string()).collect();
+                write!(f, "[{}]", shapes.join(", "))
+            }
+            Shape::Repeated(shape) => write!(f, "[{}...]", shape),
+            Shape::Fields(fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|(name, shape)| format!("{}: {}", name, shape))
+                    .collect();
+                write!(f, "[{}]", fields.join(", "))
+            }
+        };
+    }
+}
+
+// shapes named with @Shape(Name, shape)
+// used to resolve names in the arguments of other annotations
+pub struct ShapeDefinitions<'a> {
+    definitions: HashMap<&'a str, &'a AnnotationArgument>,
+}
+
+impl<'a> ShapeDefinitions<'a> {
+    // later definitions of a name replace earlier ones
+    pub fn from_annotations(annotations: &'a [Annotation]) -> Self {
+        let mut definitions = HashMap::new();
+
+        for annotation in annotations.iter().filter(|a| a.get_name() == "Shape") {
+            if let [AnnotationArgument::Identifier(name), shape] =
+                annotation.get_arguments().as_slice()
+            {
+                definitions.insert(name.as_str(), shape);
+            }
+        }
+
+        return ShapeDefinitions { definitions };
+    }
+
+    // shape given as the only argument of an annotation, i.e. @Input([int...])
+    pub fn resolve_annotation(&self, annotation: &Annotation) -> Result<Shape, String> {
+        return match annotation.get_arguments().as_slice() {
+            [argument] => self.resolve(argument),
+            _ => Err(format!(
+                "annotation '{}' requires a single shape",
+                annotation.get_name()
+            )),
+        };
+    }
+
+    pub fn resolve(&self, argument: &AnnotationArgument) -> Result<Shape, String> {
+        return self.resolve_with(argument, &mut vec![]);
+    }
+
+    // resolving is a list of names being resolved, to catch shapes that contain themselves
+    fn resolve_with(
+        &self,
+        argument: &AnnotationArgument,
+        resolving: &mut Vec<String>,
+    ) -> Result<Shape, String> {
+        return match argument {
+            AnnotationArgument::Unit => Ok(Shape::Type(DataType::Unit)),
+            AnnotationArgument::Identifier(name) if name == "any" => Ok(Shape::Any),
+            AnnotationArgument::Identifier(name) => {
+                if let Some((_, data_type)) = TYPE_NAMES.iter().find(|(n, _)| n == name) {
+                    return Ok(Shape::Type(*data_type));
+                }
+
+                let definition = match self.definitions.get(name.as_str()) {
+                    Some(definition) => *definition,
+                    None => return Err(format!("unknown shape '{}'", name)),
+                };
+
+                if resolving.contains(name) {
+                    return Err(format!("shape '{}' contains itself", name));
+                }
+
+                resolving.push(name.clone());
+                let shape = self.resolve_with(definition, resolving);
+                resolving.pop();
+
+                shape
+            }
+            AnnotationArgument::List(items) => match items.as_slice() {
+                [AnnotationArgument::Repeated(item)] => Ok(Shape::Repeated(Box::new(
+                    self.resolve_with(item, resolving)?,
+                ))),
+                _ if items
+                    .iter()
+                    .all(|item| matches!(item, AnnotationArgument::Field(_, _)))
+                    && !items.is_empty() =>
+                {
+                    let mut fields = vec![];
+
+                    for item in items {
+                        if let AnnotationArgument::Field(name, shape) = item {
+                            fields.push((name.clone(), self.resolve_with(shape, resolving)?));
+                        }
+                    }
+
+                    Ok(Shape::Fields(fields))
+                }
+                _ => {
+                    let mut shapes = vec![];
+
+                    for item in items {
+                        match item {
+                            AnnotationArgument::Field(name, _) => {
+                                return Err(format!(
+                                    "field '{}' in a shape of values without names",
+                                    name
+                                ))
+                            }
+                            AnnotationArgument::Repeated(_) => {
+                                return Err(String::from(
+                                    "repeated values must be the only item of a shape",
+                                ))
+                            }
+                            item => shapes.push(self.resolve_with(item, resolving)?),
+                        }
+                    }
+
+                    Ok(Shape::Tuple(shapes))
+                }
+            },
+            _ => Err(String::from("invalid shape")),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identifier(name: &str) -> AnnotationArgument {
+        return AnnotationArgument::Identifier(String::from(name));
+    }
+
+    fn field(name: &str, argument: AnnotationArgument) -> AnnotationArgument {
+        return AnnotationArgument::Field(String::from(name), Box::new(argument));
+    }
+
+    fn shape_annotation(name: &str, argument: AnnotationArgument) -> Annotation {
+        let mut annotation = Annotation::new(String::from("Shape"));
+        annotation.set_arguments(vec![identifier(name), argument]);
+        return annotation;
+    }
+
+    #[test]
+    fn resolves_named_shapes() {
+        let annotations = vec![
+            shape_annotation(
+                "Address",
+                AnnotationArgument::List(vec![field("zip", identifier("int"))]),
+            ),
+            shape_annotation(
+                "User",
+                AnnotationArgument::List(vec![
+                    field("name", identifier("string")),
+                    field("address", identifier("Address")),
+                ]),
+            ),
+        ];
+        let definitions = ShapeDefinitions::from_annotations(&annotations);
+
+        let shape = definitions.resolve(&identifier("User")).unwrap();
+
+        assert_eq!(
+            shape,
+            Shape::Fields(vec![
+                (String::from("name"), Shape::Type(DataType::String)),
+                (
+                    String::from("address"),
+                    Shape::Fields(vec![(String::from("zip"), Shape::Type(DataType::Integer))])
+                ),
+            ])
+        );
+        assert_eq!(shape.to_string(), "[name: string, address: [zip: int]]");
+    }
+
+    #[test]
+    fn resolves_tuples_and_repeated_values() {
+        let definitions = ShapeDefinitions::from_annotations(&[]);
+
+        assert_eq!(
+            definitions
+                .resolve(&AnnotationArgument::List(vec![
+                    identifier("int"),
+                    identifier("string")
+                ]))
+                .unwrap()
+                .to_string(),
+            "[int, string]"
+        );
+        assert_eq!(
+            definitions
+                .resolve(&AnnotationArgument::List(vec![
+                    AnnotationArgument::Repeated(Box::new(identifier("decimal")))
+                ]))
+                .unwrap()
+                .to_string(),
+            "[decimal...]"
+        );
+    }
+
+    #[test]
+    fn reports_invalid_shapes() {
+        let annotations = vec![shape_annotation("Loop", identifier("Loop"))];
+        let definitions = ShapeDefinitions::from_annotations(&annotations);
+
+        assert_eq!(
+            definitions.resolve(&identifier("Loop")),
+            Err(String::from("shape 'Loop' contains itself"))
+        );
+        assert_eq!(
+            definitions.resolve(&identifier("Other")),
+            Err(String::from("unknown shape 'Other'"))
+        );
+        assert_eq!(
+            definitions.resolve(&AnnotationArgument::Integer(5)),
+            Err(String::from("invalid shape"))
+        );
+    }
+
+    #[test]
+    fn accepts_types() {
+        assert!(Shape::Type(DataType::Decimal).accepts_type(DataType::Integer));
+        assert!(!Shape::Type(DataType::Integer).accepts_type(DataType::Decimal));
+        assert!(Shape::Repeated(Box::new(Shape::Any)).accepts_type(DataType::AssociativeList));
+        assert!(!Shape::Fields(vec![]).accepts_type(DataType::List));
+    }
+}
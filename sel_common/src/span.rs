@@ -0,0 +1,165 @@
+use std::fmt;
+
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Location {
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Location {
+    pub fn new(offset: usize, line: usize, column: usize) -> Self {
+        return Location {
+            offset,
+            line,
+            column,
+        };
+    }
+
+    // location of the first character of a source string
+    pub fn start() -> Self {
+        return Location::new(0, 1, 1);
+    }
+
+    pub fn get_offset(&self) -> usize {
+        return self.offset;
+    }
+
+    pub fn get_line(&self) -> usize {
+        return self.line;
+    }
+
+    pub fn get_column(&self) -> usize {
+        return self.column;
+    }
+
+    // location directly after given character
+    pub fn advance(&self, c: char) -> Self {
+        return if c == '\n' {
+            Location::new(self.offset + c.len_utf8(), self.line + 1, 1)
+        } else {
+            Location::new(self.offset + c.len_utf8(), self.line, self.column + 1)
+        };
+    }
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+// start is inclusive, end is exclusive
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Span {
+    start: Location,
+    end: Location,
+}
+
+impl Span {
+    pub fn new(start: Location, end: Location) -> Self {
+        return Span { start, end };
+    }
+
+    pub fn get_start(&self) -> Location {
+        return self.start;
+    }
+
+    pub fn get_end(&self) -> Location {
+        return self.end;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.end.offset - self.start.offset;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    // smallest span covering both spans
+    pub fn merge(&self, other: &Span) -> Self {
+        let start = if other.start.offset < self.start.offset {
+            other.start
+        } else {
+            self.start
+        };
+
+        let end = if other.end.offset > self.end.offset {
+            other.end
+        } else {
+            self.end
+        };
+
+        return Span::new(start, end);
+    }
+
+    pub fn slice<'a>(&self, source: &'a str) -> &'a str {
+        return source
+            .get(self.start.offset..self.end.offset)
+            .unwrap_or("");
+    }
+
+    // format for diagnostics as file:line:col-line:col
+    pub fn display_with_file(&self, file: &str) -> String {
+        return format!("{}:{}", file, self);
+    }
+}
+
+impl std::fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_column() {
+        let location = Location::start().advance('a');
+
+        assert_eq!(location, Location::new(1, 1, 2));
+    }
+
+    #[test]
+    fn advance_line() {
+        let location = Location::start().advance('a').advance('\n');
+
+        assert_eq!(location, Location::new(2, 2, 1));
+    }
+
+    #[test]
+    fn advance_multi_byte() {
+        let location = Location::start().advance('é');
+
+        assert_eq!(location, Location::new(2, 1, 2));
+    }
+
+    #[test]
+    fn merge_spans() {
+        let first = Span::new(Location::new(0, 1, 1), Location::new(1, 1, 2));
+        let second = Span::new(Location::new(4, 1, 5), Location::new(6, 1, 7));
+
+        let merged = second.merge(&first);
+
+        assert_eq!(merged.get_start(), Location::new(0, 1, 1));
+        assert_eq!(merged.get_end(), Location::new(6, 1, 7));
+    }
+
+    #[test]
+    fn slice_source() {
+        let span = Span::new(Location::new(4, 1, 5), Location::new(6, 1, 7));
+
+        assert_eq!(span.slice("5 + 10"), "10");
+    }
+
+    #[test]
+    fn display_span() {
+        let span = Span::new(Location::new(4, 1, 5), Location::new(6, 1, 7));
+
+        assert_eq!(format!("{}", span), "1:5-1:7");
+        assert_eq!(span.display_with_file("main.sel"), "main.sel:1:5-1:7");
+    }
+}
@@ -1,6 +1,6 @@
 use std::vec::Vec;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SELSubTree {
     roots: Vec<usize>,
 }
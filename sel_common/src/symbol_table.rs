@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SymbolTable {
     symbols: Vec<String>,
     key_to_index: HashMap<String, usize>,
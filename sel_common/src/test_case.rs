@@ -0,0 +1,79 @@
+use crate::Span;
+
+// expression flagged with @Test
+// kept apart from the tree's roots, so it is not part of its results
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestCase {
+    // root of the value given in the annotation, i.e. @Test([1, 2, 3])
+    input: Option<usize>,
+    root: usize,
+    // span of the annotation
+    span: Span,
+    // indices of the tree's mocks that apply to this test
+    mocks: Vec<usize>,
+}
+
+impl TestCase {
+    pub fn new(input: Option<usize>, root: usize, span: Span) -> Self {
+        return TestCase {
+            input,
+            root,
+            span,
+            mocks: vec![],
+        };
+    }
+
+    pub fn with_mocks(mut self, mocks: Vec<usize>) -> Self {
+        self.mocks = mocks;
+        return self;
+    }
+
+    pub fn get_input(&self) -> Option<usize> {
+        return self.input;
+    }
+
+    pub fn get_root(&self) -> usize {
+        return self.root;
+    }
+
+    pub fn get_span(&self) -> Span {
+        return self.span;
+    }
+
+    pub fn get_mocks(&self) -> &Vec<usize> {
+        return &self.mocks;
+    }
+}
+
+// @Mock or @MockOnce, i.e. @Mock(get_user_by_id, [:name = "Panda"], "id")
+// replaces a function or named expression while running tests
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mock {
+    name: String,
+    // root of the annotation's arguments
+    // a list of the name, result and optional input
+    arguments: usize,
+    span: Span,
+}
+
+impl Mock {
+    pub fn new(name: String, arguments: usize, span: Span) -> Self {
+        return Mock {
+            name,
+            arguments,
+            span,
+        };
+    }
+
+    pub fn get_name(&self) -> &String {
+        return &self.name;
+    }
+
+    pub fn get_arguments(&self) -> usize {
+        return self.arguments;
+    }
+
+    pub fn get_span(&self) -> Span {
+        return self.span;
+    }
+}
@@ -1,193 +1,278 @@
 use crate::sel_types::associative_list::AssociativeList;
+use crate::sel_types::error::{RuntimeErrorKind, SELRuntimeError};
 use crate::sel_types::expression::Expression;
 use crate::sel_types::list::List;
 use crate::sel_types::pair::Pair;
+use crate::sel_types::partial_application::PartialApplication;
 use crate::sel_types::range::Range;
 use crate::sel_types::stream::SELStream;
 use crate::sel_types::stream_instruction::StreamInstruction;
 use crate::sel_types::symbol::Symbol;
-use bincode::{deserialize, serialize};
+use crate::value_data::ValueData;
+use crate::Operation;
+use std::sync::Arc;
 
-pub trait ToByteVec {
-    fn to_byte_vec(&self) -> Vec<u8>;
+pub trait ToValueData {
+    fn to_value_data(self) -> ValueData;
 }
 
-pub trait FromByteVec {
-    fn from_byte_vec(v: &Vec<u8>) -> Self;
+// payload of a different kind gives the type's default
+pub trait FromValueData {
+    fn from_value_data(v: &ValueData) -> Self;
 }
 
-impl ToByteVec for &String {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for &String {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::String(Arc::from(self.as_str()));
     }
 }
 
-impl FromByteVec for String {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(String::from(""));
+impl FromValueData for String {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::String(s) => String::from(&**s),
+            _ => String::from(""),
+        };
     }
 }
 
-impl ToByteVec for i32 {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for i32 {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::Integer(i64::from(self));
     }
 }
 
-impl FromByteVec for i32 {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(0);
+impl FromValueData for i32 {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::Integer(num) => *num as i32,
+            _ => 0,
+        };
     }
 }
 
-impl ToByteVec for i64 {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for i64 {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::Integer(self);
     }
 }
 
-impl FromByteVec for i64 {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(0);
+impl FromValueData for i64 {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::Integer(num) => *num,
+            _ => 0,
+        };
     }
 }
 
-impl ToByteVec for f64 {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for f64 {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::Decimal(self);
     }
 }
 
-impl FromByteVec for f64 {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(0.0);
+impl FromValueData for f64 {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::Decimal(num) => *num,
+            _ => 0.0,
+        };
     }
 }
 
-impl ToByteVec for usize {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+// indices stored in the data heap
+impl ToValueData for usize {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::Integer(self as i64);
     }
 }
 
-impl FromByteVec for usize {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(0);
+impl FromValueData for usize {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::Integer(num) if *num >= 0 => *num as usize,
+            _ => 0,
+        };
     }
 }
 
-impl ToByteVec for bool {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for bool {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::Boolean(self);
     }
 }
 
-impl FromByteVec for bool {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(false);
+impl FromValueData for bool {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::Boolean(b) => *b,
+            _ => false,
+        };
     }
 }
 
-impl ToByteVec for Pair {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for Range {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::Range(self);
     }
 }
 
-impl FromByteVec for Pair {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(Pair::empty());
+impl FromValueData for Range {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::Range(range) => range.clone(),
+            _ => Range::new(0, 0),
+        };
     }
 }
 
-impl ToByteVec for Range {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for StreamInstruction {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::StreamInstruction(self);
     }
 }
 
-impl FromByteVec for Range {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(Range::new(0, 0));
+impl FromValueData for StreamInstruction {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::StreamInstruction(instruction) => *instruction,
+            _ => StreamInstruction::Close,
+        };
     }
 }
 
-impl ToByteVec for Symbol {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for Pair {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::Pair(Arc::new(self));
     }
 }
 
-impl FromByteVec for Symbol {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(Symbol::new(String::from(""), 0));
+impl FromValueData for Pair {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::Pair(value) => (**value).clone(),
+            _ => Pair::empty(),
+        };
     }
 }
 
-impl ToByteVec for List {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for Symbol {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::Symbol(Arc::new(self));
     }
 }
 
-impl FromByteVec for List {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(List::new());
+impl FromValueData for Symbol {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::Symbol(value) => (**value).clone(),
+            _ => Symbol::new(String::from(""), 0),
+        };
     }
 }
 
-impl ToByteVec for AssociativeList {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for List {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::List(Arc::new(self));
     }
 }
 
-impl FromByteVec for AssociativeList {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(AssociativeList::new());
+// copies the shared list, ValueData::as_list borrows it instead
+impl FromValueData for List {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::List(value) => (**value).clone(),
+            _ => List::new(),
+        };
     }
 }
 
-impl ToByteVec for Expression {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for AssociativeList {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::AssociativeList(Arc::new(self));
     }
 }
 
-impl FromByteVec for Expression {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(Expression::new(None));
+// copies the shared list, ValueData::as_associative_list borrows it instead
+impl FromValueData for AssociativeList {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::AssociativeList(value) => (**value).clone(),
+            _ => AssociativeList::new(),
+        };
     }
 }
 
-impl ToByteVec for SELStream {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for Expression {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::Expression(Arc::new(self));
     }
 }
 
-impl FromByteVec for SELStream {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(SELStream::empty());
+impl FromValueData for Expression {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::Expression(value) => (**value).clone(),
+            _ => Expression::new(None),
+        };
     }
 }
 
-impl ToByteVec for StreamInstruction {
-    fn to_byte_vec(&self) -> Vec<u8> {
-        return serialize(self).unwrap_or(vec![]);
+impl ToValueData for SELStream {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::Stream(Arc::new(self));
     }
 }
 
-impl FromByteVec for StreamInstruction {
-    fn from_byte_vec(v: &Vec<u8>) -> Self {
-        return deserialize(v).unwrap_or(StreamInstruction::Close);
+impl FromValueData for SELStream {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::Stream(value) => (**value).clone(),
+            _ => SELStream::empty(),
+        };
     }
 }
 
-pub fn to_byte_vec<T: ToByteVec>(val: T) -> Vec<u8> {
-    return val.to_byte_vec();
+impl ToValueData for PartialApplication {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::PartialApplication(Arc::new(self));
+    }
+}
+
+impl FromValueData for PartialApplication {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::PartialApplication(value) => (**value).clone(),
+            _ => PartialApplication::new(""),
+        };
+    }
+}
+
+impl ToValueData for SELRuntimeError {
+    fn to_value_data(self) -> ValueData {
+        return ValueData::Error(Arc::new(self));
+    }
+}
+
+impl FromValueData for SELRuntimeError {
+    fn from_value_data(v: &ValueData) -> Self {
+        return match v {
+            ValueData::Error(error) => (**error).clone(),
+            _ => SELRuntimeError::new(
+                RuntimeErrorKind::TypeMismatch,
+                Operation::None,
+                vec![],
+                String::from(""),
+            ),
+        };
+    }
+}
+
+pub fn to_value_data<T: ToValueData>(val: T) -> ValueData {
+    return val.to_value_data();
 }
 
-pub fn from_byte_vec<T: FromByteVec>(v: &Vec<u8>) -> T {
-    return T::from_byte_vec(v);
+pub fn from_value_data<T: FromValueData>(v: &ValueData) -> T {
+    return T::from_value_data(v);
 }
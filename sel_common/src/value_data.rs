@@ -0,0 +1,71 @@
+use crate::sel_types::associative_list::AssociativeList;
+use crate::sel_types::error::SELRuntimeError;
+use crate::sel_types::expression::Expression;
+use crate::sel_types::list::List;
+use crate::sel_types::pair::Pair;
+use crate::sel_types::partial_application::PartialApplication;
+use crate::sel_types::range::Range;
+use crate::sel_types::stream::SELStream;
+use crate::sel_types::stream_instruction::StreamInstruction;
+use crate::sel_types::symbol::Symbol;
+use std::sync::Arc;
+
+// native payload of a value
+// larger payloads are shared so cloning a value is cheap
+// Arc instead of Rc so trees and values can be sent between threads
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ValueData {
+    Integer(i64),
+    Decimal(f64),
+    Boolean(bool),
+    String(Arc<str>),
+    Range(Range),
+    Symbol(Arc<Symbol>),
+    Pair(Arc<Pair>),
+    List(Arc<List>),
+    AssociativeList(Arc<AssociativeList>),
+    Expression(Arc<Expression>),
+    Stream(Arc<SELStream>),
+    StreamInstruction(StreamInstruction),
+    PartialApplication(Arc<PartialApplication>),
+    Error(Arc<SELRuntimeError>),
+}
+
+// borrowed views of shared payloads, so reading a value doesn't copy it
+// None for payloads of other kinds
+impl ValueData {
+    pub fn as_symbol(&self) -> Option<&Symbol> {
+        return match self {
+            ValueData::Symbol(symbol) => Some(symbol),
+            _ => None,
+        };
+    }
+
+    pub fn as_pair(&self) -> Option<&Pair> {
+        return match self {
+            ValueData::Pair(pair) => Some(pair),
+            _ => None,
+        };
+    }
+
+    pub fn as_list(&self) -> Option<&List> {
+        return match self {
+            ValueData::List(list) => Some(list),
+            _ => None,
+        };
+    }
+
+    pub fn as_associative_list(&self) -> Option<&AssociativeList> {
+        return match self {
+            ValueData::AssociativeList(list) => Some(list),
+            _ => None,
+        };
+    }
+
+    pub fn as_expression(&self) -> Option<&Expression> {
+        return match self {
+            ValueData::Expression(expression) => Some(expression),
+            _ => None,
+        };
+    }
+}
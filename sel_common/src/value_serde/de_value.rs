@@ -0,0 +1,377 @@
+use super::ConversionError;
+use crate::sel_types::pair::Pair;
+use crate::{DataType, SELValue, ValueData};
+use serde::de::value::{BorrowedStrDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeSeed, IntoDeserializer, Unexpected, Visitor};
+use std::slice::Iter;
+
+fn unexpected(value: &SELValue) -> Unexpected<'_> {
+    return match value.get_value() {
+        Some(ValueData::Integer(i)) => Unexpected::Signed(*i),
+        Some(ValueData::Decimal(d)) => Unexpected::Float(*d),
+        Some(ValueData::Boolean(b)) => Unexpected::Bool(*b),
+        Some(ValueData::String(s)) => Unexpected::Str(s),
+        Some(ValueData::Symbol(_)) => Unexpected::Other("symbol"),
+        Some(ValueData::Range(_)) => Unexpected::Other("range"),
+        Some(ValueData::Pair(_)) => Unexpected::Other("pair"),
+        Some(ValueData::List(_)) => Unexpected::Seq,
+        Some(ValueData::AssociativeList(_)) => Unexpected::Map,
+        Some(_) => Unexpected::Other("value without data representation"),
+        None => Unexpected::Unit,
+    };
+}
+
+// identifier of a pair's left side when it is a symbol
+fn symbol_key(pair: &Pair) -> Option<&String> {
+    return match pair.get_left().get_value() {
+        Some(ValueData::Symbol(symbol)) => Some(symbol.get_identifier()),
+        _ => None,
+    };
+}
+
+pub struct ValueDeserializer<'de> {
+    value: &'de SELValue,
+}
+
+impl<'de> ValueDeserializer<'de> {
+    pub fn new(value: &'de SELValue) -> Self {
+        return ValueDeserializer { value };
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = ConversionError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, ConversionError>
+    where
+        V: Visitor<'de>,
+    {
+        return match self.value.get_value() {
+            None if self.value.get_type() == DataType::Unit => visitor.visit_unit(),
+            Some(ValueData::Integer(i)) => visitor.visit_i64(*i),
+            Some(ValueData::Decimal(d)) => visitor.visit_f64(*d),
+            Some(ValueData::Boolean(b)) => visitor.visit_bool(*b),
+            Some(ValueData::String(s)) => visitor.visit_borrowed_str(s),
+            Some(ValueData::Symbol(symbol)) => visitor.visit_borrowed_str(symbol.get_identifier()),
+            // ranges read as a tuple of their bounds
+            Some(ValueData::Range(range)) => visitor.visit_seq(SeqDeserializer::new(
+                vec![range.get_lower(), range.get_upper()].into_iter(),
+            )),
+            // symbol keyed pair is a single field
+            Some(ValueData::Pair(pair)) => match symbol_key(pair) {
+                Some(_) => visitor.visit_map(PairAccess::new(pair)),
+                None => visitor.visit_seq(ListAccess::new(
+                    [pair.get_left(), pair.get_right()].to_vec().into_iter(),
+                )),
+            },
+            Some(ValueData::List(list)) => visitor.visit_seq(ListAccess::new(
+                list.get_values().iter().collect::<Vec<_>>().into_iter(),
+            )),
+            Some(ValueData::AssociativeList(list)) => visitor.visit_map(
+                AssociativeListAccess::new(list.get_list().get_values().iter()),
+            ),
+            Some(ValueData::Error(error)) => Err(ConversionError::new(format!(
+                "value is an error, {}",
+                error
+            ))),
+            _ => Err(ConversionError::new(format!(
+                "{} values can not be converted",
+                self.value.get_type()
+            ))),
+        };
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, ConversionError>
+    where
+        V: Visitor<'de>,
+    {
+        return if self.value.get_type() == DataType::Unit {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        };
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ConversionError>
+    where
+        V: Visitor<'de>,
+    {
+        return visitor.visit_newtype_struct(self);
+    }
+
+    // unit variants are symbols or strings
+    // other variants are a pair or single item associative list keyed by variant
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ConversionError>
+    where
+        V: Visitor<'de>,
+    {
+        let pair: &Pair = match self.value.get_value() {
+            Some(ValueData::Symbol(symbol)) => {
+                return visitor.visit_enum(symbol.get_identifier().as_str().into_deserializer())
+            }
+            Some(ValueData::String(s)) => return visitor.visit_enum((&s[..]).into_deserializer()),
+            Some(ValueData::Pair(pair)) => pair,
+            Some(ValueData::AssociativeList(list)) if list.get_list().get_values().len() == 1 => {
+                match list.get_list().get_values()[0].get_value() {
+                    Some(ValueData::Pair(pair)) => pair,
+                    _ => return Err(de::Error::invalid_type(unexpected(self.value), &"an enum")),
+                }
+            }
+            _ => return Err(de::Error::invalid_type(unexpected(self.value), &"an enum")),
+        };
+
+        return match symbol_key(pair) {
+            Some(variant) => visitor.visit_enum(EnumAccess {
+                variant,
+                value: pair.get_right(),
+            }),
+            None => Err(de::Error::invalid_type(
+                unexpected(pair.get_left()),
+                &"a symbol naming an enum variant",
+            )),
+        };
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+// list items, errors are reported with the item's index
+struct ListAccess<'de> {
+    values: std::vec::IntoIter<&'de SELValue>,
+    index: usize,
+}
+
+impl<'de> ListAccess<'de> {
+    fn new(values: std::vec::IntoIter<&'de SELValue>) -> Self {
+        return ListAccess { values, index: 0 };
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for ListAccess<'de> {
+    type Error = ConversionError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, ConversionError>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let value = match self.values.next() {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        let index = self.index;
+        self.index += 1;
+
+        return seed
+            .deserialize(ValueDeserializer::new(value))
+            .map(Some)
+            .map_err(|error| error.at(index.to_string()));
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        return Some(self.values.len());
+    }
+}
+
+// symbol keyed pairs of an associative list
+// errors are reported with the pair's key
+struct AssociativeListAccess<'de> {
+    values: Iter<'de, SELValue>,
+    pair: Option<&'de Pair>,
+}
+
+impl<'de> AssociativeListAccess<'de> {
+    fn new(values: Iter<'de, SELValue>) -> Self {
+        return AssociativeListAccess { values, pair: None };
+    }
+}
+
+impl<'de> de::MapAccess<'de> for AssociativeListAccess<'de> {
+    type Error = ConversionError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, ConversionError>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let value = match self.values.next() {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        let pair: &Pair = match value.get_value() {
+            Some(ValueData::Pair(pair)) => pair,
+            _ => {
+                return Err(de::Error::invalid_type(
+                    unexpected(value),
+                    &"a pair with a symbol key",
+                ))
+            }
+        };
+
+        let key = match symbol_key(pair) {
+            Some(key) => key,
+            None => {
+                return Err(de::Error::invalid_type(
+                    unexpected(pair.get_left()),
+                    &"a symbol key",
+                ))
+            }
+        };
+
+        self.pair = Some(pair);
+
+        return seed
+            .deserialize(BorrowedStrDeserializer::new(key))
+            .map(Some);
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, ConversionError>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let pair = match self.pair.take() {
+            Some(pair) => pair,
+            None => {
+                return Err(ConversionError::new(String::from(
+                    "value read before its key",
+                )))
+            }
+        };
+
+        return seed
+            .deserialize(ValueDeserializer::new(pair.get_right()))
+            .map_err(|error| error.at(symbol_key(pair).cloned().unwrap_or_default()));
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        return Some(self.values.len());
+    }
+}
+
+// single symbol keyed pair read as a map
+struct PairAccess<'de> {
+    pair: &'de Pair,
+    read: bool,
+}
+
+impl<'de> PairAccess<'de> {
+    fn new(pair: &'de Pair) -> Self {
+        return PairAccess { pair, read: false };
+    }
+}
+
+impl<'de> de::MapAccess<'de> for PairAccess<'de> {
+    type Error = ConversionError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, ConversionError>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        if self.read {
+            return Ok(None);
+        }
+
+        self.read = true;
+
+        return match symbol_key(self.pair) {
+            Some(key) => seed
+                .deserialize(BorrowedStrDeserializer::new(key))
+                .map(Some),
+            None => Ok(None),
+        };
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, ConversionError>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        return seed.deserialize(ValueDeserializer::new(self.pair.get_right()));
+    }
+}
+
+struct EnumAccess<'de> {
+    variant: &'de String,
+    value: &'de SELValue,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumAccess<'de> {
+    type Error = ConversionError;
+    type Variant = VariantAccess<'de>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, VariantAccess<'de>), ConversionError>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(BorrowedStrDeserializer::new(self.variant))?;
+
+        return Ok((
+            variant,
+            VariantAccess {
+                variant: self.variant,
+                value: self.value,
+            },
+        ));
+    }
+}
+
+struct VariantAccess<'de> {
+    variant: &'de String,
+    value: &'de SELValue,
+}
+
+impl<'de> de::VariantAccess<'de> for VariantAccess<'de> {
+    type Error = ConversionError;
+
+    fn unit_variant(self) -> Result<(), ConversionError> {
+        return if self.value.get_type() == DataType::Unit {
+            Ok(())
+        } else {
+            Err(de::Error::invalid_type(
+                unexpected(self.value),
+                &"unit variant",
+            ))
+        };
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, ConversionError>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        return seed
+            .deserialize(ValueDeserializer::new(self.value))
+            .map_err(|error| error.at(self.variant.clone()));
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, ConversionError>
+    where
+        V: Visitor<'de>,
+    {
+        return de::Deserializer::deserialize_seq(ValueDeserializer::new(self.value), visitor)
+            .map_err(|error| error.at(self.variant.clone()));
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ConversionError>
+    where
+        V: Visitor<'de>,
+    {
+        return de::Deserializer::deserialize_map(ValueDeserializer::new(self.value), visitor)
+            .map_err(|error| error.at(self.variant.clone()));
+    }
+}
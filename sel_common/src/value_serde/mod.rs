@@ -0,0 +1,339 @@
+use crate::{SELContext, SELValue};
+use serde::{de, ser, Deserialize, Serialize};
+use std::fmt;
+
+mod de_value;
+mod ser_value;
+
+use de_value::ValueDeserializer;
+use ser_value::ValueSerializer;
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct ConversionError {
+    message: String,
+    // field names and list indexes leading to the value that failed
+    path: Vec<String>,
+}
+
+impl ConversionError {
+    pub fn new(message: String) -> Self {
+        return ConversionError {
+            message,
+            path: vec![],
+        };
+    }
+
+    pub fn get_message(&self) -> &String {
+        return &self.message;
+    }
+
+    pub fn get_path(&self) -> &Vec<String> {
+        return &self.path;
+    }
+
+    // segments are added while unwinding so outer ones go first
+    fn at(mut self, segment: String) -> Self {
+        self.path.insert(0, segment);
+        return self;
+    }
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path.join("."), self.message)
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+impl ser::Error for ConversionError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        return ConversionError::new(message.to_string());
+    }
+}
+
+impl de::Error for ConversionError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        return ConversionError::new(message.to_string());
+    }
+}
+
+// structs and maps become associative lists with symbol keys
+// symbols are added to the given context
+// so they are equal to symbols in source compiled with it
+pub fn to_sel_value_with_context<T>(
+    value: &T,
+    context: &mut SELContext,
+) -> Result<SELValue, ConversionError>
+where
+    T: Serialize + ?Sized,
+{
+    return value.serialize(&mut ValueSerializer::new(context));
+}
+
+// symbols get indexes of a new context
+// lookups by key still work since they fall back to the symbol's identifier
+pub fn to_sel_value<T>(value: &T) -> Result<SELValue, ConversionError>
+where
+    T: Serialize + ?Sized,
+{
+    return to_sel_value_with_context(value, &mut SELContext::new());
+}
+
+pub fn from_sel_value<'de, T>(value: &'de SELValue) -> Result<T, ConversionError>
+where
+    T: Deserialize<'de>,
+{
+    return T::deserialize(ValueDeserializer::new(value));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sel_types::associative_list::AssociativeList;
+    use crate::sel_types::error::{RuntimeErrorKind, SELRuntimeError};
+    use crate::sel_types::list::List;
+    use crate::sel_types::pair::Pair;
+    use crate::sel_types::symbol::Symbol;
+    use crate::{from_value_data, DataType, Operation};
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Animal {
+        name: String,
+        legs: u8,
+        weight: f64,
+        tame: bool,
+        owner: Option<String>,
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(i64, i64),
+        Rectangle { width: i64, height: i64 },
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Meters(i64);
+
+    fn panda() -> Animal {
+        return Animal {
+            name: String::from("Panda"),
+            legs: 4,
+            weight: 100.5,
+            tame: false,
+            owner: None,
+        };
+    }
+
+    fn symbol_value(context: &mut SELContext, identifier: &str) -> SELValue {
+        let identifier = String::from(identifier);
+        let index = context.add_symbol(&identifier);
+
+        return SELValue::new_from_symbol(Symbol::new(identifier, index));
+    }
+
+    #[test]
+    fn struct_is_associative_list() {
+        let mut context = SELContext::new();
+
+        let value = to_sel_value_with_context(&panda(), &mut context).unwrap();
+        let list: AssociativeList = from_value_data(value.get_value().unwrap());
+
+        let name_index = *context
+            .get_symbol_table()
+            .get_value(&String::from("name"))
+            .unwrap();
+
+        assert_eq!(value.get_type(), DataType::AssociativeList);
+        assert_eq!(list.get_list().get_values().len(), 5);
+        assert_eq!(
+            list.get_by_association_index(name_index),
+            Some(SELValue::new_from_string(&String::from("Panda")))
+        );
+    }
+
+    #[test]
+    fn none_is_unit() {
+        let value = to_sel_value(&panda()).unwrap();
+        let list: AssociativeList = from_value_data(value.get_value().unwrap());
+
+        let owner = Symbol::new(String::from("owner"), 4);
+
+        assert_eq!(list.get_by_symbol(&owner), Some(SELValue::new()));
+    }
+
+    #[test]
+    fn round_trips_structs() {
+        let value = to_sel_value(&panda()).unwrap();
+
+        assert_eq!(from_sel_value::<Animal>(&value), Ok(panda()));
+    }
+
+    #[test]
+    fn round_trips_enums() {
+        let shapes = vec![
+            Shape::Point,
+            Shape::Circle(2.5),
+            Shape::Line(1, 5),
+            Shape::Rectangle {
+                width: 2,
+                height: 3,
+            },
+        ];
+
+        let value = to_sel_value(&shapes).unwrap();
+
+        assert_eq!(value.get_type(), DataType::List);
+        assert_eq!(from_sel_value::<Vec<Shape>>(&value), Ok(shapes));
+    }
+
+    #[test]
+    fn enum_variants_are_symbols_and_pairs() {
+        let mut context = SELContext::new();
+
+        assert_eq!(
+            to_sel_value_with_context(&Shape::Point, &mut context),
+            Ok(symbol_value(&mut context, "Point"))
+        );
+
+        let circle = to_sel_value_with_context(&Shape::Circle(2.5), &mut context).unwrap();
+
+        assert_eq!(
+            circle,
+            SELValue::new_from_pair(Pair::new(
+                symbol_value(&mut context, "Circle"),
+                SELValue::new_from_decimal(2.5)
+            ))
+        );
+    }
+
+    #[test]
+    fn sequences_and_tuples_are_lists() {
+        let mut list = List::new();
+        list.push(SELValue::new_from_int(1));
+        list.push(SELValue::new_from_string(&String::from("a")));
+        list.push(SELValue::new_from_boolean(true));
+
+        let value = to_sel_value(&(1, "a", true)).unwrap();
+
+        assert_eq!(value, SELValue::new_from_list(list));
+        assert_eq!(
+            from_sel_value::<(i64, String, bool)>(&value),
+            Ok((1, String::from("a"), true))
+        );
+    }
+
+    #[test]
+    fn round_trips_maps_and_newtypes() {
+        let mut map: BTreeMap<String, Vec<Meters>> = BTreeMap::new();
+        map.insert(String::from("short"), vec![Meters(1), Meters(2)]);
+        map.insert(String::from("long"), vec![]);
+
+        let value = to_sel_value(&map).unwrap();
+
+        assert_eq!(value.get_type(), DataType::AssociativeList);
+        assert_eq!(from_sel_value(&value), Ok(map));
+    }
+
+    #[test]
+    fn reads_sel_values() {
+        let mut context = SELContext::new();
+
+        let mut list = AssociativeList::new();
+        list.push(SELValue::new_from_pair(Pair::new(
+            symbol_value(&mut context, "width"),
+            SELValue::new_from_int(2),
+        )));
+        list.push(SELValue::new_from_pair(Pair::new(
+            symbol_value(&mut context, "height"),
+            SELValue::new_from_int(3),
+        )));
+
+        let value = SELValue::new_from_pair(Pair::new(
+            symbol_value(&mut context, "Rectangle"),
+            SELValue::new_from_associative_list(list),
+        ));
+
+        assert_eq!(
+            from_sel_value::<Shape>(&value),
+            Ok(Shape::Rectangle {
+                width: 2,
+                height: 3
+            })
+        );
+        assert_eq!(
+            from_sel_value::<(i64, i64)>(&SELValue::new_from_range(1, 5)),
+            Ok((1, 5))
+        );
+        assert_eq!(
+            from_sel_value::<Shape>(&SELValue::new_from_string(&String::from("Point"))),
+            Ok(Shape::Point)
+        );
+        assert_eq!(from_sel_value::<f64>(&SELValue::new_from_int(2)), Ok(2.0));
+    }
+
+    #[test]
+    fn reports_shape_mismatch_with_path() {
+        let mut animals = List::new();
+        animals.push(to_sel_value(&panda()).unwrap());
+        animals.push(to_sel_value(&Shape::Line(1, 2)).unwrap());
+
+        let error = from_sel_value::<Vec<Animal>>(&SELValue::new_from_list(animals)).unwrap_err();
+
+        assert_eq!(error.get_path(), &vec![String::from("1")]);
+
+        let mut context = SELContext::new();
+        let mut list = AssociativeList::new();
+        list.push(SELValue::new_from_pair(Pair::new(
+            symbol_value(&mut context, "name"),
+            SELValue::new_from_int(5),
+        )));
+
+        let error =
+            from_sel_value::<Animal>(&SELValue::new_from_associative_list(list)).unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "name: invalid type: integer `5`, expected a string"
+        );
+    }
+
+    #[test]
+    fn reports_out_of_range_numbers() {
+        assert!(from_sel_value::<u8>(&SELValue::new_from_int(300)).is_err());
+        assert!(to_sel_value(&u64::MAX).is_err());
+    }
+
+    #[test]
+    fn rejects_non_string_keys() {
+        let mut map: BTreeMap<i64, i64> = BTreeMap::new();
+        map.insert(1, 2);
+
+        assert_eq!(
+            to_sel_value(&map).unwrap_err().get_message(),
+            "map keys must be strings, found Integer"
+        );
+    }
+
+    #[test]
+    fn errors_are_not_converted() {
+        let error = SELValue::new_from_error(SELRuntimeError::new(
+            RuntimeErrorKind::DivideByZero,
+            Operation::IntegerDivision,
+            vec![DataType::Integer, DataType::Integer],
+            String::from("division by zero"),
+        ));
+
+        assert_eq!(
+            from_sel_value::<i64>(&error).unwrap_err().get_message(),
+            "value is an error, divide by zero in IntegerDivision(Integer, Integer): division by zero"
+        );
+    }
+}
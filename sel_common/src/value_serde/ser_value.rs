@@ -0,0 +1,434 @@
+use super::ConversionError;
+use crate::sel_types::associative_list::AssociativeList;
+use crate::sel_types::list::List;
+use crate::sel_types::pair::Pair;
+use crate::sel_types::symbol::Symbol;
+use crate::{DataType, SELContext, SELValue, ValueData};
+use serde::ser::{self, Serialize};
+use std::convert::TryFrom;
+
+pub struct ValueSerializer<'c> {
+    context: &'c mut SELContext,
+}
+
+impl<'c> ValueSerializer<'c> {
+    pub fn new(context: &'c mut SELContext) -> Self {
+        return ValueSerializer { context };
+    }
+
+    fn symbol(&mut self, identifier: &str) -> SELValue {
+        let identifier = String::from(identifier);
+        let index = self.context.add_symbol(&identifier);
+
+        return SELValue::new_from_symbol(Symbol::new(identifier, index));
+    }
+
+    // variants with data are a pair of the variant name and the data
+    fn keyed(&mut self, key: &str, value: SELValue) -> SELValue {
+        return SELValue::new_from_pair(Pair::new(self.symbol(key), value));
+    }
+}
+
+impl<'a, 'c> ser::Serializer for &'a mut ValueSerializer<'c> {
+    type Ok = SELValue;
+    type Error = ConversionError;
+
+    type SerializeSeq = ListSerializer<'a, 'c>;
+    type SerializeTuple = ListSerializer<'a, 'c>;
+    type SerializeTupleStruct = ListSerializer<'a, 'c>;
+    type SerializeTupleVariant = ListSerializer<'a, 'c>;
+    type SerializeMap = AssociativeListSerializer<'a, 'c>;
+    type SerializeStruct = AssociativeListSerializer<'a, 'c>;
+    type SerializeStructVariant = AssociativeListSerializer<'a, 'c>;
+
+    fn serialize_bool(self, v: bool) -> Result<SELValue, ConversionError> {
+        return Ok(SELValue::new_from_boolean(v));
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<SELValue, ConversionError> {
+        return self.serialize_i64(i64::from(v));
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<SELValue, ConversionError> {
+        return self.serialize_i64(i64::from(v));
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<SELValue, ConversionError> {
+        return self.serialize_i64(i64::from(v));
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<SELValue, ConversionError> {
+        return Ok(SELValue::new_from_int(v));
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<SELValue, ConversionError> {
+        return self.serialize_i64(i64::from(v));
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<SELValue, ConversionError> {
+        return self.serialize_i64(i64::from(v));
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<SELValue, ConversionError> {
+        return self.serialize_i64(i64::from(v));
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<SELValue, ConversionError> {
+        return match i64::try_from(v) {
+            Ok(v) => self.serialize_i64(v),
+            Err(_) => Err(ConversionError::new(format!(
+                "{} is too large for an Integer",
+                v
+            ))),
+        };
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<SELValue, ConversionError> {
+        return self.serialize_f64(f64::from(v));
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<SELValue, ConversionError> {
+        return Ok(SELValue::new_from_decimal(v));
+    }
+
+    fn serialize_char(self, v: char) -> Result<SELValue, ConversionError> {
+        return Ok(SELValue::new_from_string(&v.to_string()));
+    }
+
+    fn serialize_str(self, v: &str) -> Result<SELValue, ConversionError> {
+        return Ok(SELValue::new_from_string(&String::from(v)));
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<SELValue, ConversionError> {
+        let mut list = List::new();
+
+        for byte in v {
+            list.push(SELValue::new_from_int(i64::from(*byte)));
+        }
+
+        return Ok(SELValue::new_from_list(list));
+    }
+
+    fn serialize_none(self) -> Result<SELValue, ConversionError> {
+        return Ok(SELValue::new());
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<SELValue, ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        return value.serialize(self);
+    }
+
+    fn serialize_unit(self) -> Result<SELValue, ConversionError> {
+        return Ok(SELValue::new());
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<SELValue, ConversionError> {
+        return Ok(SELValue::new());
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<SELValue, ConversionError> {
+        return Ok(self.symbol(variant));
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<SELValue, ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        return value.serialize(self);
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<SELValue, ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = value.serialize(&mut *self)?;
+
+        return Ok(self.keyed(variant, value));
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<ListSerializer<'a, 'c>, ConversionError> {
+        return Ok(ListSerializer::new(self, None));
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<ListSerializer<'a, 'c>, ConversionError> {
+        return Ok(ListSerializer::new(self, None));
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<ListSerializer<'a, 'c>, ConversionError> {
+        return Ok(ListSerializer::new(self, None));
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<ListSerializer<'a, 'c>, ConversionError> {
+        return Ok(ListSerializer::new(self, Some(variant)));
+    }
+
+    fn serialize_map(
+        self,
+        _len: Option<usize>,
+    ) -> Result<AssociativeListSerializer<'a, 'c>, ConversionError> {
+        return Ok(AssociativeListSerializer::new(self, None));
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<AssociativeListSerializer<'a, 'c>, ConversionError> {
+        return Ok(AssociativeListSerializer::new(self, None));
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<AssociativeListSerializer<'a, 'c>, ConversionError> {
+        return Ok(AssociativeListSerializer::new(self, Some(variant)));
+    }
+}
+
+// sequences, tuples and tuple variants
+pub struct ListSerializer<'a, 'c> {
+    serializer: &'a mut ValueSerializer<'c>,
+    list: List,
+    variant: Option<&'static str>,
+}
+
+impl<'a, 'c> ListSerializer<'a, 'c> {
+    fn new(serializer: &'a mut ValueSerializer<'c>, variant: Option<&'static str>) -> Self {
+        return ListSerializer {
+            serializer,
+            list: List::new(),
+            variant,
+        };
+    }
+
+    fn push<T>(&mut self, value: &T) -> Result<(), ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = value.serialize(&mut *self.serializer)?;
+        self.list.push(value);
+
+        return Ok(());
+    }
+
+    fn finish(self) -> Result<SELValue, ConversionError> {
+        let value = SELValue::new_from_list(self.list);
+
+        return Ok(match self.variant {
+            Some(variant) => self.serializer.keyed(variant, value),
+            None => value,
+        });
+    }
+}
+
+impl<'a, 'c> ser::SerializeSeq for ListSerializer<'a, 'c> {
+    type Ok = SELValue;
+    type Error = ConversionError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        return self.push(value);
+    }
+
+    fn end(self) -> Result<SELValue, ConversionError> {
+        return self.finish();
+    }
+}
+
+impl<'a, 'c> ser::SerializeTuple for ListSerializer<'a, 'c> {
+    type Ok = SELValue;
+    type Error = ConversionError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        return self.push(value);
+    }
+
+    fn end(self) -> Result<SELValue, ConversionError> {
+        return self.finish();
+    }
+}
+
+impl<'a, 'c> ser::SerializeTupleStruct for ListSerializer<'a, 'c> {
+    type Ok = SELValue;
+    type Error = ConversionError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        return self.push(value);
+    }
+
+    fn end(self) -> Result<SELValue, ConversionError> {
+        return self.finish();
+    }
+}
+
+impl<'a, 'c> ser::SerializeTupleVariant for ListSerializer<'a, 'c> {
+    type Ok = SELValue;
+    type Error = ConversionError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        return self.push(value);
+    }
+
+    fn end(self) -> Result<SELValue, ConversionError> {
+        return self.finish();
+    }
+}
+
+// maps, structs and struct variants
+pub struct AssociativeListSerializer<'a, 'c> {
+    serializer: &'a mut ValueSerializer<'c>,
+    list: AssociativeList,
+    // map key waiting for its value
+    key: Option<SELValue>,
+    variant: Option<&'static str>,
+}
+
+impl<'a, 'c> AssociativeListSerializer<'a, 'c> {
+    fn new(serializer: &'a mut ValueSerializer<'c>, variant: Option<&'static str>) -> Self {
+        return AssociativeListSerializer {
+            serializer,
+            list: AssociativeList::new(),
+            key: None,
+            variant,
+        };
+    }
+
+    fn insert<T>(&mut self, key: SELValue, value: &T) -> Result<(), ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = value.serialize(&mut *self.serializer)?;
+        self.list
+            .push(SELValue::new_from_pair(Pair::new(key, value)));
+
+        return Ok(());
+    }
+
+    fn finish(self) -> Result<SELValue, ConversionError> {
+        let value = SELValue::new_from_associative_list(self.list);
+
+        return Ok(match self.variant {
+            Some(variant) => self.serializer.keyed(variant, value),
+            None => value,
+        });
+    }
+}
+
+impl<'a, 'c> ser::SerializeMap for AssociativeListSerializer<'a, 'c> {
+    type Ok = SELValue;
+    type Error = ConversionError;
+
+    // only keys that are strings or unit variants can be symbols
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let key = key.serialize(&mut *self.serializer)?;
+
+        self.key = Some(match key.get_value() {
+            Some(ValueData::String(identifier)) => self.serializer.symbol(identifier),
+            _ if key.get_type() == DataType::Symbol => key,
+            _ => {
+                return Err(ConversionError::new(format!(
+                    "map keys must be strings, found {}",
+                    key.get_type()
+                )))
+            }
+        });
+
+        return Ok(());
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        return match self.key.take() {
+            Some(key) => self.insert(key, value),
+            None => Err(ConversionError::new(String::from(
+                "map value serialized before its key",
+            ))),
+        };
+    }
+
+    fn end(self) -> Result<SELValue, ConversionError> {
+        return self.finish();
+    }
+}
+
+impl<'a, 'c> ser::SerializeStruct for AssociativeListSerializer<'a, 'c> {
+    type Ok = SELValue;
+    type Error = ConversionError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let key = self.serializer.symbol(key);
+
+        return self.insert(key, value);
+    }
+
+    fn end(self) -> Result<SELValue, ConversionError> {
+        return self.finish();
+    }
+}
+
+impl<'a, 'c> ser::SerializeStructVariant for AssociativeListSerializer<'a, 'c> {
+    type Ok = SELValue;
+    type Error = ConversionError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), ConversionError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let key = self.serializer.symbol(key);
+
+        return self.insert(key, value);
+    }
+
+    fn end(self) -> Result<SELValue, ConversionError> {
+        return self.finish();
+    }
+}
@@ -0,0 +1,196 @@
+use sel_common::annotation::AnnotationArgument;
+use sel_tokenizer::{Token, TokenType};
+
+// parses tokens between the parenthesis of an annotation
+// i.e. `MyShape, [name: string, scores: [int...]]`
+pub fn parse_annotation_arguments(tokens: &[Token]) -> Vec<AnnotationArgument> {
+    let mut current = 0;
+
+    return parse_items(tokens, &mut current, None);
+}
+
+fn is_type(tokens: &[Token], current: usize, token_type: TokenType) -> bool {
+    return matches!(tokens.get(current), Some(token) if token.get_token_type() == token_type);
+}
+
+// comma separated items until the closing token, which is consumed
+fn parse_items(
+    tokens: &[Token],
+    current: &mut usize,
+    close: Option<TokenType>,
+) -> Vec<AnnotationArgument> {
+    let mut items = vec![];
+
+    while let Some(token) = tokens.get(*current) {
+        let token_type = token.get_token_type();
+
+        if Some(token_type) == close {
+            *current += 1;
+            break;
+        }
+
+        // skipped items are left out
+        if token_type == TokenType::Comma {
+            *current += 1;
+            continue;
+        }
+
+        items.push(parse_item(tokens, current));
+    }
+
+    return items;
+}
+
+// value optionally followed by `...`, `: value` or `= value`
+fn parse_item(tokens: &[Token], current: &mut usize) -> AnnotationArgument {
+    let mut item = parse_value(tokens, current);
+
+    if is_type(tokens, *current, TokenType::InclusiveRange) {
+        *current += 1;
+        item = AnnotationArgument::Repeated(Box::new(item));
+    }
+
+    if is_type(tokens, *current, TokenType::Symbol) {
+        *current += 1;
+        let value = parse_item(tokens, current);
+
+        return match item {
+            AnnotationArgument::Identifier(name) => {
+                AnnotationArgument::Field(name, Box::new(value))
+            }
+            item => AnnotationArgument::Pair(Box::new(item), Box::new(value)),
+        };
+    }
+
+    if is_type(tokens, *current, TokenType::Pair) {
+        *current += 1;
+        let value = parse_item(tokens, current);
+
+        return AnnotationArgument::Pair(Box::new(item), Box::new(value));
+    }
+
+    return item;
+}
+
+fn parse_value(tokens: &[Token], current: &mut usize) -> AnnotationArgument {
+    let token = match tokens.get(*current) {
+        Some(token) => token,
+        None => return AnnotationArgument::Unit,
+    };
+
+    *current += 1;
+
+    let text = token.get_token_str();
+
+    return match token.get_token_type() {
+        TokenType::Identifier => AnnotationArgument::Identifier(text),
+        TokenType::Integer => match text.parse() {
+            Ok(integer) => AnnotationArgument::Integer(integer),
+            Err(_) => AnnotationArgument::Other(text),
+        },
+        TokenType::Decimal => match text.parse() {
+            Ok(decimal) => AnnotationArgument::Decimal(decimal),
+            Err(_) => AnnotationArgument::Other(text),
+        },
+        TokenType::SingleQuotedString | TokenType::DoubleQuotedString => {
+            AnnotationArgument::String(text)
+        }
+        TokenType::Boolean => AnnotationArgument::Boolean(text == "true"),
+        TokenType::Unit => AnnotationArgument::Unit,
+        // negative numbers
+        TokenType::MinusSign => match parse_value(tokens, current) {
+            AnnotationArgument::Integer(integer) => AnnotationArgument::Integer(-integer),
+            AnnotationArgument::Decimal(decimal) => AnnotationArgument::Decimal(-decimal),
+            _ => AnnotationArgument::Other(text),
+        },
+        TokenType::Symbol if is_type(tokens, *current, TokenType::Identifier) => {
+            *current += 1;
+            AnnotationArgument::Symbol(tokens[*current - 1].get_token_str())
+        }
+        TokenType::StartAssociativeList => AnnotationArgument::List(parse_items(
+            tokens,
+            current,
+            Some(TokenType::EndAssociativeList),
+        )),
+        TokenType::StartGroup => {
+            AnnotationArgument::Group(parse_items(tokens, current, Some(TokenType::EndGroup)))
+        }
+        _ => AnnotationArgument::Other(text),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sel_tokenizer::Tokenizer;
+
+    fn arguments(s: &str) -> Vec<AnnotationArgument> {
+        let tokens: Vec<Token> = Tokenizer::new(&String::from(s)).collect();
+
+        return parse_annotation_arguments(&tokens);
+    }
+
+    fn identifier(name: &str) -> AnnotationArgument {
+        return AnnotationArgument::Identifier(String::from(name));
+    }
+
+    #[test]
+    fn parses_values() {
+        assert_eq!(
+            arguments("60, -2.5, \"name\", true, (), :sym"),
+            vec![
+                AnnotationArgument::Integer(60),
+                AnnotationArgument::Decimal(-2.5),
+                AnnotationArgument::String(String::from("name")),
+                AnnotationArgument::Boolean(true),
+                AnnotationArgument::Unit,
+                AnnotationArgument::Symbol(String::from("sym")),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_shapes() {
+        assert_eq!(
+            arguments("MyShape, [name: string, scores: [int...]]"),
+            vec![
+                identifier("MyShape"),
+                AnnotationArgument::List(vec![
+                    AnnotationArgument::Field(String::from("name"), Box::new(identifier("string"))),
+                    AnnotationArgument::Field(
+                        String::from("scores"),
+                        Box::new(AnnotationArgument::List(vec![
+                            AnnotationArgument::Repeated(Box::new(identifier("int")))
+                        ]))
+                    ),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_pairs_and_groups() {
+        assert_eq!(
+            arguments("[:a = 1], (x, y)"),
+            vec![
+                AnnotationArgument::List(vec![AnnotationArgument::Pair(
+                    Box::new(AnnotationArgument::Symbol(String::from("a"))),
+                    Box::new(AnnotationArgument::Integer(1))
+                )]),
+                AnnotationArgument::Group(vec![identifier("x"), identifier("y")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_other_tokens_as_text() {
+        assert_eq!(
+            arguments("a + b"),
+            vec![
+                identifier("a"),
+                AnnotationArgument::Other(String::from("+")),
+                identifier("b"),
+            ]
+        );
+    }
+}
@@ -1,10 +1,13 @@
 use crate::find_root::find_root_index;
-use crate::group_handling::{correct_group, identifier_call_check, update_group};
+use crate::group_handling::{
+    close_empty_group, correct_group, identifier_call_check, update_group,
+};
 use crate::precedence_manager::{PrecedenceManager, LIST_PRECEDENCE, RIGHT_TO_LEFT_PRECEDENCES};
 use crate::process_tokens::make_nodes_from_tokenizer;
 use crate::resolve_tree::resolve_tree;
 use crate::utils::promote_match_lists;
 use sel_common::named_expression::NamedExpression;
+use sel_common::test_case::{Mock, TestCase};
 use sel_common::{DataHeap, Operation, SELContext, SELSubTree, SELTree, SELTreeNode};
 use sel_tokenizer::Tokenizer;
 use std::collections::{HashMap, HashSet};
@@ -17,9 +20,11 @@ pub fn build_tree_from_string(s: &String, context: SELContext) -> SELTree {
         mut nodes,
         mut data,
         firsts_of_expression,
-        annotations,
+        mut annotations,
         documents,
         named_expressions,
+        tests,
+        mocks,
         name_spaces,
     ) = make_nodes_from_tokenizer(&mut precedence_manager, &mut tokenizer, &mut context);
 
@@ -33,6 +38,20 @@ pub fn build_tree_from_string(s: &String, context: SELContext) -> SELTree {
             // base tier doesn't need any correction
             // before or after creating the tree
 
+            if index != 0 && group.is_empty() {
+                nodes = close_empty_group(nodes, group);
+
+                check_set_expression_sub_tree(
+                    &mut nodes,
+                    &mut data,
+                    &mut sub_trees,
+                    vec![],
+                    group.get_parent(),
+                );
+
+                continue;
+            }
+
             if index != 0 {
                 nodes = correct_group(nodes, group);
             }
@@ -97,7 +116,6 @@ pub fn build_tree_from_string(s: &String, context: SELContext) -> SELTree {
     let named_expressions = named_expressions
         .iter()
         .fold(HashMap::new(), |mut map, value| {
-            println!("banning {:?}", value.get_root());
             sub_root_ban_set.insert(value.get_root());
 
             map.insert(
@@ -110,6 +128,47 @@ pub fn build_tree_from_string(s: &String, context: SELContext) -> SELTree {
             map
         });
 
+    // tests are not roots of the tree
+    let tests = tests
+        .iter()
+        .map(|test| {
+            sub_root_ban_set.insert(test.get_root());
+
+            if let Some(input) = test.get_input() {
+                sub_root_ban_set.insert(input);
+            }
+
+            TestCase::new(
+                test.get_input()
+                    .map(|input| find_root_index(&nodes, Some(input))),
+                find_root_index(&nodes, Some(test.get_root())),
+                test.get_span(),
+            )
+            .with_mocks(test.get_mocks().clone())
+        })
+        .collect();
+
+    let mocks = mocks
+        .iter()
+        .map(|mock| {
+            sub_root_ban_set.insert(mock.get_arguments());
+
+            Mock::new(
+                mock.get_name().clone(),
+                find_root_index(&nodes, Some(mock.get_arguments())),
+                mock.get_span(),
+            )
+        })
+        .collect();
+
+    for annotation in annotations.iter_mut() {
+        let target = annotation
+            .get_target()
+            .map(|target| find_root_index(&nodes, Some(target)));
+
+        annotation.set_target(target);
+    }
+
     // firsts of group doesn't contain very first
     // we find this one by starting at 0
     let root = if !sub_root_ban_set.contains(&0) {
@@ -149,7 +208,8 @@ pub fn build_tree_from_string(s: &String, context: SELContext) -> SELTree {
         documents,
         named_expressions,
         name_spaces,
-    );
+    )
+    .with_tests(tests, mocks);
 }
 
 fn check_set_expression_sub_tree(
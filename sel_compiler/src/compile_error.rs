@@ -0,0 +1,62 @@
+use sel_common::Span;
+use std::fmt;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum CompileErrorKind {
+    UnclosedGroup,
+    UnclosedAssociativeList,
+    UnclosedExpressionBlock,
+    UnexpectedClose,
+    MismatchedClose,
+    DanglingOperator,
+    UnterminatedString,
+    InvalidNumber,
+    InvalidAnnotation,
+    UnknownToken,
+    // @Shape, @Input or @Result that doesn't describe a shape
+    InvalidShape,
+    // expression doesn't match its declared @Input or @Result
+    ShapeMismatch,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct CompileError {
+    kind: CompileErrorKind,
+    span: Span,
+    message: String,
+}
+
+impl CompileError {
+    pub fn new(kind: CompileErrorKind, span: Span, message: String) -> Self {
+        return CompileError {
+            kind,
+            span,
+            message,
+        };
+    }
+
+    pub fn get_kind(&self) -> CompileErrorKind {
+        return self.kind;
+    }
+
+    pub fn get_span(&self) -> Span {
+        return self.span;
+    }
+
+    pub fn get_message(&self) -> &String {
+        return &self.message;
+    }
+
+    // format for diagnostics as file:line:col-line:col: message
+    pub fn display_with_file(&self, file: &str) -> String {
+        return format!("{}: {}", self.span.display_with_file(file), self.message);
+    }
+}
+
+impl std::fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.span, self.message)
+    }
+}
+
+impl std::error::Error for CompileError {}
@@ -1,4 +1,7 @@
 use crate::build::build_tree_from_string;
+use crate::compile_error::CompileError;
+use crate::type_check::check_types;
+use crate::validate::validate_tokens;
 use sel_common::{SELContext, SELTree};
 
 pub struct Compiler {}
@@ -15,4 +18,32 @@ impl Compiler {
     pub fn compile_with_context(&self, s: &String, context: SELContext) -> SELTree {
         return build_tree_from_string(s, context);
     }
+
+    pub fn try_compile(&self, s: &str) -> Result<SELTree, Vec<CompileError>> {
+        return self.try_compile_with_context(s, SELContext::new());
+    }
+
+    // validates source before building so malformed input is reported instead of panicking
+    // built trees are then checked against their declared shapes
+    pub fn try_compile_with_context(
+        &self,
+        s: &str,
+        context: SELContext,
+    ) -> Result<SELTree, Vec<CompileError>> {
+        let source = String::from(s);
+        let errors = validate_tokens(&source);
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let tree = build_tree_from_string(&source, context);
+        let errors = check_types(&tree).get_errors().clone();
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        return Ok(tree);
+    }
 }
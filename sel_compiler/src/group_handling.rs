@@ -31,6 +31,7 @@ pub fn correct_group(
         .filter(|last| {
             last.get_operation() != Operation::Group
                 && last.get_operation() != Operation::AssociativeList
+                && last.get_operation() != Operation::InterpretedAccess
         })
         // check if last node has a right
         .and_then(|last| {
@@ -60,6 +61,54 @@ pub fn correct_group(
     nodes
 }
 
+fn detach_group_left(
+    nodes: &[SELTreeNode],
+    precedence_group: &PrecedenceGroup,
+    changes: &mut Vec<Change>,
+) {
+    // Check group's left side
+    // if anything but an identifier
+    // set to none
+    // interpreted access keeps its left, it is the value being accessed
+    nodes
+        .get(precedence_group.get_parent())
+        .filter(|parent_node| parent_node.get_operation() != Operation::InterpretedAccess)
+        .and_then(|parent_node| parent_node.get_left())
+        .and_then(|left_index| nodes.get(left_index))
+        .filter(|left_node| {
+            left_node.get_operation() != Operation::CurrentResult
+                && left_node.get_data_type() != DataType::Identifier
+        })
+        .and_then(|_left_node| {
+            changes.push(Change {
+                index_to_change: precedence_group.get_parent(),
+                new_index: None,
+                side_to_set: NodeSide::Left,
+            });
+
+            Some(true)
+        });
+}
+
+// empty groups have no tree to correct
+// only need to detach the group parent from its neighbors
+pub fn close_empty_group(
+    mut nodes: Vec<SELTreeNode>,
+    precedence_group: &PrecedenceGroup,
+) -> Vec<SELTreeNode> {
+    let mut changes = vec![Change {
+        index_to_change: precedence_group.get_parent(),
+        new_index: None,
+        side_to_set: NodeSide::Right,
+    }];
+
+    detach_group_left(&nodes, precedence_group, &mut changes);
+
+    apply_changes(&mut nodes, changes);
+
+    nodes
+}
+
 pub fn update_group(
     mut nodes: Vec<SELTreeNode>,
     precedence_group: &PrecedenceGroup,
@@ -88,26 +137,7 @@ pub fn update_group(
         });
     }
 
-    // Check group's left side
-    // if anything but an identifier
-    // set to none
-    nodes
-        .get(precedence_group.get_parent())
-        .and_then(|parent_node| parent_node.get_left())
-        .and_then(|left_index| nodes.get(left_index))
-        .filter(|left_node| {
-            left_node.get_operation() != Operation::CurrentResult
-                && left_node.get_data_type() != DataType::Identifier
-        })
-        .and_then(|_left_node| {
-            changes.push(Change {
-                index_to_change: precedence_group.get_parent(),
-                new_index: None,
-                side_to_set: NodeSide::Left,
-            });
-
-            Some(true)
-        });
+    detach_group_left(&nodes, precedence_group, &mut changes);
 
     apply_changes(&mut nodes, changes);
 
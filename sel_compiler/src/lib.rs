@@ -1,5 +1,7 @@
+mod annotation_arguments;
 mod build;
 mod change;
+mod compile_error;
 mod compiler;
 mod find_root;
 mod group_handling;
@@ -7,12 +9,16 @@ mod precedence_manager;
 mod process_tokens;
 mod resolve_node;
 mod resolve_tree;
+mod type_check;
 mod utils;
+mod validate;
 
 #[cfg(test)]
 mod tests;
 
+pub use compile_error::{CompileError, CompileErrorKind};
 pub use compiler::Compiler;
+pub use type_check::{check_types, TypeCheck};
 
 #[cfg(test)]
 mod compiler_tests {
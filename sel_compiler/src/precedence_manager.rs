@@ -7,8 +7,7 @@ const GROUP_PRECEDENCE: usize = VALUE_PRECEDENCE + 1;
 const TRANSFORM_PRECEDENCE: usize = GROUP_PRECEDENCE + 1;
 const UNARY_PRECEDENCE: usize = TRANSFORM_PRECEDENCE + 1;
 const ACCESS_PRECEDENCE: usize = UNARY_PRECEDENCE + 1;
-const INTERPRETED_ACCESS_PRECEDENCE: usize = ACCESS_PRECEDENCE + 1;
-const RANGE_PRECEDENCE: usize = INTERPRETED_ACCESS_PRECEDENCE + 1;
+const RANGE_PRECEDENCE: usize = ACCESS_PRECEDENCE + 1;
 const EXPONENTIAL_PRECEDENCE: usize = RANGE_PRECEDENCE + 1;
 const MULTIPLICATION_PRECEDENCE: usize = EXPONENTIAL_PRECEDENCE + 1;
 const ADDITION_PRECEDENCE: usize = MULTIPLICATION_PRECEDENCE + 1;
@@ -25,7 +24,8 @@ const LOGICAL_OR_PRECEDENCE: usize = LOGICAL_XOR_PRECEDENCE + 1;
 const PAIR_PRECEDENCE: usize = LOGICAL_OR_PRECEDENCE + 1;
 const MATCH_PRECEDENCE: usize = PAIR_PRECEDENCE + 1;
 pub const LIST_PRECEDENCE: usize = MATCH_PRECEDENCE + 1;
-const PARTIAL_APPLICATION_PRECEDENCE: usize = LIST_PRECEDENCE + 1;
+const COMBINE_PRECEDENCE: usize = LIST_PRECEDENCE + 1;
+const PARTIAL_APPLICATION_PRECEDENCE: usize = COMBINE_PRECEDENCE + 1;
 const PIPE_RIGHT_PRECEDENCE: usize = PARTIAL_APPLICATION_PRECEDENCE + 1;
 const PIPE_LEFT_PRECEDENCE: usize = PIPE_RIGHT_PRECEDENCE + 1;
 const STREAM_PRECEDENCE: usize = PIPE_RIGHT_PRECEDENCE + 1;
@@ -51,7 +51,6 @@ impl PrecedenceGroup {
         members.push(vec![]); // GROUP_PRECEDENCE
         members.push(vec![]); // TRANSFORM_PRECEDENCE
         members.push(vec![]); // ACCESS_PRECEDENCE
-        members.push(vec![]); // INTERPRETED_ACCESS_PRECEDENCE
         members.push(vec![]); // UNARY_PRECEDENCE
         members.push(vec![]); // RANGE_PRECEDENCE
         members.push(vec![]); // EXPONENTIAL_PRECEDENCE
@@ -69,6 +68,7 @@ impl PrecedenceGroup {
         members.push(vec![]); // LOGICAL_OR_PRECEDENCE
         members.push(vec![]); // PAIR_PRECEDENCE
         members.push(vec![]); // LIST_PRECEDENCE
+        members.push(vec![]); // COMBINE_PRECEDENCE
         members.push(vec![]); // PARTIAL_APPLICATION_PRECEDENCE
         members.push(vec![]); // MATCH_PRECEDENCE
         members.push(vec![]); // PIPE_RIGHT_PRECEDENCE
@@ -101,6 +101,10 @@ impl PrecedenceGroup {
     pub fn get_parent(&self) -> usize {
         return self.parent;
     }
+
+    pub fn is_empty(&self) -> bool {
+        return self.count == 0;
+    }
 }
 
 pub struct PrecedenceManager {
@@ -123,9 +127,9 @@ impl PrecedenceManager {
 
         operation_priorities.insert(Operation::Transform, TRANSFORM_PRECEDENCE);
 
+        // same precedence so accesses chain left to right, i.e. x[0].y
         operation_priorities.insert(Operation::DotAccess, ACCESS_PRECEDENCE);
-
-        operation_priorities.insert(Operation::InterpretedAccess, INTERPRETED_ACCESS_PRECEDENCE);
+        operation_priorities.insert(Operation::InterpretedAccess, ACCESS_PRECEDENCE);
 
         operation_priorities.insert(Operation::Symbol, UNARY_PRECEDENCE);
         operation_priorities.insert(Operation::Not, UNARY_PRECEDENCE);
@@ -183,6 +187,8 @@ impl PrecedenceManager {
         operation_priorities.insert(Operation::List, LIST_PRECEDENCE);
         operation_priorities.insert(Operation::MatchList, LIST_PRECEDENCE);
 
+        operation_priorities.insert(Operation::Combine, COMBINE_PRECEDENCE);
+
         operation_priorities.insert(
             Operation::PartialApplication,
             PARTIAL_APPLICATION_PRECEDENCE,
@@ -1,10 +1,12 @@
+use crate::annotation_arguments::parse_annotation_arguments;
 use crate::precedence_manager::PrecedenceManager;
 use crate::utils::{get_data_type_for_token, get_operation_type_for_token};
 use sel_common::annotation::Annotation;
 use sel_common::annotation_document::AnnotationDocument;
 use sel_common::named_expression::NamedExpression;
-use sel_common::{DataHeap, DataType, Operation, SELContext, SELTreeNode};
-use sel_tokenizer::{TokenType, Tokenizer};
+use sel_common::test_case::{Mock, TestCase};
+use sel_common::{DataHeap, DataType, Location, Operation, SELContext, SELTreeNode, Span};
+use sel_tokenizer::{Token, TokenType, Tokenizer};
 use std::collections::HashMap;
 
 const TERMINABLE_OPS: [Operation; 6] = [
@@ -20,6 +22,54 @@ fn op_is_terminable(op: Operation) -> bool {
     return TERMINABLE_OPS.contains(&op);
 }
 
+// operations that leave an empty list slot when followed by a comma
+// i.e. `(, 5)` and `(1, , 5)`
+fn op_opens_slot(op: Operation) -> bool {
+    return op == Operation::List
+        || op == Operation::Group
+        || op == Operation::AssociativeList
+        || op == Operation::InterpretedAccess;
+}
+
+// operations that start an operand
+// used to call a named expression reference directly followed by a value
+fn op_starts_operand(op: Operation) -> bool {
+    return matches!(
+        op,
+        Operation::Touch
+            | Operation::Input
+            | Operation::CurrentResult
+            | Operation::AssociativeList
+            | Operation::Expression
+    );
+}
+
+// inserts a node that has no token, i.e. a Unit value for an operand that was left out
+// linked to previous node and given a zero width span at location
+fn insert_implicit_node(
+    nodes: &mut Vec<SELTreeNode>,
+    precedence_manager: &mut PrecedenceManager,
+    op: Operation,
+    data_type: DataType,
+    location: Location,
+    inserted_index: usize,
+    previous_index: usize,
+) {
+    let mut node = SELTreeNode::new(op, data_type, inserted_index, None);
+    node.set_span(Some(Span::new(location, location)));
+
+    if inserted_index > 0 {
+        node.set_left(Some(previous_index));
+
+        if let Some(previous_node) = nodes.get_mut(previous_index) {
+            previous_node.set_right(Some(inserted_index));
+        }
+    }
+
+    nodes.push(node);
+    precedence_manager.add_index_with_operation(op, inserted_index);
+}
+
 pub fn make_nodes_from_tokenizer(
     precedence_manager: &mut PrecedenceManager,
     tokenizer: &mut Tokenizer,
@@ -31,6 +81,8 @@ pub fn make_nodes_from_tokenizer(
     Vec<Annotation>,
     Vec<AnnotationDocument>,
     Vec<NamedExpression>,
+    Vec<TestCase>,
+    Vec<Mock>,
     HashMap<usize, Vec<String>>,
 ) {
     let mut nodes: Vec<SELTreeNode> = vec![];
@@ -39,6 +91,8 @@ pub fn make_nodes_from_tokenizer(
     let mut annotations: Vec<Annotation> = vec![];
     let mut documents: Vec<AnnotationDocument> = vec![];
     let mut named_expressions: Vec<NamedExpression> = vec![];
+    let mut tests: Vec<TestCase> = vec![];
+    let mut mocks: Vec<Mock> = vec![];
     let mut identifier_namespaces: HashMap<usize, Vec<String>> = HashMap::new();
 
     let mut current_document: AnnotationDocument = AnnotationDocument::new();
@@ -50,25 +104,124 @@ pub fn make_nodes_from_tokenizer(
     let mut in_document = false;
     let mut infix_next = false;
     let mut infix_last = false;
+    let mut reference_last = false;
     let mut current_identifier: Vec<String> = vec![];
+    // @Test waiting for its expression
+    // and first node of its argument if it has one
+    let mut test_annotation: Option<Span> = None;
+    let mut test_input: Option<usize> = None;
+    // @Mock and @MockOnce waiting for arguments
+    // then first node of the arguments, waiting for the mocked name
+    let mut mock_annotation: Option<(Span, bool)> = None;
+    let mut mock_arguments: Option<(usize, Span, bool)> = None;
+    // mocks applied to every following test and only the next one
+    let mut active_mocks: Vec<usize> = vec![];
+    let mut once_mocks: Vec<usize> = vec![];
+    // annotation waiting for its arguments
+    // then the tokens of its arguments until they are closed
+    let mut awaiting_arguments: Option<usize> = None;
+    let mut argument_tokens: Option<(usize, Vec<Token>, usize)> = None;
+    // annotations waiting for the expression they decorate
+    let mut untargeted: Vec<usize> = vec![];
 
     // loop trough all tokens
     // convert them to tree nodes
     // and link them together
     for token in tokenizer {
-        let inserted_index = nodes.len();
-        let previous_index = if nodes.len() > 0 {
+        let mut inserted_index = nodes.len();
+        let mut previous_index = if nodes.len() > 0 {
             inserted_index - 1
         } else {
             0
         };
 
+        let mut reference_symbol: Option<usize> = None;
+
+        // first argument of a mock is the name of what it replaces
+        if let Some((arguments, span, once)) = mock_arguments.take() {
+            if token.get_token_type() == TokenType::Identifier {
+                mocks.push(Mock::new(token.get_token_str(), arguments, span));
+
+                if once {
+                    once_mocks.push(mocks.len() - 1);
+                } else {
+                    active_mocks.push(mocks.len() - 1);
+                }
+            }
+        }
+
+        // arguments immediately follow the annotation name
+        if let Some(annotation) = awaiting_arguments.take() {
+            let follows_name = matches!(
+                annotations[annotation].get_span(),
+                Some(span) if span.get_end().get_offset() == token.get_span().get_start().get_offset()
+            );
+
+            if token.get_token_type() == TokenType::StartGroup && follows_name {
+                argument_tokens = Some((annotation, vec![], 0));
+            }
+        }
+
+        let mut is_annotation_argument = false;
+
+        if token.get_token_type() != TokenType::CommentAnnotation {
+            if let Some((annotation, mut tokens, mut depth)) = argument_tokens.take() {
+                let line_end = token.get_token_type() == TokenType::LineEnd;
+
+                if !line_end {
+                    match token.get_token_type() {
+                        TokenType::StartGroup => depth += 1,
+                        TokenType::EndGroup => depth -= 1,
+                        _ => (),
+                    }
+
+                    tokens.push(token.clone());
+                    is_annotation_argument = true;
+                }
+
+                if line_end || depth == 0 {
+                    // leave out the surrounding parenthesis
+                    let end = if depth == 0 {
+                        tokens.len() - 1
+                    } else {
+                        tokens.len()
+                    };
+                    let arguments = parse_annotation_arguments(&tokens[1..end]);
+
+                    annotations[annotation].set_arguments(arguments);
+                } else {
+                    argument_tokens = Some((annotation, tokens, depth));
+                }
+
+                // arguments of @Test and mocks are evaluated
+                // others are only given to the runtime
+                let evaluated = matches!(
+                    annotations[annotation].get_name().as_str(),
+                    "Test" | "Mock" | "MockOnce"
+                );
+
+                if is_annotation_argument && !evaluated {
+                    continue;
+                }
+            }
+        }
+
         if token.get_token_type() == TokenType::CommentAnnotation {
             // drop
             continue;
         } else if token.get_token_type() == TokenType::Annotation {
             let name = String::from(token.get_token_str()[1..].trim());
-            annotations.push(Annotation::new(name));
+
+            if name == "Test" {
+                test_annotation = Some(token.get_span());
+                test_input = None;
+            } else if name == "Mock" || name == "MockOnce" {
+                mock_annotation = Some((token.get_span(), name == "MockOnce"));
+            }
+
+            annotations.push(Annotation::new(name).with_span(token.get_span()));
+            awaiting_arguments = Some(annotations.len() - 1);
+            untargeted.push(annotations.len() - 1);
             continue;
         } else if token.get_token_type() == TokenType::DocumentAnnotation {
             // slice out the line without the leading '@@'
@@ -92,17 +245,26 @@ pub fn make_nodes_from_tokenizer(
             continue;
         } else if token.get_token_type() == TokenType::TaggedIdentifier {
             // slice away the leading '#'
-            let name = String::from(token.get_token_str()[1..].as_ref());
+            let name = String::from(&token.get_token_str()[1..]);
             let symbol_index = context.add_symbol(&name);
 
-            // set to next node index for now
-            // will find root after precedence resolution
-            // next node is inserted index because we are dropping this token
-            //so inserted index will be the same next iteration
-            let root = inserted_index;
-
-            named_expressions.push(NamedExpression::new(root, symbol_index));
-            continue;
+            if named_expressions
+                .iter()
+                .any(|named_expression| named_expression.get_symbol() == symbol_index)
+            {
+                // tag of an already named expression is a reference to it
+                // made into an identifier node below so it can be called like a function
+                reference_symbol = Some(symbol_index);
+            } else {
+                // set to next node index for now
+                // will find root after precedence resolution
+                // next node is inserted index because we are dropping this token
+                //so inserted index will be the same next iteration
+                let root = inserted_index;
+
+                named_expressions.push(NamedExpression::new(root, symbol_index));
+                continue;
+            }
         }
 
         if in_document {
@@ -130,6 +292,12 @@ pub fn make_nodes_from_tokenizer(
                 .get_mut(previous_index)
                 .and_then(|previous_node| -> Option<usize> {
                     previous_node.set_value(data.insert_integer(symbol_value as i64));
+
+                    // symbol node covers both the ':' and the identifier
+                    let span = previous_node
+                        .get_span()
+                        .map_or(token.get_span(), |span| span.merge(&token.get_span()));
+                    previous_node.set_span(Some(span));
                     None
                 });
             symbol_next = false;
@@ -139,6 +307,11 @@ pub fn make_nodes_from_tokenizer(
         let mut op = get_operation_type_for_token(&token);
         let mut data_type = get_data_type_for_token(&token);
 
+        if reference_symbol.is_some() {
+            op = Operation::Touch;
+            data_type = DataType::Identifier;
+        }
+
         if data_type == DataType::Symbol {
             symbol_next = true;
         } else if data_type == DataType::Unit {
@@ -151,7 +324,9 @@ pub fn make_nodes_from_tokenizer(
             }
         }
 
-        let value = if token.get_token_type() == TokenType::Identifier {
+        let value = if let Some(symbol_index) = reference_symbol {
+            data.insert_integer(symbol_index as i64)
+        } else if token.get_token_type() == TokenType::Identifier {
             let parts: Vec<String> = token
                 .get_token_str()
                 .split("::")
@@ -188,17 +363,63 @@ pub fn make_nodes_from_tokenizer(
             infix_last = true;
         }
 
+        // reference followed directly by a value is called with that value
+        // i.e. #plus_rand 5 is the same as #plus_rand <- 5
+        if reference_last && link_next && op_starts_operand(op) {
+            insert_implicit_node(
+                &mut nodes,
+                precedence_manager,
+                Operation::PipeFirstLeft,
+                DataType::Unknown,
+                token.get_span().get_start(),
+                inserted_index,
+                previous_index,
+            );
+
+            previous_index = inserted_index;
+            inserted_index += 1;
+            last_op = Operation::PipeFirstLeft;
+            last_data_type = DataType::Unknown;
+        }
+
         if op == Operation::Subtraction && last_data_type == DataType::Unknown {
             // if previous node is not a value
             // this op is actually a Negation operation
             op = Operation::Negation;
-        } else if op == Operation::AssociativeList && last_op == Operation::Touch {
-            // if op before associative list was a value type
+        } else if op == Operation::AssociativeList
+            && link_next
+            && matches!(
+                last_op,
+                Operation::Touch | Operation::Input | Operation::CurrentResult
+            )
+        {
+            // if op before associative list was a value type on the same line
             // then it is an interpreted access operation
             op = Operation::InterpretedAccess;
         }
 
+        // collect without a seed value gets Unit as the seed
+        // skipped list items are Unit values
+        if (op == Operation::CollectInit && last_op == Operation::Collect)
+            || (op == Operation::List && op_opens_slot(last_op))
+        {
+            insert_implicit_node(
+                &mut nodes,
+                precedence_manager,
+                Operation::Touch,
+                DataType::Unit,
+                token.get_span().get_start(),
+                inserted_index,
+                previous_index,
+            );
+
+            previous_index = inserted_index;
+            inserted_index += 1;
+        }
+
         let mut node = SELTreeNode::new(op, data_type, inserted_index, value);
+        node.set_span(Some(token.get_span()));
+        node.set_tagged(reference_symbol.is_some());
 
         if !link_next {
             // check to see if previous node and current node are terminable
@@ -223,6 +444,47 @@ pub fn make_nodes_from_tokenizer(
             }
         }
 
+        if !link_next || nodes.is_empty() {
+            // set to first node for now
+            // will find roots after precedence resolution
+            if !is_annotation_argument {
+                for annotation in untargeted.drain(..) {
+                    annotations[annotation].set_target(Some(inserted_index));
+                }
+            }
+
+            // arguments immediately follow the annotation name
+            let is_argument = |annotation_span: Span| {
+                token.get_token_type() == TokenType::StartGroup
+                    && token.get_span().get_start().get_offset()
+                        == annotation_span.get_end().get_offset()
+            };
+
+            if let Some((annotation_span, once)) = mock_annotation
+                .take()
+                .filter(|(annotation_span, _)| is_argument(*annotation_span))
+            {
+                mock_arguments = Some((inserted_index, annotation_span, once));
+            } else if let Some(annotation_span) = test_annotation {
+                // expression after the arguments is the test
+                if test_input.is_none() && is_argument(annotation_span) {
+                    test_input = Some(inserted_index);
+                } else {
+                    // set to first node for now
+                    // will find roots after precedence resolution
+                    let test_mocks = active_mocks.iter().chain(once_mocks.iter()).cloned();
+
+                    tests.push(
+                        TestCase::new(test_input, inserted_index, annotation_span)
+                            .with_mocks(test_mocks.collect()),
+                    );
+                    test_annotation = None;
+                    test_input = None;
+                    once_mocks.clear();
+                }
+            }
+        }
+
         // flip back for next node
         if !link_next {
             firsts_of_expression.push(inserted_index);
@@ -243,6 +505,7 @@ pub fn make_nodes_from_tokenizer(
 
         last_data_type = data_type;
         last_op = op;
+        reference_last = reference_symbol.is_some();
     }
 
     // no tokens
@@ -251,6 +514,13 @@ pub fn make_nodes_from_tokenizer(
         nodes.push(SELTreeNode::new(Operation::None, DataType::Unit, 0, None));
     }
 
+    // arguments not closed before the end
+    if let Some((annotation, tokens, _)) = argument_tokens {
+        let arguments = parse_annotation_arguments(&tokens[1..]);
+
+        annotations[annotation].set_arguments(arguments);
+    }
+
     // add last document if has lines
     if current_document.get_lines().len() > 0 {
         documents.push(current_document);
@@ -263,6 +533,8 @@ pub fn make_nodes_from_tokenizer(
         annotations,
         documents,
         named_expressions,
+        tests,
+        mocks,
         identifier_namespaces,
     );
 }
@@ -24,6 +24,9 @@ pub fn resolve_node(
 
             match start_index {
                 None => (),
+                // side was already attached as this node's group
+                // i.e. the key of an interpreted access
+                Some(node_index) if nodes.get(node_index).unwrap().get_parent() == Some(index) => {}
                 Some(node_index) => {
                     let mut next_node = nodes.get(node_index).unwrap();
 
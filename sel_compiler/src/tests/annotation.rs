@@ -1,4 +1,5 @@
 use super::super::Compiler;
+use sel_common::annotation::AnnotationArgument;
 use sel_common::{DataType, Operation};
 
 #[test]
@@ -246,3 +247,213 @@ fn expression_with_annotation_block_stores_multiple_document() {
         &String::from("and a third")
     );
 }
+
+#[test]
+fn test_annotation_makes_test_case() {
+    let input = String::from(
+        "\
+5 + 10
+
+@Test([1, 2])
+$ == 15
+",
+    );
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    assert_eq!(tree.get_root().get_operation(), Operation::Addition);
+    assert_eq!(tree.get_sub_roots().len(), 0);
+    assert_eq!(tree.get_tests().len(), 1);
+
+    let test = tree.get_tests().first().unwrap();
+    let test_input = tree.get_nodes().get(test.get_input().unwrap()).unwrap();
+    let test_root = tree.get_nodes().get(test.get_root()).unwrap();
+
+    assert_eq!(test_input.get_operation(), Operation::Group);
+    assert_eq!(test_root.get_operation(), Operation::Equality);
+    assert_eq!(test.get_span().get_start().get_line(), 3);
+}
+
+#[test]
+fn test_annotation_without_argument() {
+    let input = String::from(
+        "\
+#plus_five $ + 5
+
+@Test
+#plus_five(10) == 15
+
+20
+",
+    );
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    assert_eq!(tree.get_root().get_operation(), Operation::Touch);
+    assert_eq!(tree.get_sub_roots().len(), 0);
+
+    let test = tree.get_tests().first().unwrap();
+
+    assert_eq!(test.get_input(), None);
+    assert_eq!(
+        tree.get_nodes()
+            .get(test.get_root())
+            .unwrap()
+            .get_operation(),
+        Operation::Equality
+    );
+}
+
+#[test]
+fn mock_annotations_apply_to_following_tests() {
+    let input = String::from(
+        "\
+get_user($)
+
+@Mock(get_user, \"Panda\")
+@MockOnce(get_user, (), \"unknown\")
+@Test(\"unknown\")
+$ == ()
+
+@Test(\"id\")
+$ == \"Panda\"
+",
+    );
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    assert_eq!(tree.get_sub_roots().len(), 0);
+    assert_eq!(tree.get_mocks().len(), 2);
+
+    let mock = tree.get_mocks().first().unwrap();
+
+    assert_eq!(mock.get_name(), &String::from("get_user"));
+    assert_eq!(
+        tree.get_nodes()
+            .get(mock.get_arguments())
+            .unwrap()
+            .get_operation(),
+        Operation::Group
+    );
+
+    let tests = tree.get_tests();
+
+    assert_eq!(tests.first().unwrap().get_mocks(), &vec![0, 1]);
+    assert_eq!(tests.get(1).unwrap().get_mocks(), &vec![0]);
+}
+
+#[test]
+fn annotation_arguments_are_parsed() {
+    let input = String::from(
+        "\
+@Shape(User, [name: string, scores: [int...]])
+@Cache(60)
+$
+",
+    );
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // arguments are not roots of the tree
+    assert_eq!(tree.get_root().get_operation(), Operation::Input);
+    assert_eq!(tree.get_sub_roots().len(), 0);
+
+    let annotations = tree.get_annotations();
+
+    assert_eq!(
+        annotations.first().unwrap().get_name(),
+        &String::from("Shape")
+    );
+    assert_eq!(
+        annotations.first().unwrap().get_arguments(),
+        &vec![
+            AnnotationArgument::Identifier(String::from("User")),
+            AnnotationArgument::List(vec![
+                AnnotationArgument::Field(
+                    String::from("name"),
+                    Box::new(AnnotationArgument::Identifier(String::from("string")))
+                ),
+                AnnotationArgument::Field(
+                    String::from("scores"),
+                    Box::new(AnnotationArgument::List(vec![
+                        AnnotationArgument::Repeated(Box::new(AnnotationArgument::Identifier(
+                            String::from("int")
+                        )))
+                    ]))
+                ),
+            ]),
+        ]
+    );
+    assert_eq!(
+        annotations.get(1).unwrap().get_arguments(),
+        &vec![AnnotationArgument::Integer(60)]
+    );
+}
+
+#[test]
+fn annotations_target_following_expression() {
+    let input = String::from(
+        "\
+@Deprecated
+5 + 10
+
+@Context(Users)
+#greet \"Hello, \" + $
+
+$ * 2
+",
+    );
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    let root = tree.get_root().get_own_index();
+    let deprecated = tree.annotations_for(root);
+
+    assert_eq!(deprecated.len(), 1);
+    assert_eq!(
+        deprecated.first().unwrap().get_name(),
+        &String::from("Deprecated")
+    );
+
+    let greet = tree.get_named_expressions().values().next().unwrap();
+    let context = tree.annotations_for(greet.get_root());
+
+    assert_eq!(context.len(), 1);
+    assert_eq!(
+        context.first().unwrap().get_name(),
+        &String::from("Context")
+    );
+
+    let last = *tree.get_sub_roots().first().unwrap();
+
+    assert_eq!(tree.annotations_for(last).len(), 0);
+}
+
+#[test]
+fn test_annotation_targets_test() {
+    let input = String::from(
+        "\
+$ + 1
+
+@Test(5)
+$ == 6
+",
+    );
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    let test = tree.get_tests().first().unwrap();
+    let annotation = tree.get_annotations().first().unwrap();
+
+    assert_eq!(annotation.get_target(), Some(test.get_root()));
+    assert_eq!(
+        annotation.get_arguments(),
+        &vec![AnnotationArgument::Integer(5)]
+    );
+}
@@ -34,3 +34,30 @@ fn single_associative_list() {
     assert_eq!(r_right.get_operation(), Operation::Touch);
     assert_eq!(r_right.get_data_type(), DataType::Integer);
 }
+
+#[test]
+fn empty_associative_list_operand() {
+    let input = String::from("5 + []");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // tree should look like
+    //       +
+    //      / \
+    //     5  []
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::Addition);
+
+    assert_eq!(left.get_operation(), Operation::Touch);
+    assert_eq!(left.get_data_type(), DataType::Integer);
+
+    assert_eq!(right.get_operation(), Operation::AssociativeList);
+    assert_eq!(right.get_left(), None);
+    assert_eq!(right.get_right(), None);
+}
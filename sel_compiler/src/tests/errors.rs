@@ -0,0 +1,299 @@
+use super::super::{CompileError, CompileErrorKind, Compiler};
+use sel_common::{DataType, Operation};
+
+fn compile_errors(input: &str) -> Vec<CompileError> {
+    let compiler = Compiler::new();
+
+    return match compiler.try_compile(input) {
+        Ok(_) => panic!("Expected compile errors for {:?}", input),
+        Err(errors) => errors,
+    };
+}
+
+fn assert_single_error(input: &str, kind: CompileErrorKind, text: &str) {
+    let errors = compile_errors(input);
+
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+
+    let error = errors.get(0).unwrap();
+
+    assert_eq!(error.get_kind(), kind);
+    assert_eq!(error.get_span().slice(input), text);
+}
+
+#[test]
+fn try_compile_valid_input() {
+    let compiler = Compiler::new();
+
+    let tree = compiler.try_compile("5 + 10").unwrap();
+
+    let root = tree.get_root();
+
+    assert_eq!(root.get_operation(), Operation::Addition);
+    assert_eq!(root.get_data_type(), DataType::Unknown);
+}
+
+#[test]
+fn try_compile_valid_complex_input() {
+    let compiler = Compiler::new();
+
+    let result = compiler.try_compile(
+        "@Test(1, 2)\n@ comment\nclamp(10, min=5) -> [1, 2, -3] ~ (, 5)\n!true && ($.value >= -1)",
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn unclosed_group() {
+    assert_single_error("(5 + 10", CompileErrorKind::UnclosedGroup, "(");
+}
+
+#[test]
+fn unclosed_associative_list() {
+    assert_single_error("[1, 2, 3", CompileErrorKind::UnclosedAssociativeList, "[");
+}
+
+#[test]
+fn unclosed_expression_block() {
+    assert_single_error("{ 5 + 10", CompileErrorKind::UnclosedExpressionBlock, "{");
+}
+
+#[test]
+fn unexpected_close() {
+    assert_single_error("5 + 10)", CompileErrorKind::UnexpectedClose, ")");
+}
+
+#[test]
+fn mismatched_close() {
+    assert_single_error("(5 + 10]", CompileErrorKind::MismatchedClose, "]");
+}
+
+#[test]
+fn dangling_operator_right() {
+    assert_single_error("5 +", CompileErrorKind::DanglingOperator, "+");
+}
+
+#[test]
+fn dangling_operator_left() {
+    assert_single_error("* 5", CompileErrorKind::DanglingOperator, "*");
+}
+
+#[test]
+fn dangling_operator_before_close() {
+    assert_single_error("(5 *) + 1", CompileErrorKind::DanglingOperator, "*");
+}
+
+#[test]
+fn dangling_operator_across_lines() {
+    let errors = compile_errors("5 +\n\n");
+
+    assert_eq!(
+        errors.get(0).unwrap().get_kind(),
+        CompileErrorKind::DanglingOperator
+    );
+}
+
+#[test]
+fn operator_continues_on_next_line() {
+    let compiler = Compiler::new();
+
+    assert!(compiler.try_compile("5\n+ 10").is_ok());
+}
+
+#[test]
+fn negation_is_not_dangling() {
+    let compiler = Compiler::new();
+
+    assert!(compiler.try_compile("-5 * -(3)").is_ok());
+}
+
+#[test]
+fn dangling_not() {
+    assert_single_error("5 && !", CompileErrorKind::DanglingOperator, "!");
+}
+
+#[test]
+fn symbol_without_name() {
+    assert_single_error(":", CompileErrorKind::DanglingOperator, ":");
+}
+
+#[test]
+fn range_to_symbol_without_name() {
+    assert_single_error("1..:", CompileErrorKind::DanglingOperator, ":");
+}
+
+#[test]
+fn infix_call_without_right_operand() {
+    assert_single_error("5 `max` ", CompileErrorKind::DanglingOperator, "`max`");
+}
+
+#[test]
+fn infix_call_without_left_operand() {
+    assert_single_error("`max` 5", CompileErrorKind::DanglingOperator, "`max`");
+}
+
+#[test]
+fn unclosed_infix_call() {
+    assert_single_error("5 `max 3", CompileErrorKind::DanglingOperator, "`");
+}
+
+#[test]
+fn named_expression_starting_with_operator() {
+    assert_single_error("#f = 5", CompileErrorKind::DanglingOperator, "=");
+}
+
+#[test]
+fn named_expression_reference_is_operand() {
+    let compiler = Compiler::new();
+
+    assert!(compiler.try_compile("#f $ + 5\n\n10 -> #f").is_ok());
+    assert!(compiler.try_compile("#f $ + 5\n\n#f 10 `max` 5").is_ok());
+}
+
+#[test]
+fn unterminated_string() {
+    assert_single_error(
+        "5 + \"hello",
+        CompileErrorKind::UnterminatedString,
+        "\"hello",
+    );
+}
+
+#[test]
+fn unterminated_single_quoted_string() {
+    assert_single_error("'hello", CompileErrorKind::UnterminatedString, "'hello");
+}
+
+#[test]
+fn integer_out_of_range() {
+    assert_single_error(
+        "99999999999999999999 + 1",
+        CompileErrorKind::InvalidNumber,
+        "99999999999999999999",
+    );
+}
+
+#[test]
+fn number_with_trailing_identifier() {
+    assert_single_error("3abc + 1", CompileErrorKind::InvalidNumber, "3abc");
+}
+
+#[test]
+fn invalid_annotation_name() {
+    assert_single_error("@1Test\n5", CompileErrorKind::InvalidAnnotation, "@1Test");
+}
+
+#[test]
+fn unclosed_annotation_arguments() {
+    assert_single_error(
+        "@Test(1, 2\n5 + 10",
+        CompileErrorKind::InvalidAnnotation,
+        "@Test",
+    );
+}
+
+#[test]
+fn mock_without_name_and_result() {
+    assert_single_error(
+        "@Mock(5)\n@Test\n1 == 1",
+        CompileErrorKind::InvalidAnnotation,
+        "@Mock",
+    );
+    assert_single_error(
+        "@MockOnce\n@Test\n1 == 1",
+        CompileErrorKind::InvalidAnnotation,
+        "@MockOnce",
+    );
+}
+
+#[test]
+fn unknown_token() {
+    assert_single_error("5 $! 10", CompileErrorKind::UnknownToken, "$!");
+}
+
+#[test]
+fn multiple_errors_in_source_order() {
+    let errors = compile_errors("(5 +\n'abc");
+
+    let kinds: Vec<CompileErrorKind> = errors.iter().map(|error| error.get_kind()).collect();
+
+    assert_eq!(
+        kinds,
+        vec![
+            CompileErrorKind::UnclosedGroup,
+            CompileErrorKind::UnterminatedString
+        ]
+    );
+}
+
+#[test]
+fn error_display_includes_location() {
+    let errors = compile_errors("5 + 1\n(1");
+
+    assert_eq!(
+        format!("{}", errors.get(0).unwrap()),
+        "2:1-2:2: '(' is never closed, expected ')'"
+    );
+}
+
+#[test]
+fn malformed_input_does_not_panic() {
+    let compiler = Compiler::new();
+
+    let inputs = vec![
+        ")",
+        "]",
+        "}",
+        "((",
+        "))",
+        "(]",
+        "[)",
+        "+",
+        "-",
+        "!",
+        "5 +",
+        "+ 5",
+        "5 + * 3",
+        ",",
+        "'",
+        "\"",
+        "\"\\",
+        "@",
+        "@(",
+        "@Test(",
+        "@Test((",
+        "99999999999999999999",
+        "1..",
+        "..5",
+        "$.",
+        "$[",
+        "{ }",
+        "()",
+        "[]",
+        "a ->",
+        "-> a",
+        "~",
+        "`",
+        ":",
+        "1..:",
+        "5 `max` ",
+        "#f = 5",
+        ">>>",
+        "5 >-",
+        "1e400",
+    ];
+
+    for input in inputs {
+        let _ = compiler.try_compile(input);
+    }
+}
+
+#[test]
+fn collect_seed_and_expression_are_optional() {
+    let compiler = Compiler::new();
+
+    assert!(compiler.try_compile("$ >- -<").is_ok());
+    assert!(compiler.try_compile("$ >- [] -<").is_ok());
+    assert!(compiler.try_compile("$ >- 0 -< $.value + $.result").is_ok());
+}
@@ -416,3 +416,35 @@ fn multiple_groups_directly_nested() {
     assert_eq!(rr_right.get_operation(), Operation::Touch);
     assert_eq!(rr_right.get_data_type(), DataType::Integer);
 }
+
+#[test]
+fn group_skipped_slot() {
+    let input = String::from("(, 5)");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // tree should look like
+    //                  G
+    //                   \
+    //                   ,
+    //                  / \
+    //                 () 5
+
+    let root = tree.get_root();
+
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    let r_left = tree.get_nodes().get(right.get_left().unwrap()).unwrap();
+    let r_right = tree.get_nodes().get(right.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::Group);
+
+    assert_eq!(right.get_operation(), Operation::List);
+
+    assert_eq!(r_left.get_operation(), Operation::Touch);
+    assert_eq!(r_left.get_data_type(), DataType::Unit);
+
+    assert_eq!(r_right.get_operation(), Operation::Touch);
+    assert_eq!(r_right.get_data_type(), DataType::Integer);
+}
@@ -2,9 +2,12 @@ mod annotation;
 mod associative_list;
 mod call;
 mod conditional;
+mod errors;
 mod expressions;
 mod groups;
 mod multi_expr;
 mod multi_op;
+mod shapes;
 mod single_op;
+mod spans;
 mod touch;
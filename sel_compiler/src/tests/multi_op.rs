@@ -550,3 +550,347 @@ fn compiles_pair_of_pair() {
     assert_eq!(r_right.get_operation(), Operation::Touch);
     assert_eq!(r_right.get_data_type(), DataType::Integer);
 }
+
+#[test]
+fn seeded_collect() {
+    let input = String::from("$ >- 0 -< $.value");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // tree should look like
+    //           -<
+    //          /  \
+    //        >-    .
+    //       /  \  / \
+    //      $   0 $  value
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    let l_left = tree.get_nodes().get(left.get_left().unwrap()).unwrap();
+    let l_right = tree.get_nodes().get(left.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::CollectInit);
+
+    assert_eq!(left.get_operation(), Operation::Collect);
+
+    assert_eq!(l_left.get_operation(), Operation::Input);
+
+    assert_eq!(l_right.get_operation(), Operation::Touch);
+    assert_eq!(l_right.get_data_type(), DataType::Integer);
+
+    assert_eq!(right.get_operation(), Operation::DotAccess);
+}
+
+#[test]
+fn collect_without_seed_or_expression() {
+    let input = String::from("$ >- -<");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // seed is filled in with unit
+    //         -<
+    //        /
+    //      >-
+    //     /  \
+    //    $   ()
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+
+    let l_left = tree.get_nodes().get(left.get_left().unwrap()).unwrap();
+    let l_right = tree.get_nodes().get(left.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::CollectInit);
+    assert_eq!(root.get_right(), None);
+
+    assert_eq!(left.get_operation(), Operation::Collect);
+
+    assert_eq!(l_left.get_operation(), Operation::Input);
+
+    assert_eq!(l_right.get_operation(), Operation::Touch);
+    assert_eq!(l_right.get_data_type(), DataType::Unit);
+}
+
+#[test]
+fn collect_with_empty_list_seed() {
+    let input = String::from("$ >- [] -<");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+
+    let l_right = tree.get_nodes().get(left.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::CollectInit);
+    assert_eq!(root.get_right(), None);
+
+    assert_eq!(left.get_operation(), Operation::Collect);
+
+    assert_eq!(l_right.get_operation(), Operation::AssociativeList);
+    assert_eq!(l_right.get_right(), None);
+}
+
+#[test]
+fn combine_then_stream() {
+    let input = String::from("first <> second <> third >>> $");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // tree should look like
+    //               >>>
+    //              /   \
+    //            <>     $
+    //           /  \
+    //         <>   third
+    //        /  \
+    //    first  second
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    let l_left = tree.get_nodes().get(left.get_left().unwrap()).unwrap();
+    let l_right = tree.get_nodes().get(left.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::Stream);
+
+    assert_eq!(left.get_operation(), Operation::Combine);
+
+    assert_eq!(l_left.get_operation(), Operation::Combine);
+
+    assert_eq!(l_right.get_operation(), Operation::Touch);
+    assert_eq!(l_right.get_data_type(), DataType::Identifier);
+
+    assert_eq!(right.get_operation(), Operation::Input);
+}
+
+#[test]
+fn chained_partial_application() {
+    let input = String::from("clamp ~ (1) ~ (2)");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // tree should look like
+    //                  ~
+    //                 / \
+    //                ~   G
+    //               / \   \
+    //           clamp  G   2
+    //                   \
+    //                    1
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    let l_left = tree.get_nodes().get(left.get_left().unwrap()).unwrap();
+    let l_right = tree.get_nodes().get(left.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::PartialApplication);
+
+    assert_eq!(left.get_operation(), Operation::PartialApplication);
+
+    assert_eq!(right.get_operation(), Operation::Group);
+
+    assert_eq!(l_left.get_operation(), Operation::Touch);
+    assert_eq!(l_left.get_data_type(), DataType::Identifier);
+
+    assert_eq!(l_right.get_operation(), Operation::Group);
+}
+
+#[test]
+fn chained_infix_calls() {
+    let input = String::from("5 `min` 10 `max` 15");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // tree should look like
+    //           max
+    //          /   \
+    //        min    15
+    //       /   \
+    //      5     10
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    let l_left = tree.get_nodes().get(left.get_left().unwrap()).unwrap();
+    let l_right = tree.get_nodes().get(left.get_right().unwrap()).unwrap();
+
+    let symbols = tree.get_symbol_table();
+
+    assert_eq!(root.get_operation(), Operation::InfixCall);
+    assert_eq!(
+        symbols.get_symbol(tree.get_usize_value_of(root).unwrap()),
+        Some(&String::from("max"))
+    );
+
+    assert_eq!(left.get_operation(), Operation::InfixCall);
+    assert_eq!(
+        symbols.get_symbol(tree.get_usize_value_of(left).unwrap()),
+        Some(&String::from("min"))
+    );
+
+    assert_eq!(right.get_operation(), Operation::Touch);
+    assert_eq!(right.get_data_type(), DataType::Integer);
+
+    assert_eq!(l_left.get_operation(), Operation::Touch);
+    assert_eq!(l_left.get_data_type(), DataType::Integer);
+
+    assert_eq!(l_right.get_operation(), Operation::Touch);
+    assert_eq!(l_right.get_data_type(), DataType::Integer);
+}
+
+#[test]
+fn infix_call_with_addition() {
+    let input = String::from("5 + 10 `max` 15 + 20");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // tree should look like
+    //            max
+    //          /     \
+    //         +       +
+    //        / \     / \
+    //       5   10  15  20
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::InfixCall);
+
+    assert_eq!(left.get_operation(), Operation::Addition);
+
+    assert_eq!(right.get_operation(), Operation::Addition);
+}
+
+#[test]
+fn interpreted_access_with_expression_key() {
+    let input = String::from("$[? + 1]");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // tree should look like
+    //                  I
+    //                 / \
+    //                $   +
+    //                   / \
+    //                  ?   1
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    let r_left = tree.get_nodes().get(right.get_left().unwrap()).unwrap();
+    let r_right = tree.get_nodes().get(right.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::InterpretedAccess);
+
+    assert_eq!(left.get_operation(), Operation::Input);
+
+    assert_eq!(right.get_operation(), Operation::Addition);
+
+    assert_eq!(r_left.get_operation(), Operation::CurrentResult);
+
+    assert_eq!(r_right.get_operation(), Operation::Touch);
+    assert_eq!(r_right.get_data_type(), DataType::Integer);
+}
+
+#[test]
+fn interpreted_access_with_range_key() {
+    let input = String::from("5[1..3]");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // tree should look like
+    //                  I
+    //                 / \
+    //                5   ..
+    //                   /  \
+    //                  1    3
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::InterpretedAccess);
+
+    assert_eq!(left.get_operation(), Operation::Touch);
+    assert_eq!(left.get_data_type(), DataType::Integer);
+
+    assert_eq!(right.get_operation(), Operation::ExclusiveRange);
+}
+
+#[test]
+fn interpreted_access_then_dot_access() {
+    let input = String::from("value[0].name");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // tree should look like
+    //                  .
+    //                 / \
+    //                I   name
+    //               / \
+    //           value  0
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    let l_left = tree.get_nodes().get(left.get_left().unwrap()).unwrap();
+    let l_right = tree.get_nodes().get(left.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::DotAccess);
+
+    assert_eq!(left.get_operation(), Operation::InterpretedAccess);
+
+    assert_eq!(right.get_operation(), Operation::Touch);
+    assert_eq!(right.get_data_type(), DataType::Identifier);
+
+    assert_eq!(l_left.get_operation(), Operation::Touch);
+    assert_eq!(l_left.get_data_type(), DataType::Identifier);
+
+    assert_eq!(l_right.get_operation(), Operation::Touch);
+    assert_eq!(l_right.get_data_type(), DataType::Integer);
+}
+
+#[test]
+fn associative_list_on_next_line() {
+    let input = String::from("5\n[1, 2]");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    let root = tree.get_root();
+    let sub_root = tree.get_sub_root(0).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::Touch);
+
+    assert_eq!(sub_root.get_operation(), Operation::AssociativeList);
+}
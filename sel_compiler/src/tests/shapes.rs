@@ -0,0 +1,314 @@
+use super::super::{check_types, CompileError, CompileErrorKind, Compiler};
+use sel_common::DataType;
+
+fn compile_errors(input: &str) -> Vec<CompileError> {
+    return match Compiler::new().try_compile(input) {
+        Ok(_) => panic!("Expected compile errors for {:?}", input),
+        Err(errors) => errors,
+    };
+}
+
+fn assert_single_error(input: &str, kind: CompileErrorKind, text: &str, message: &str) {
+    let errors = compile_errors(input);
+
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+
+    let error = errors.first().unwrap();
+
+    assert_eq!(error.get_kind(), kind);
+    assert_eq!(error.get_span().slice(input), text);
+    assert_eq!(error.get_message(), message);
+}
+
+#[test]
+fn matching_shapes_compile() {
+    let input = "\
+@Shape(User, [first_name: string, age: int, scores: [int...]])
+@Input(User)
+@Result(string)
+$.first_name + \" \" + $.age + $.scores[0]
+";
+
+    assert!(Compiler::new().try_compile(input).is_ok());
+}
+
+#[test]
+fn infers_node_types() {
+    let input = "\
+@Input([int, decimal])
+$.0 * $[1]
+";
+    let tree = Compiler::new().compile(&String::from(input));
+
+    let check = check_types(&tree);
+    let types = check.get_types();
+
+    assert!(check.get_errors().is_empty());
+    assert_eq!(types[tree.get_root().get_own_index()], DataType::Decimal);
+    assert_eq!(
+        types[tree.get_root().get_left().unwrap()],
+        DataType::Integer
+    );
+}
+
+#[test]
+fn unknown_field_of_input() {
+    assert_single_error(
+        "@Input([first_name: string])\n$.last_name",
+        CompileErrorKind::ShapeMismatch,
+        "$.last_name",
+        "no field 'last_name' in [first_name: string]",
+    );
+}
+
+#[test]
+fn index_out_of_tuple() {
+    assert_single_error(
+        "@Input([int, string])\n$[2]",
+        CompileErrorKind::ShapeMismatch,
+        "$[2",
+        "no index 2 in [int, string]",
+    );
+}
+
+#[test]
+fn access_of_primitive_input() {
+    assert_single_error(
+        "@Input(int)\n$.name",
+        CompileErrorKind::ShapeMismatch,
+        "$.name",
+        "no field 'name' in int",
+    );
+}
+
+#[test]
+fn operator_mismatch_with_input() {
+    assert_single_error(
+        "@Input([name: string, active: bool])\n$.name * $.active",
+        CompileErrorKind::ShapeMismatch,
+        "$.name * $.active",
+        "cannot apply Multiplication to String and Boolean",
+    );
+}
+
+#[test]
+fn result_mismatch() {
+    assert_single_error(
+        "@Input(int)\n@Result(string)\n$ * 2",
+        CompileErrorKind::ShapeMismatch,
+        "$ * 2",
+        "expected string result, found Integer",
+    );
+}
+
+#[test]
+fn named_expressions_use_their_shapes() {
+    assert_single_error(
+        "\
+@Input([value: int])
+#double $.value * 2
+
+@Input([value: string])
+#shout $.valu + \"!\"
+",
+        CompileErrorKind::ShapeMismatch,
+        "$.valu",
+        "no field 'valu' in [value: string]",
+    );
+}
+
+#[test]
+fn named_expressions_called_by_tests_are_checked_once() {
+    assert_single_error(
+        "\
+@Input([int...])
+#sum $.name
+
+@Test
+#sum([1]) == 15
+",
+        CompileErrorKind::ShapeMismatch,
+        "$.name",
+        "no field 'name' in [int...]",
+    );
+}
+
+#[test]
+fn unknown_shape() {
+    assert_single_error(
+        "@Input(Person)\n$.name",
+        CompileErrorKind::InvalidShape,
+        "@Input",
+        "unknown shape 'Person'",
+    );
+}
+
+#[test]
+fn expressions_without_shapes_are_not_checked() {
+    assert!(Compiler::new().try_compile("\"a\" * true").is_ok());
+}
+
+mod validate_input {
+    use super::super::super::Compiler;
+    use sel_common::sel_types::associative_list::AssociativeList;
+    use sel_common::sel_types::list::List;
+    use sel_common::sel_types::pair::Pair;
+    use sel_common::sel_types::symbol::Symbol;
+    use sel_common::{SELTree, SELValue};
+
+    fn tree(input: &str) -> SELTree {
+        return Compiler::new().try_compile(input).unwrap();
+    }
+
+    fn list_of(values: Vec<SELValue>) -> SELValue {
+        let mut list = List::new();
+
+        for value in values {
+            list.push(value);
+        }
+
+        return SELValue::new_from_list(list);
+    }
+
+    fn fields_of(fields: Vec<(&str, SELValue)>) -> SELValue {
+        let mut list = AssociativeList::new();
+
+        for (name, value) in fields {
+            list.push(SELValue::new_from_pair(Pair::new(
+                SELValue::new_from_symbol(Symbol::new(String::from(name), 0)),
+                value,
+            )));
+        }
+
+        return SELValue::new_from_associative_list(list);
+    }
+
+    fn error_of(tree: &SELTree, value: &SELValue) -> String {
+        return tree.validate_input(value).unwrap_err().to_string();
+    }
+
+    #[test]
+    fn primitive_input() {
+        let tree = tree("@Input(decimal)\n$ * 2");
+
+        assert!(tree
+            .validate_input(&SELValue::new_from_decimal(1.5))
+            .is_ok());
+        assert!(tree.validate_input(&SELValue::new_from_int(1)).is_ok());
+        assert_eq!(
+            error_of(&tree, &SELValue::new_from_string(&String::from("1"))),
+            "$: expected decimal, found String"
+        );
+    }
+
+    #[test]
+    fn tuple_input() {
+        let tree = tree("@Input([int, string])\n$.0");
+
+        assert!(tree
+            .validate_input(&list_of(vec![
+                SELValue::new_from_int(1),
+                SELValue::new_from_string(&String::from("a"))
+            ]))
+            .is_ok());
+        assert_eq!(
+            error_of(&tree, &list_of(vec![SELValue::new_from_int(1)])),
+            "$: expected 2 values, found 1"
+        );
+        assert_eq!(
+            error_of(
+                &tree,
+                &list_of(vec![SELValue::new_from_int(1), SELValue::new_from_int(2)])
+            ),
+            "$[1]: expected string, found Integer"
+        );
+    }
+
+    #[test]
+    fn repeated_input() {
+        let tree = tree("@Input([int...])\n$.0");
+
+        assert!(tree.validate_input(&list_of(vec![])).is_ok());
+        assert_eq!(
+            error_of(
+                &tree,
+                &list_of(vec![SELValue::new_from_int(1), SELValue::new()])
+            ),
+            "$[1]: expected int, found Unit"
+        );
+    }
+
+    #[test]
+    fn nested_shapes() {
+        let tree = tree(
+            "\
+@Shape(Address, [street: string, zip: int])
+@Shape(User, [name: string, address: Address])
+@Input(User)
+$.address.zip
+",
+        );
+
+        let address = |zip: SELValue| {
+            fields_of(vec![
+                ("name", SELValue::new_from_string(&String::from("Panda"))),
+                (
+                    "address",
+                    fields_of(vec![
+                        ("street", SELValue::new_from_string(&String::from("Main"))),
+                        ("zip", zip),
+                    ]),
+                ),
+            ])
+        };
+
+        assert!(tree
+            .validate_input(&address(SELValue::new_from_int(12345)))
+            .is_ok());
+        assert_eq!(
+            error_of(
+                &tree,
+                &address(SELValue::new_from_string(&String::from("12345")))
+            ),
+            "$.address.zip: expected int, found String"
+        );
+        assert_eq!(
+            error_of(&tree, &fields_of(vec![("name", SELValue::new())])),
+            "$.name: expected string, found Unit"
+        );
+        assert_eq!(
+            error_of(
+                &tree,
+                &fields_of(vec![(
+                    "name",
+                    SELValue::new_from_string(&String::from("Panda"))
+                )])
+            ),
+            "$: missing field 'address'"
+        );
+    }
+
+    #[test]
+    fn indexes_within_fields() {
+        let tree = tree("@Input([scores: [int...]])\n$.scores");
+
+        assert_eq!(
+            error_of(
+                &tree,
+                &fields_of(vec![(
+                    "scores",
+                    list_of(vec![
+                        SELValue::new_from_int(1),
+                        SELValue::new_from_string(&String::from("2"))
+                    ])
+                )])
+            ),
+            "$.scores[1]: expected int, found String"
+        );
+    }
+
+    #[test]
+    fn trees_without_input_accept_anything() {
+        assert!(tree("$ + 1").validate_input(&SELValue::new()).is_ok());
+    }
+}
@@ -924,6 +924,62 @@ fn compiles_named_expression_with_following_expression() {
     assert_eq!(right.get_data_type(), DataType::Decimal);
 }
 
+#[test]
+fn compiles_named_expression_reference() {
+    let input = String::from("#plus_five $ + 5\n\n#plus_five(10)");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    assert_eq!(tree.get_named_expressions().len(), 1);
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    let symbol = tree
+        .get_symbol_table()
+        .get_symbol(tree.get_usize_value_of(left).unwrap());
+
+    assert_eq!(root.get_operation(), Operation::Group);
+
+    assert_eq!(left.get_operation(), Operation::Touch);
+    assert_eq!(left.get_data_type(), DataType::Identifier);
+    assert_eq!(symbol, Some(&String::from("plus_five")));
+
+    assert_eq!(right.get_operation(), Operation::Touch);
+    assert_eq!(right.get_data_type(), DataType::Integer);
+}
+
+#[test]
+fn compiles_named_expression_reference_with_value() {
+    let input = String::from("#plus_five $ + 5\n\n#plus_five 10 + 1");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    // called with the rest of the line
+    // tree should look like
+    //                  <-
+    //                 /  \
+    //        #plus_five   +
+    //                    / \
+    //                   10  1
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::PipeFirstLeft);
+
+    assert_eq!(left.get_operation(), Operation::Touch);
+    assert_eq!(left.get_data_type(), DataType::Identifier);
+
+    assert_eq!(right.get_operation(), Operation::Addition);
+}
+
 #[test]
 fn compiles_pipe_first_left() {
     let input = String::from("func <- 10");
@@ -1099,3 +1155,25 @@ fn compiles_collect_init() {
     assert_eq!(right.get_operation(), Operation::Touch);
     assert_eq!(right.get_data_type(), DataType::Integer);
 }
+
+#[test]
+fn compiles_combine() {
+    let input = String::from("first <> second");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    let root = tree.get_root();
+
+    let left = tree.get_nodes().get(root.get_left().unwrap()).unwrap();
+    let right = tree.get_nodes().get(root.get_right().unwrap()).unwrap();
+
+    assert_eq!(root.get_operation(), Operation::Combine);
+    assert_eq!(root.get_data_type(), DataType::Unknown);
+
+    assert_eq!(left.get_operation(), Operation::Touch);
+    assert_eq!(left.get_data_type(), DataType::Identifier);
+
+    assert_eq!(right.get_operation(), Operation::Touch);
+    assert_eq!(right.get_data_type(), DataType::Identifier);
+}
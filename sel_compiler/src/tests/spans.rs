@@ -0,0 +1,87 @@
+use super::super::Compiler;
+use sel_common::{Location, Operation, SELTree, SELTreeNode};
+
+fn assert_node_span(node: &SELTreeNode, start: (usize, usize, usize), end: (usize, usize, usize)) {
+    let span = node.get_span().unwrap();
+
+    assert_eq!(span.get_start(), Location::new(start.0, start.1, start.2));
+    assert_eq!(span.get_end(), Location::new(end.0, end.1, end.2));
+}
+
+fn left_of<'a>(tree: &'a SELTree, node: &SELTreeNode) -> &'a SELTreeNode {
+    return tree.get_nodes().get(node.get_left().unwrap()).unwrap();
+}
+
+fn right_of<'a>(tree: &'a SELTree, node: &SELTreeNode) -> &'a SELTreeNode {
+    return tree.get_nodes().get(node.get_right().unwrap()).unwrap();
+}
+
+#[test]
+fn binary_operation_spans() {
+    let input = String::from("5 + 10");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    let root = tree.get_root();
+
+    assert_node_span(root, (2, 1, 3), (3, 1, 4));
+    assert_node_span(left_of(&tree, root), (0, 1, 1), (1, 1, 2));
+    assert_node_span(right_of(&tree, root), (4, 1, 5), (6, 1, 7));
+}
+
+#[test]
+fn symbol_span_covers_identifier() {
+    let input = String::from(":value = 10");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    let root = tree.get_root();
+    let symbol = left_of(&tree, root);
+
+    assert_eq!(root.get_operation(), Operation::Pair);
+    assert_node_span(symbol, (0, 1, 1), (6, 1, 7));
+    assert_eq!(symbol.get_span().unwrap().slice(&input), ":value");
+}
+
+#[test]
+fn sub_root_spans_on_later_lines() {
+    let input = String::from("5 + 10\n'Result: ' + ?");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    let sub_root = tree.get_sub_root(0).unwrap();
+
+    assert_node_span(sub_root, (18, 2, 12), (19, 2, 13));
+    assert_eq!(
+        left_of(&tree, sub_root).get_span().unwrap().slice(&input),
+        "'Result: '"
+    );
+    assert_node_span(right_of(&tree, sub_root), (20, 2, 14), (21, 2, 15));
+}
+
+#[test]
+fn group_span_is_opening_token() {
+    let input = String::from("5 * (4 + 3)");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    let root = tree.get_root();
+    let group = right_of(&tree, root);
+
+    assert_eq!(group.get_operation(), Operation::Group);
+    assert_node_span(group, (4, 1, 5), (5, 1, 6));
+}
+
+#[test]
+fn empty_tree_has_no_span() {
+    let input = String::from("");
+    let compiler = Compiler::new();
+
+    let tree = compiler.compile(&input);
+
+    assert_eq!(tree.get_root().get_span(), None);
+}
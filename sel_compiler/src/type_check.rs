@@ -0,0 +1,508 @@
+use crate::compile_error::{CompileError, CompileErrorKind};
+use sel_common::annotation::{Annotation, AnnotationArgument};
+use sel_common::shape::{Shape, ShapeDefinitions};
+use sel_common::{DataType, Location, Operation, SELTree, SELTreeNode, Span};
+use std::collections::HashSet;
+
+// types inferred for the nodes of a tree
+// and mismatches with the shapes declared by @Input and @Result
+pub struct TypeCheck {
+    types: Vec<DataType>,
+    errors: Vec<CompileError>,
+}
+
+impl TypeCheck {
+    // indexed by node, Unknown when the type depends on values at runtime
+    pub fn get_types(&self) -> &Vec<DataType> {
+        return &self.types;
+    }
+
+    pub fn get_errors(&self) -> &Vec<CompileError> {
+        return &self.errors;
+    }
+}
+
+struct Checker<'a> {
+    tree: &'a SELTree,
+    types: Vec<DataType>,
+    errors: Vec<CompileError>,
+    // mismatches are only reported for expressions with a declared shape
+    report: bool,
+}
+
+fn span_of(node: &SELTreeNode) -> Span {
+    return node
+        .get_span()
+        .unwrap_or_else(|| Span::new(Location::start(), Location::start()));
+}
+
+// span covering the node and its operands
+fn expression_span(tree: &SELTree, node: &SELTreeNode) -> Span {
+    let mut span = span_of(node);
+
+    for side in [node.get_left(), node.get_right()].iter() {
+        if let Some(operand) = side.and_then(|index| tree.get_nodes().get(index)) {
+            span = span.merge(&expression_span(tree, operand));
+        }
+    }
+
+    return span;
+}
+
+fn annotation_span(annotation: &Annotation, tree: &SELTree) -> Span {
+    return annotation
+        .get_span()
+        .unwrap_or_else(|| span_of(tree.get_root()));
+}
+
+fn is_number(data_type: DataType) -> bool {
+    return data_type == DataType::Integer || data_type == DataType::Decimal;
+}
+
+// result of arithmetic, None if the operation fails for these types
+// mirrors the operations of the executor
+fn arithmetic_type(op: Operation, left: DataType, right: DataType) -> Option<DataType> {
+    return match (left, right) {
+        (DataType::Unit, _) | (_, DataType::Unit) => Some(DataType::Unit),
+        (DataType::Integer, DataType::Integer) => Some(DataType::Integer),
+        (left, right) if is_number(left) && is_number(right) => match op {
+            Operation::IntegerDivision => Some(DataType::Integer),
+            _ => Some(DataType::Decimal),
+        },
+        (DataType::String, other) | (other, DataType::String)
+            if op == Operation::Addition
+                && (other == DataType::String
+                    || other == DataType::Boolean
+                    || is_number(other)) =>
+        {
+            Some(DataType::String)
+        }
+        _ => None,
+    };
+}
+
+fn comparison_type(left: DataType, right: DataType) -> Option<DataType> {
+    return match (left, right) {
+        (DataType::Unit, _) | (_, DataType::Unit) => Some(DataType::Unit),
+        (left, right) if is_number(left) && is_number(right) => Some(DataType::Boolean),
+        (DataType::String, DataType::String) => Some(DataType::Boolean),
+        _ => None,
+    };
+}
+
+fn binary_type(op: Operation, left: DataType, right: DataType) -> Option<DataType> {
+    return match op {
+        Operation::Addition
+        | Operation::Subtraction
+        | Operation::Multiplication
+        | Operation::Division
+        | Operation::IntegerDivision
+        | Operation::Modulo
+        | Operation::Exponential => arithmetic_type(op, left, right),
+        Operation::BitwiseAnd
+        | Operation::BitwiseOr
+        | Operation::BitwiseXOR
+        | Operation::BitwiseLeftShift
+        | Operation::BitwiseRightShift => match (left, right) {
+            (DataType::Integer, DataType::Integer) => Some(DataType::Integer),
+            _ => None,
+        },
+        _ => comparison_type(left, right),
+    };
+}
+
+fn unary_type(op: Operation, operand: DataType) -> Option<DataType> {
+    return match (op, operand) {
+        (Operation::Negation, operand) if is_number(operand) => Some(operand),
+        (Operation::Negation, DataType::Unit) => Some(DataType::Unit),
+        (Operation::Not, DataType::Boolean) | (Operation::Not, DataType::Unit) => {
+            Some(DataType::Boolean)
+        }
+        (Operation::Not, DataType::Integer) => Some(DataType::Integer),
+        _ => None,
+    };
+}
+
+// key of an access, i.e. `name` in `$.name` or `0` in `$[0]`
+enum Key {
+    Name(String),
+    Index(i64),
+}
+
+impl<'a> Checker<'a> {
+    fn error(&mut self, span: Span, message: String) {
+        if self.report {
+            self.errors.push(CompileError::new(
+                CompileErrorKind::ShapeMismatch,
+                span,
+                message,
+            ));
+        }
+    }
+
+    fn infer_side(&mut self, index: Option<usize>, input: &Shape) -> Shape {
+        return match index {
+            Some(index) => self.infer(index, input),
+            None => Shape::Any,
+        };
+    }
+
+    // shape of the node's result when evaluated with input of the given shape
+    fn infer(&mut self, index: usize, input: &Shape) -> Shape {
+        let tree = self.tree;
+        let node = match tree.get_nodes().get(index) {
+            Some(node) => node,
+            None => return Shape::Any,
+        };
+
+        let op = node.get_operation();
+
+        let shape = match op {
+            Operation::Touch => match node.get_data_type() {
+                DataType::Integer
+                | DataType::Decimal
+                | DataType::String
+                | DataType::Boolean
+                | DataType::Unit
+                | DataType::Symbol => Shape::Type(node.get_data_type()),
+                _ => Shape::Any,
+            },
+            Operation::Input => input.clone(),
+            Operation::Symbol => Shape::Type(DataType::Symbol),
+            Operation::DotAccess => {
+                let left = self.infer_side(node.get_left(), input);
+                let key = node.get_right().and_then(|right| self.key_of(right, false));
+
+                self.access(node, left, key)
+            }
+            Operation::InterpretedAccess => {
+                let left = self.infer_side(node.get_left(), input);
+                self.infer_side(node.get_right(), input);
+                let key = node.get_right().and_then(|right| self.key_of(right, true));
+
+                self.access(node, left, key)
+            }
+            Operation::Negation | Operation::Not => {
+                let operand = self.infer_side(node.get_right(), input).get_data_type();
+
+                if operand == DataType::Unknown {
+                    Shape::Any
+                } else {
+                    match unary_type(op, operand) {
+                        Some(data_type) => Shape::Type(data_type),
+                        None => {
+                            self.error(
+                                expression_span(tree, node),
+                                format!("cannot apply {:?} to {}", op, operand),
+                            );
+                            Shape::Any
+                        }
+                    }
+                }
+            }
+            Operation::Addition
+            | Operation::Subtraction
+            | Operation::Multiplication
+            | Operation::Division
+            | Operation::IntegerDivision
+            | Operation::Modulo
+            | Operation::Exponential
+            | Operation::BitwiseAnd
+            | Operation::BitwiseOr
+            | Operation::BitwiseXOR
+            | Operation::BitwiseLeftShift
+            | Operation::BitwiseRightShift
+            | Operation::GreaterThan
+            | Operation::GreaterThanOrEqual
+            | Operation::LessThan
+            | Operation::LessThanOrEqual => {
+                let left = self.infer_side(node.get_left(), input).get_data_type();
+                let right = self.infer_side(node.get_right(), input).get_data_type();
+
+                if left == DataType::Unknown || right == DataType::Unknown {
+                    Shape::Any
+                } else {
+                    match binary_type(op, left, right) {
+                        Some(data_type) => Shape::Type(data_type),
+                        None => {
+                            self.error(
+                                expression_span(tree, node),
+                                format!("cannot apply {:?} to {} and {}", op, left, right),
+                            );
+                            Shape::Any
+                        }
+                    }
+                }
+            }
+            Operation::Equality
+            | Operation::Inequality
+            | Operation::KeysEqual
+            | Operation::KeysNotEqual
+            | Operation::ValuesEqual
+            | Operation::ValuesNotEqual
+            | Operation::Contains
+            | Operation::NotContains
+            | Operation::LogicalAnd
+            | Operation::LogicalOr
+            | Operation::LogicalXOR
+            | Operation::MatchTrue
+            | Operation::MatchFalse
+            | Operation::MatchList => {
+                self.infer_side(node.get_left(), input);
+                self.infer_side(node.get_right(), input);
+                Shape::Any
+            }
+            Operation::ExclusiveRange | Operation::InclusiveRange => {
+                self.infer_side(node.get_left(), input);
+                self.infer_side(node.get_right(), input);
+                Shape::Type(DataType::Range)
+            }
+            Operation::Pair => {
+                self.infer_side(node.get_left(), input);
+                self.infer_side(node.get_right(), input);
+                Shape::Type(DataType::Pair)
+            }
+            Operation::List => {
+                self.infer_side(node.get_left(), input);
+                self.infer_side(node.get_right(), input);
+                Shape::Type(DataType::List)
+            }
+            Operation::AssociativeList => {
+                self.infer_side(node.get_right(), input);
+                Shape::Type(DataType::AssociativeList)
+            }
+            Operation::Group => match node.get_left() {
+                // arguments of a call are evaluated with the same input
+                Some(left) => {
+                    self.infer(left, &Shape::Any);
+                    self.infer_side(node.get_right(), input);
+                    Shape::Any
+                }
+                None => self.infer_side(node.get_right(), input),
+            },
+            // value is on the left of right pipes and on the right of left pipes
+            Operation::PipeFirstRight | Operation::PipeLastRight => {
+                self.infer_side(node.get_left(), input);
+                self.infer_side(node.get_right(), &Shape::Any);
+                Shape::Any
+            }
+            Operation::PipeFirstLeft | Operation::PipeLastLeft => {
+                self.infer_side(node.get_left(), &Shape::Any);
+                self.infer_side(node.get_right(), input);
+                Shape::Any
+            }
+            Operation::Stream => {
+                self.infer_side(node.get_left(), input);
+                self.infer_side(node.get_right(), &Shape::Any);
+                Shape::Any
+            }
+            // operations that give their operands other inputs
+            _ => {
+                self.infer_side(node.get_left(), &Shape::Any);
+                self.infer_side(node.get_right(), &Shape::Any);
+                Shape::Any
+            }
+        };
+
+        if let Some(data_type) = self.types.get_mut(index) {
+            *data_type = shape.get_data_type();
+        }
+
+        return shape;
+    }
+
+    // names of dot access are identifiers, interpreted access is given values
+    fn key_of(&self, index: usize, interpreted: bool) -> Option<Key> {
+        let node = self.tree.get_nodes().get(index)?;
+
+        if node.get_operation() != Operation::Touch {
+            return None;
+        }
+
+        return match node.get_data_type() {
+            DataType::Identifier if !interpreted => self
+                .tree
+                .get_integer_value_of(node)
+                .and_then(|symbol| self.tree.get_symbol_table().get_symbol(symbol as usize))
+                .map(|name| Key::Name(name.clone())),
+            DataType::String if interpreted => self.tree.get_string_value_of(node).map(Key::Name),
+            DataType::Integer => self.tree.get_integer_value_of(node).map(Key::Index),
+            _ => None,
+        };
+    }
+
+    fn access(&mut self, node: &SELTreeNode, shape: Shape, key: Option<Key>) -> Shape {
+        let key = match key {
+            Some(key) => key,
+            None => return Shape::Any,
+        };
+
+        let found = match (&shape, &key) {
+            (Shape::Fields(fields), Key::Name(name)) => fields
+                .iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, shape)| shape.clone()),
+            (Shape::Fields(fields), Key::Index(index)) => {
+                position(*index, fields.len()).map(|index| fields[index].1.clone())
+            }
+            (Shape::Tuple(shapes), Key::Index(index)) => {
+                position(*index, shapes.len()).map(|index| shapes[index].clone())
+            }
+            (Shape::Repeated(shape), Key::Index(_)) => Some(*shape.clone()),
+            (Shape::Type(data_type), _) if is_number(*data_type) => None,
+            (Shape::Type(DataType::Boolean), _) => None,
+            (Shape::Type(DataType::String), Key::Index(_)) => Some(Shape::Type(DataType::String)),
+            (Shape::Type(DataType::String), Key::Name(_)) => None,
+            (Shape::Type(_), _) | (Shape::Any, _) => return Shape::Any,
+            (Shape::Tuple(_), Key::Name(_)) | (Shape::Repeated(_), Key::Name(_)) => None,
+        };
+
+        return match found {
+            Some(shape) => shape,
+            None => {
+                let message = match key {
+                    Key::Name(name) => format!("no field '{}' in {}", name, shape),
+                    Key::Index(index) => format!("no index {} in {}", index, shape),
+                };
+
+                self.error(expression_span(self.tree, node), message);
+                Shape::Any
+            }
+        };
+    }
+}
+
+// negative indices count from the end
+fn position(index: i64, length: usize) -> Option<usize> {
+    let index = if index < 0 {
+        length as i64 + index
+    } else {
+        index
+    };
+
+    return if index >= 0 && (index as usize) < length {
+        Some(index as usize)
+    } else {
+        None
+    };
+}
+
+// infers types of all expressions of the tree
+// expressions annotated with @Input or @Result are checked against them
+pub fn check_types(tree: &SELTree) -> TypeCheck {
+    let mut checker = Checker {
+        tree,
+        types: vec![DataType::Unknown; tree.get_nodes().len()],
+        errors: vec![],
+        report: false,
+    };
+
+    let annotations = tree.get_annotations();
+    let definitions = ShapeDefinitions::from_annotations(annotations);
+
+    let mut shape_names = HashSet::new();
+
+    for annotation in annotations.iter().filter(|a| a.get_name() == "Shape") {
+        let span = annotation_span(annotation, tree);
+
+        match annotation.get_arguments().as_slice() {
+            [AnnotationArgument::Identifier(name), shape] => {
+                if !shape_names.insert(name) {
+                    checker.errors.push(CompileError::new(
+                        CompileErrorKind::InvalidShape,
+                        span,
+                        format!("shape '{}' is defined more than once", name),
+                    ));
+                }
+
+                if let Err(message) = definitions.resolve(shape) {
+                    checker.errors.push(CompileError::new(
+                        CompileErrorKind::InvalidShape,
+                        span,
+                        message,
+                    ));
+                }
+            }
+            _ => checker.errors.push(CompileError::new(
+                CompileErrorKind::InvalidShape,
+                span,
+                String::from("annotation 'Shape' requires a name and a shape"),
+            )),
+        }
+    }
+
+    let mut roots = vec![tree.get_root().get_own_index()];
+    roots.extend(tree.get_sub_roots());
+    roots.extend(tree.get_named_expressions().values().map(|n| n.get_root()));
+    roots.extend(tree.get_tests().iter().map(|test| test.get_root()));
+
+    // roots can repeat, i.e. a named expression is also the root
+    // when the file has nothing else besides tests
+    let mut checked_roots = HashSet::new();
+    roots.retain(|root| checked_roots.insert(*root));
+
+    for root in roots {
+        let mut input = Shape::Any;
+        let mut result: Option<(Shape, Span)> = None;
+
+        for name in ["Input", "Result"].iter() {
+            let declared: Vec<&Annotation> = tree
+                .annotations_for(root)
+                .into_iter()
+                .filter(|annotation| annotation.get_name() == name)
+                .collect();
+
+            if declared.len() > 1 {
+                checker.errors.push(CompileError::new(
+                    CompileErrorKind::InvalidShape,
+                    annotation_span(declared[1], tree),
+                    format!("expression has more than one @{}", name),
+                ));
+            }
+
+            let annotation = match declared.first() {
+                Some(annotation) => annotation,
+                None => continue,
+            };
+
+            match definitions.resolve_annotation(annotation) {
+                Ok(shape) if *name == "Input" => input = shape,
+                Ok(shape) => result = Some((shape, annotation_span(annotation, tree))),
+                Err(message) => checker.errors.push(CompileError::new(
+                    CompileErrorKind::InvalidShape,
+                    annotation_span(annotation, tree),
+                    message,
+                )),
+            }
+        }
+
+        checker.report = input != Shape::Any || result.is_some();
+
+        let inferred = checker.infer(root, &input);
+
+        if let Some((expected, span)) = result {
+            let data_type = inferred.get_data_type();
+
+            if !expected.accepts_type(data_type) {
+                let span = tree
+                    .get_nodes()
+                    .get(root)
+                    .map_or(span, |root| expression_span(tree, root));
+
+                checker.error(
+                    span,
+                    format!("expected {} result, found {}", expected, data_type),
+                );
+            }
+        }
+    }
+
+    checker
+        .errors
+        .sort_by_key(|error| error.get_span().get_start().get_offset());
+
+    return TypeCheck {
+        types: checker.types,
+        errors: checker.errors,
+    };
+}
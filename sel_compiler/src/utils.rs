@@ -130,6 +130,7 @@ pub fn get_operation_type_for_token(token: &Token) -> Operation {
         TokenType::Stream => Operation::Stream,
         TokenType::Collect => Operation::Collect,
         TokenType::SeedCollect => Operation::CollectInit,
+        TokenType::Combine => Operation::Combine,
         TokenType::Partial => Operation::PartialApplication,
         TokenType::Boolean
         | TokenType::Integer
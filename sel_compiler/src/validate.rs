@@ -0,0 +1,536 @@
+use crate::compile_error::{CompileError, CompileErrorKind};
+use sel_tokenizer::{Token, TokenType, Tokenizer};
+use std::collections::HashSet;
+
+const BINARY_OPERATORS: [TokenType; 41] = [
+    TokenType::PlusSign,
+    TokenType::MinusSign,
+    TokenType::MultiplicationSign,
+    TokenType::DivisionSign,
+    TokenType::IntegerDivisionSign,
+    TokenType::ModulusSign,
+    TokenType::ExponentialSign,
+    TokenType::ExclusiveRange,
+    TokenType::InclusiveRange,
+    TokenType::BitwiseAndSign,
+    TokenType::BitwiseXorSign,
+    TokenType::BitwiseOrSign,
+    TokenType::BitwiseLeftShiftSign,
+    TokenType::BitwiseRightShiftSign,
+    TokenType::Equal,
+    TokenType::NotEqual,
+    TokenType::KeysEqual,
+    TokenType::KeysNotEqual,
+    TokenType::ValuesEqual,
+    TokenType::ValuesNotEqual,
+    TokenType::Contains,
+    TokenType::NotContains,
+    TokenType::GreaterThan,
+    TokenType::GreaterThanOrEqual,
+    TokenType::LessThan,
+    TokenType::LessThanOrEqual,
+    TokenType::LogicalAnd,
+    TokenType::LogicalOr,
+    TokenType::LogicalXOR,
+    TokenType::Pair,
+    TokenType::PipeFirstRight,
+    TokenType::PipeFirstLeft,
+    TokenType::PipeLastRight,
+    TokenType::PipeLastLeft,
+    TokenType::Partial,
+    TokenType::MatchTrue,
+    TokenType::MatchFalse,
+    TokenType::Stream,
+    TokenType::Collect,
+    TokenType::SeedCollect,
+    TokenType::Combine,
+];
+
+// tokens that can end an operand
+const OPERAND_ENDS: [TokenType; 13] = [
+    TokenType::Integer,
+    TokenType::Decimal,
+    TokenType::SingleQuotedString,
+    TokenType::DoubleQuotedString,
+    TokenType::Boolean,
+    TokenType::Unit,
+    TokenType::Input,
+    TokenType::CurrentResult,
+    TokenType::Identifier,
+    TokenType::EndGroup,
+    TokenType::EndAssociativeList,
+    TokenType::EndExpressionBlock,
+    TokenType::Symbol,
+];
+
+// tokens that can start an operand
+const OPERAND_STARTS: [TokenType; 15] = [
+    TokenType::Integer,
+    TokenType::Decimal,
+    TokenType::SingleQuotedString,
+    TokenType::DoubleQuotedString,
+    TokenType::Boolean,
+    TokenType::Unit,
+    TokenType::Input,
+    TokenType::CurrentResult,
+    TokenType::Identifier,
+    TokenType::StartGroup,
+    TokenType::StartAssociativeList,
+    TokenType::StartExpressionBlock,
+    TokenType::Symbol,
+    TokenType::NotSign,
+    TokenType::MinusSign,
+];
+
+fn is_skipped(token: &Token) -> bool {
+    return token.get_token_type() == TokenType::LineEnd;
+}
+
+fn closing_for(open: TokenType) -> &'static str {
+    return match open {
+        TokenType::StartGroup => ")",
+        TokenType::StartAssociativeList => "]",
+        _ => "}",
+    };
+}
+
+fn unclosed_kind(open: TokenType) -> CompileErrorKind {
+    return match open {
+        TokenType::StartGroup => CompileErrorKind::UnclosedGroup,
+        TokenType::StartAssociativeList => CompileErrorKind::UnclosedAssociativeList,
+        _ => CompileErrorKind::UnclosedExpressionBlock,
+    };
+}
+
+fn opening_for(close: TokenType) -> TokenType {
+    return match close {
+        TokenType::EndGroup => TokenType::StartGroup,
+        TokenType::EndAssociativeList => TokenType::StartAssociativeList,
+        _ => TokenType::StartExpressionBlock,
+    };
+}
+
+// returns indices of tokens that make up annotation arguments
+// so the remaining checks can ignore them
+fn check_annotations(tokens: &[Token], errors: &mut Vec<CompileError>) -> HashSet<usize> {
+    let mut argument_indices: HashSet<usize> = HashSet::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        if token.get_token_type() != TokenType::Annotation {
+            continue;
+        }
+
+        let name = String::from(&token.get_token_str()[1..]);
+        let valid_name = name
+            .chars()
+            .next()
+            .map_or(false, |c| c.is_alphabetic() || c == '_');
+
+        if !valid_name {
+            errors.push(CompileError::new(
+                CompileErrorKind::InvalidAnnotation,
+                token.get_span(),
+                format!("invalid annotation name '{}'", name),
+            ));
+        }
+
+        // arguments must immediately follow the annotation name
+        let has_arguments = tokens.get(index + 1).map_or(false, |next| {
+            next.get_token_type() == TokenType::StartGroup
+                && next.get_span().get_start().get_offset()
+                    == token.get_span().get_end().get_offset()
+        });
+
+        // mocks are given the name of what they replace and its result
+        // i.e. @Mock(get_user_by_id, [:name = "Panda"])
+        let is_mock = name == "Mock" || name == "MockOnce";
+        let names_mocked = has_arguments
+            && matches!(
+                (
+                    tokens.get(index + 2).map(|next| next.get_token_type()),
+                    tokens.get(index + 3).map(|next| next.get_token_type())
+                ),
+                (Some(TokenType::Identifier), Some(TokenType::Comma))
+            );
+
+        if is_mock && !names_mocked {
+            errors.push(CompileError::new(
+                CompileErrorKind::InvalidAnnotation,
+                token.get_span(),
+                format!("annotation '{}' requires a name and a result", name),
+            ));
+        }
+
+        if !has_arguments {
+            continue;
+        }
+
+        let mut depth = 0;
+        let mut closed = false;
+        let mut current = index + 1;
+
+        while let Some(argument) = tokens.get(current) {
+            if argument.get_token_type() == TokenType::LineEnd {
+                break;
+            }
+
+            argument_indices.insert(current);
+
+            match argument.get_token_type() {
+                TokenType::StartGroup => depth += 1,
+                TokenType::EndGroup => {
+                    depth -= 1;
+                    if depth == 0 {
+                        closed = true;
+                        break;
+                    }
+                }
+                _ => (),
+            }
+
+            current += 1;
+        }
+
+        if !closed {
+            errors.push(CompileError::new(
+                CompileErrorKind::InvalidAnnotation,
+                token.get_span(),
+                format!("arguments of annotation '{}' are not closed", name),
+            ));
+        }
+    }
+
+    return argument_indices;
+}
+
+fn check_groups(tokens: &[Token], ignored: &HashSet<usize>, errors: &mut Vec<CompileError>) {
+    let mut open_stack: Vec<&Token> = vec![];
+
+    for (index, token) in tokens.iter().enumerate() {
+        if ignored.contains(&index) {
+            continue;
+        }
+
+        match token.get_token_type() {
+            TokenType::StartGroup
+            | TokenType::StartAssociativeList
+            | TokenType::StartExpressionBlock => open_stack.push(token),
+            TokenType::EndGroup | TokenType::EndAssociativeList | TokenType::EndExpressionBlock => {
+                match open_stack.pop() {
+                    None => errors.push(CompileError::new(
+                        CompileErrorKind::UnexpectedClose,
+                        token.get_span(),
+                        format!(
+                            "unexpected '{}' with nothing to close",
+                            token.get_token_str()
+                        ),
+                    )),
+                    Some(open) => {
+                        if open.get_token_type() != opening_for(token.get_token_type()) {
+                            errors.push(CompileError::new(
+                                CompileErrorKind::MismatchedClose,
+                                token.get_span(),
+                                format!(
+                                    "expected '{}' to close '{}' at {} but found '{}'",
+                                    closing_for(open.get_token_type()),
+                                    open.get_token_str(),
+                                    open.get_span().get_start(),
+                                    token.get_token_str()
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    for open in open_stack {
+        errors.push(CompileError::new(
+            unclosed_kind(open.get_token_type()),
+            open.get_span(),
+            format!(
+                "'{}' is never closed, expected '{}'",
+                open.get_token_str(),
+                closing_for(open.get_token_type())
+            ),
+        ));
+    }
+}
+
+fn check_numbers(tokens: &[Token], ignored: &HashSet<usize>, errors: &mut Vec<CompileError>) {
+    for (index, token) in tokens.iter().enumerate() {
+        if ignored.contains(&index) {
+            continue;
+        }
+
+        let valid = match token.get_token_type() {
+            TokenType::Integer => token.get_token_str().parse::<i64>().is_ok(),
+            TokenType::Decimal => token
+                .get_token_str()
+                .parse::<f64>()
+                .map_or(false, |num| num.is_finite()),
+            _ => continue,
+        };
+
+        if !valid {
+            errors.push(CompileError::new(
+                CompileErrorKind::InvalidNumber,
+                token.get_span(),
+                format!(
+                    "numeric literal '{}' is out of range",
+                    token.get_token_str()
+                ),
+            ));
+            continue;
+        }
+
+        // identifiers directly attached to a number, i.e. 3abc
+        match tokens.get(index + 1) {
+            Some(next)
+                if next.get_token_type() == TokenType::Identifier
+                    && next.get_span().get_start() == token.get_span().get_end() =>
+            {
+                let span = token.get_span().merge(&next.get_span());
+
+                errors.push(CompileError::new(
+                    CompileErrorKind::InvalidNumber,
+                    span,
+                    format!(
+                        "invalid numeric literal '{}{}'",
+                        token.get_token_str(),
+                        next.get_token_str()
+                    ),
+                ));
+            }
+            _ => (),
+        }
+    }
+}
+
+// tagged identifiers that refer to an already named expression
+// the first tag of a name starts that expression instead
+fn tag_references(tokens: &[&Token]) -> HashSet<usize> {
+    let mut names: HashSet<String> = HashSet::new();
+    let mut references: HashSet<usize> = HashSet::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        if token.get_token_type() == TokenType::TaggedIdentifier
+            && !names.insert(token.get_token_str())
+        {
+            references.insert(index);
+        }
+    }
+
+    return references;
+}
+
+fn significant_tokens<'a>(tokens: &'a [Token], ignored: &HashSet<usize>) -> Vec<&'a Token> {
+    return tokens
+        .iter()
+        .enumerate()
+        .filter(|(index, token)| !ignored.contains(index) && !is_skipped(token))
+        .map(|(_index, token)| token)
+        .collect();
+}
+
+fn ends_operand(tokens: &[&Token], references: &HashSet<usize>, index: usize) -> bool {
+    return references.contains(&index)
+        || matches!(tokens.get(index), Some(token) if OPERAND_ENDS.contains(&token.get_token_type()));
+}
+
+fn starts_operand(tokens: &[&Token], references: &HashSet<usize>, index: usize) -> bool {
+    return references.contains(&index)
+        || matches!(tokens.get(index), Some(token) if OPERAND_STARTS.contains(&token.get_token_type()));
+}
+
+fn check_operators(tokens: &[Token], ignored: &HashSet<usize>, errors: &mut Vec<CompileError>) {
+    let significant = significant_tokens(tokens, ignored);
+    let references = tag_references(&significant);
+
+    for (index, token) in significant.iter().enumerate() {
+        let token_type = token.get_token_type();
+
+        let is_binary = BINARY_OPERATORS.contains(&token_type);
+        let is_unary = token_type == TokenType::NotSign;
+
+        if !is_binary && !is_unary {
+            continue;
+        }
+
+        let previous_type = if index > 0 {
+            significant
+                .get(index - 1)
+                .map(|previous| previous.get_token_type())
+        } else {
+            None
+        };
+        let next_type = significant.get(index + 1).map(|next| next.get_token_type());
+
+        // collect seed and collect expression are both optional
+        // i.e. `>- -<` and `>- [] -<`
+        let has_left = (index > 0 && ends_operand(&significant, &references, index - 1))
+            || (token_type == TokenType::SeedCollect && previous_type == Some(TokenType::Collect));
+
+        let has_right = token_type == TokenType::SeedCollect
+            || starts_operand(&significant, &references, index + 1)
+            || (token_type == TokenType::Collect && next_type == Some(TokenType::SeedCollect));
+
+        // minus without a left operand is a negation
+        let needs_left = is_binary && token_type != TokenType::MinusSign;
+
+        if needs_left && !has_left {
+            errors.push(CompileError::new(
+                CompileErrorKind::DanglingOperator,
+                token.get_span(),
+                format!(
+                    "operator '{}' is missing its left operand",
+                    token.get_token_str()
+                ),
+            ));
+        }
+
+        if !has_right {
+            errors.push(CompileError::new(
+                CompileErrorKind::DanglingOperator,
+                token.get_span(),
+                format!(
+                    "operator '{}' is missing its right operand",
+                    token.get_token_str()
+                ),
+            ));
+        }
+    }
+}
+
+// infix calls surround a name with back ticks
+// and need an operand on both sides, i.e. 10 `max` 15
+fn check_infix_calls(tokens: &[Token], ignored: &HashSet<usize>, errors: &mut Vec<CompileError>) {
+    let significant = significant_tokens(tokens, ignored);
+    let references = tag_references(&significant);
+
+    let mut index = 0;
+
+    while let Some(token) = significant.get(index) {
+        if token.get_token_type() != TokenType::BackTick {
+            index += 1;
+            continue;
+        }
+
+        let call = match (significant.get(index + 1), significant.get(index + 2)) {
+            (Some(name), Some(close))
+                if name.get_token_type() == TokenType::Identifier
+                    && close.get_token_type() == TokenType::BackTick =>
+            {
+                Some((name, close))
+            }
+            _ => None,
+        };
+
+        let (name, close) = match call {
+            Some(call) => call,
+            None => {
+                errors.push(CompileError::new(
+                    CompileErrorKind::DanglingOperator,
+                    token.get_span(),
+                    String::from("'`' must surround the name of an infix call"),
+                ));
+                index += 1;
+                continue;
+            }
+        };
+
+        let span = token.get_span().merge(&close.get_span());
+
+        if index == 0 || !ends_operand(&significant, &references, index - 1) {
+            errors.push(CompileError::new(
+                CompileErrorKind::DanglingOperator,
+                span,
+                format!(
+                    "infix call '{}' is missing its left operand",
+                    name.get_token_str()
+                ),
+            ));
+        }
+
+        if !starts_operand(&significant, &references, index + 3) {
+            errors.push(CompileError::new(
+                CompileErrorKind::DanglingOperator,
+                span,
+                format!(
+                    "infix call '{}' is missing its right operand",
+                    name.get_token_str()
+                ),
+            ));
+        }
+
+        index += 3;
+    }
+}
+
+// ':' needs the name of its symbol directly after it, i.e. :name
+fn check_symbols(tokens: &[Token], ignored: &HashSet<usize>, errors: &mut Vec<CompileError>) {
+    for (index, token) in tokens.iter().enumerate() {
+        if ignored.contains(&index) || token.get_token_type() != TokenType::Symbol {
+            continue;
+        }
+
+        if !matches!(tokens.get(index + 1), Some(next) if next.get_token_type() == TokenType::Identifier)
+        {
+            errors.push(CompileError::new(
+                CompileErrorKind::DanglingOperator,
+                token.get_span(),
+                String::from("symbol ':' is missing its name"),
+            ));
+        }
+    }
+}
+
+fn check_unknown(tokens: &[Token], ignored: &HashSet<usize>, errors: &mut Vec<CompileError>) {
+    for (index, token) in tokens.iter().enumerate() {
+        if !ignored.contains(&index) && token.get_token_type() == TokenType::Unknown {
+            errors.push(CompileError::new(
+                CompileErrorKind::UnknownToken,
+                token.get_span(),
+                format!("unrecognized symbol '{}'", token.get_token_str()),
+            ));
+        }
+    }
+}
+
+pub fn validate_tokens(s: &String) -> Vec<CompileError> {
+    let mut errors: Vec<CompileError> = vec![];
+    let mut tokenizer = Tokenizer::new(s);
+
+    // comments and documents have no effect on structure
+    let tokens: Vec<Token> = tokenizer
+        .by_ref()
+        .filter(|token| {
+            token.get_token_type() != TokenType::CommentAnnotation
+                && token.get_token_type() != TokenType::DocumentAnnotation
+        })
+        .collect();
+
+    if let Some(span) = tokenizer.get_unterminated_string() {
+        errors.push(CompileError::new(
+            CompileErrorKind::UnterminatedString,
+            span,
+            String::from("unterminated string literal"),
+        ));
+    }
+
+    let annotation_arguments = check_annotations(&tokens, &mut errors);
+
+    check_unknown(&tokens, &annotation_arguments, &mut errors);
+    check_groups(&tokens, &annotation_arguments, &mut errors);
+    check_numbers(&tokens, &annotation_arguments, &mut errors);
+    check_operators(&tokens, &annotation_arguments, &mut errors);
+    check_infix_calls(&tokens, &annotation_arguments, &mut errors);
+    check_symbols(&tokens, &annotation_arguments, &mut errors);
+
+    // report in source order
+    errors.sort_by_key(|error| error.get_span().get_start().get_offset());
+
+    return errors;
+}
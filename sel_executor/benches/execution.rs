@@ -0,0 +1,135 @@
+// timings of compiled expressions executed repeatedly
+// run with `cargo bench -p sel_executor`
+use sel_common::sel_types::list::List;
+use sel_common::SELValue;
+use sel_compiler::Compiler;
+use sel_executor::{execute_program, execute_sel_tree, lower_sel_tree, SELExecutionContext};
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 2_000;
+// large inputs take milliseconds per run
+const LARGE_ITERATIONS: u32 = 20;
+
+fn int_list(count: i64) -> SELValue {
+    let mut list = List::new();
+
+    for value in 0..count {
+        list.push(SELValue::new_from_int(value));
+    }
+
+    return SELValue::new_from_list(list);
+}
+
+fn time<F>(iterations: u32, run: F) -> Duration
+where
+    F: Fn(),
+{
+    // warm up
+    for _ in 0..(iterations / 10).max(1) {
+        run();
+    }
+
+    let start = Instant::now();
+
+    for _ in 0..iterations {
+        run();
+    }
+
+    return start.elapsed() / iterations;
+}
+
+fn bench(name: &str, s: &str, input: SELValue) {
+    bench_iterations(name, s, input, ITERATIONS);
+}
+
+// times walking the tree and running its lowered program
+fn bench_iterations(name: &str, s: &str, input: SELValue, iterations: u32) {
+    let compiler = Compiler::new();
+    let tree = compiler.compile(&String::from(s));
+    let program = lower_sel_tree(&tree);
+
+    let mut context = SELExecutionContext::from(tree.get_context());
+    context.set_input(input);
+
+    let walked = time(iterations, || {
+        execute_sel_tree(&tree, &context);
+    });
+
+    let run = time(iterations, || {
+        execute_program(&program, &context);
+    });
+
+    println!(
+        "{:<24} {:>12} {:>12}",
+        name,
+        format_duration(walked),
+        format_duration(run)
+    );
+}
+
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    return if nanos >= 1_000_000 {
+        format!("{:.2} ms", nanos as f64 / 1_000_000.0)
+    } else if nanos >= 1_000 {
+        format!("{:.2} us", nanos as f64 / 1_000.0)
+    } else {
+        format!("{} ns", nanos)
+    };
+}
+
+fn main() {
+    println!("{:<24} {:>12} {:>12}", "", "tree", "bytecode");
+
+    bench(
+        "arithmetic",
+        "5.0 * (8 + 2) / (3 + (9 - 4)) * ((4 + 7) * 3)",
+        SELValue::new(),
+    );
+    bench(
+        "logic",
+        "$ > 5 && $ < 10 || $ == 20 || $ % 3 == 0",
+        SELValue::new_from_int(20),
+    );
+    bench(
+        "strings",
+        "'Hello' + ' ' + 'World' + ', ' + 'from' + ' ' + 'SEL'",
+        SELValue::new(),
+    );
+    bench(
+        "list literal",
+        "1, 2, 3, 4, 5, 6, 7, 8, 9, 10",
+        SELValue::new(),
+    );
+    bench(
+        "input access",
+        "$.0 + $.250 + $.500 + $.999",
+        int_list(1000),
+    );
+    bench("interpreted access", "$[10..20] -> $[5]", int_list(1000));
+    bench(
+        "stream collect",
+        "$ >>> $.value * 2 >- 0 -< $.result + $.value",
+        int_list(200),
+    );
+    // copying the source per item shows up as quadratic time here
+    bench_iterations(
+        "stream collect 20k",
+        "$ >>> $.value * 2 >- 0 -< $.result + $.value",
+        int_list(20_000),
+        LARGE_ITERATIONS,
+    );
+    bench_iterations(
+        "collect 20k",
+        "$ >- 0 -< $.result + $.value",
+        int_list(20_000),
+        LARGE_ITERATIONS,
+    );
+    bench_iterations(
+        "index 20k",
+        "$[19999] + $[-1]",
+        int_list(20_000),
+        ITERATIONS,
+    );
+}
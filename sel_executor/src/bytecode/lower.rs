@@ -0,0 +1,363 @@
+use super::{Instruction, Program};
+use crate::opexec::execution_result::SELExecutionResult;
+use crate::opexec::is_binary_operation;
+use crate::opexec::touch::touch_result;
+use sel_common::{DataType, Operation, SELTree, SELValue};
+use std::collections::HashMap;
+
+struct Lowering<'a> {
+    tree: &'a SELTree,
+    code: Vec<Instruction>,
+    // node each instruction was lowered from
+    nodes: Vec<usize>,
+    node: usize,
+    constants: Vec<SELExecutionResult>,
+}
+
+impl<'a> Lowering<'a> {
+    fn emit(&mut self, instruction: Instruction) -> usize {
+        self.code.push(instruction);
+        self.nodes.push(self.node);
+        return self.code.len() - 1;
+    }
+
+    fn emit_constant(&mut self, result: SELExecutionResult) {
+        self.constants.push(result);
+        self.emit(Instruction::Constant(self.constants.len() - 1));
+    }
+
+    fn here(&self) -> usize {
+        return self.code.len();
+    }
+
+    // jumps are emitted before their target is known
+    fn patch(&mut self, at: usize, to: usize) {
+        self.code[at] = match self.code[at] {
+            Instruction::ShortCircuit { node, .. } => {
+                Instruction::ShortCircuit { node, target: to }
+            }
+            Instruction::JumpUnless { invert, .. } => {
+                Instruction::JumpUnless { invert, target: to }
+            }
+            Instruction::Jump(_) => Instruction::Jump(to),
+            instruction => instruction,
+        };
+    }
+
+    fn lower_node(&mut self, index: usize) {
+        let parent = self.node;
+        self.node = index;
+
+        self.lower_operation(index);
+
+        self.node = parent;
+    }
+
+    fn lower_operation(&mut self, index: usize) {
+        let node = match self.tree.get_nodes().get(index) {
+            Some(node) => node,
+            None => {
+                self.emit_constant(SELExecutionResult::new(DataType::Unknown, None));
+                return;
+            }
+        };
+
+        let (left, right) = (node.get_left(), node.get_right());
+
+        match node.get_operation() {
+            Operation::Touch if node.get_data_type() == DataType::Identifier => {
+                self.emit(Instruction::Identifier(index));
+            }
+            Operation::Touch => self.emit_constant(touch_result(self.tree, node)),
+            Operation::Input => {
+                self.emit(Instruction::Input);
+            }
+            Operation::CurrentResult => {
+                self.emit(Instruction::CurrentResult(DataType::Unit));
+            }
+            operation if is_binary_operation(operation) && left.is_some() && right.is_some() => {
+                self.lower_node(left.unwrap());
+                self.lower_node(right.unwrap());
+                self.emit(Instruction::Binary(index));
+            }
+            Operation::Negation | Operation::Not if right.is_some() => {
+                self.lower_node(right.unwrap());
+                self.emit(Instruction::Unary(index));
+            }
+            Operation::LogicalAnd | Operation::LogicalOr if left.is_some() && right.is_some() => {
+                self.lower_node(left.unwrap());
+                let short_circuit = self.emit(Instruction::ShortCircuit {
+                    node: index,
+                    target: 0,
+                });
+                self.lower_node(right.unwrap());
+                self.emit(Instruction::Binary(index));
+
+                let end = self.here();
+                self.patch(short_circuit, end);
+            }
+            Operation::List => {
+                // operands are optional, i.e. (1,)
+                for operand in [left, right].iter().flatten() {
+                    self.lower_node(*operand);
+                }
+                self.emit(Instruction::List(index));
+            }
+            Operation::Pair if left.is_some() && right.is_some() => {
+                self.lower_node(left.unwrap());
+                self.lower_node(right.unwrap());
+                self.emit(Instruction::Pair(index));
+            }
+            Operation::AssociativeList => {
+                if let Some(right) = right {
+                    self.lower_node(right);
+                }
+                self.emit(Instruction::AssociativeList(index));
+            }
+            Operation::DotAccess if left.is_some() => {
+                self.lower_node(left.unwrap());
+                self.emit(Instruction::DotAccess(index));
+            }
+            Operation::InterpretedAccess if left.is_some() && right.is_some() => {
+                self.lower_node(left.unwrap());
+                self.lower_node(right.unwrap());
+                self.emit(Instruction::InterpretedAccess(index));
+            }
+            Operation::MatchTrue | Operation::MatchFalse => self.lower_match(index),
+            Operation::MatchList => self.lower_match_list(index),
+            Operation::Group => self.lower_group(index),
+            Operation::PipeFirstRight | Operation::PipeLastRight => {
+                self.lower_pipe(index, left, right)
+            }
+            Operation::PipeFirstLeft | Operation::PipeLastLeft => {
+                self.lower_pipe(index, right, left)
+            }
+            Operation::InfixCall if left.is_some() && right.is_some() => {
+                self.lower_node(left.unwrap());
+                self.lower_node(right.unwrap());
+                self.emit(Instruction::InfixCall(index));
+            }
+            _ => {
+                self.emit(Instruction::Evaluate(index));
+            }
+        }
+    }
+
+    // arm runs when its left side is true, false for match false
+    // returns the jump to patch with the offset after the arm
+    fn lower_arm(&mut self, index: usize) -> Option<usize> {
+        let node = &self.tree.get_nodes()[index];
+
+        self.lower_node(node.get_left()?);
+
+        return Some(self.emit(Instruction::JumpUnless {
+            invert: node.get_operation() == Operation::MatchFalse,
+            target: 0,
+        }));
+    }
+
+    fn lower_match(&mut self, index: usize) {
+        // without a left side the arm never runs
+        let skip = match self.lower_arm(index) {
+            Some(skip) => skip,
+            None => {
+                self.emit(Instruction::CurrentResult(DataType::Unknown));
+                return;
+            }
+        };
+
+        match self.tree.get_nodes()[index].get_right() {
+            Some(right) => self.lower_node(right),
+            None => self.emit_constant(SELExecutionResult::new(DataType::Unknown, None)),
+        }
+
+        let end_jump = self.emit(Instruction::Jump(0));
+
+        // arm that does not run results in current result
+        let otherwise = self.here();
+        self.emit(Instruction::CurrentResult(DataType::Unknown));
+
+        let end = self.here();
+        self.patch(skip, otherwise);
+        self.patch(end_jump, end);
+    }
+
+    fn lower_match_list(&mut self, index: usize) {
+        let arms = self.match_list_arms(index);
+        let mut end_jumps: Vec<usize> = vec![];
+
+        // arms run top down, first arm that runs gives the result
+        for arm in arms {
+            let skip = match self.lower_arm(arm) {
+                Some(skip) => skip,
+                // without a left side an arm never runs
+                None => continue,
+            };
+
+            // arm without a right side gives no result
+            // so matching continues with the next one
+            if let Some(right) = self.tree.get_nodes()[arm].get_right() {
+                self.lower_node(right);
+                end_jumps.push(self.emit(Instruction::Jump(0)));
+            }
+
+            let next = self.here();
+            self.patch(skip, next);
+        }
+
+        self.emit(Instruction::CurrentResult(DataType::Unit));
+
+        let end = self.here();
+        for jump in end_jumps {
+            self.patch(jump, end);
+        }
+    }
+
+    // arms of a match list are the right sides of the chain of lists
+    // with the last one being the left most node
+    fn match_list_arms(&self, index: usize) -> Vec<usize> {
+        let nodes = self.tree.get_nodes();
+
+        let mut arms: Vec<usize> = vec![];
+        let mut current = index;
+
+        // same fail safe as when walking the tree
+        let mut it_count = 0;
+        while nodes[current].get_operation() == Operation::MatchList {
+            if let Some(right) = nodes[current].get_right() {
+                arms.push(right);
+            }
+
+            if let Some(left) = nodes[current].get_left() {
+                current = left;
+            }
+
+            it_count += 1;
+            if it_count > nodes.len() {
+                break;
+            }
+        }
+
+        arms.push(current);
+        arms.reverse();
+
+        return arms;
+    }
+
+    fn lower_group(&mut self, index: usize) {
+        let node = &self.tree.get_nodes()[index];
+
+        let function = node
+            .get_left()
+            .filter(|left| self.tree.get_nodes()[*left].get_operation() == Operation::Touch);
+
+        match (node.get_left(), function, node.get_right()) {
+            // plain group is its contents
+            (None, _, Some(right)) => self.lower_node(right),
+            (Some(_), Some(function), right) => {
+                match right {
+                    Some(right) => self.lower_node(right),
+                    None => self.emit_constant(SELExecutionResult::from(&SELValue::new())),
+                }
+
+                self.emit(Instruction::GroupCall {
+                    node: index,
+                    function,
+                });
+            }
+            // expressions and partial applications are called by walking the tree
+            _ => {
+                self.emit(Instruction::Evaluate(index));
+            }
+        }
+    }
+
+    fn lower_pipe(&mut self, index: usize, value: Option<usize>, expression: Option<usize>) {
+        let nodes = self.tree.get_nodes();
+
+        // only piping into a function or named expression is lowered
+        let function = expression.filter(|expression| {
+            nodes[*expression].get_operation() != Operation::Expression
+                && nodes[*expression].get_data_type() == DataType::Identifier
+        });
+
+        match (value, function) {
+            (Some(value), Some(function)) => {
+                self.lower_node(value);
+                self.emit(Instruction::PipeCall {
+                    node: index,
+                    function,
+                });
+            }
+            _ => {
+                self.emit(Instruction::Evaluate(index));
+            }
+        }
+    }
+
+    // named expression runs with its argument as input
+    // a block's body is run directly instead of producing the block
+    fn lower_named_expression(&mut self, root: usize) {
+        let node = &self.tree.get_nodes()[root];
+        self.node = root;
+
+        if node.get_operation() == Operation::Expression {
+            match node.get_right() {
+                Some(body) => self.lower_node(body),
+                None => self.emit_constant(SELExecutionResult::from(&SELValue::new())),
+            }
+        } else {
+            self.lower_node(root);
+            self.emit(Instruction::RunExpression);
+        }
+
+        self.emit(Instruction::Return);
+    }
+}
+
+// lowers tree into a flat program for the bytecode machine
+pub fn lower_sel_tree(tree: &SELTree) -> Program<'_> {
+    let mut lowering = Lowering {
+        tree,
+        code: vec![],
+        nodes: vec![],
+        node: 0,
+        constants: vec![],
+    };
+
+    let mut roots: Vec<usize> = vec![];
+
+    if !tree.get_nodes().is_empty() {
+        let mut root_indexes = vec![tree.get_root().get_own_index()];
+        for sub_root_index in 0..tree.get_sub_roots().len() {
+            if let Some(sub_root) = tree.get_sub_root(sub_root_index) {
+                root_indexes.push(sub_root.get_own_index());
+            }
+        }
+
+        for root_index in root_indexes {
+            roots.push(lowering.here());
+            lowering.node = root_index;
+            lowering.lower_node(root_index);
+            lowering.emit(Instruction::Return);
+        }
+    }
+
+    let mut named_expressions: HashMap<usize, usize> = HashMap::new();
+
+    for (symbol_index, named_expression) in tree.get_named_expressions() {
+        if named_expression.get_root() < tree.get_nodes().len() {
+            named_expressions.insert(*symbol_index, lowering.here());
+            lowering.lower_named_expression(named_expression.get_root());
+        }
+    }
+
+    return Program {
+        tree,
+        code: lowering.code,
+        nodes: lowering.nodes,
+        constants: lowering.constants,
+        roots,
+        named_expressions,
+    };
+}
@@ -0,0 +1,352 @@
+use super::{Instruction, Program};
+use crate::context::SELExecutionContext;
+use crate::opexec::call::call_function;
+use crate::opexec::execution_result::SELExecutionResult;
+use crate::opexec::logical::short_circuit;
+use crate::opexec::touch::{identifier_result, touch_result};
+use crate::opexec::utils::{evaluation_stopped, first_error, limit_exceeded, unknown_function};
+use crate::opexec::{
+    access, apply_binary, apply_unary, associative_list, get_node_result, list, pair,
+};
+use sel_common::sel_types::associative_list::AssociativeList;
+use sel_common::sel_types::list::List;
+use sel_common::{DataType, SELTreeNode, SELValue, ValueData};
+
+fn input_result(context: &SELExecutionContext) -> SELExecutionResult {
+    return match context.get_input() {
+        Some(input) => SELExecutionResult::from(input),
+        None => SELExecutionResult::new(DataType::Unit, None),
+    };
+}
+
+fn current_result(context: &SELExecutionContext, fallback: DataType) -> SELExecutionResult {
+    return match context.get_results().last() {
+        Some(result) => result.clone(),
+        None => match context.get_input() {
+            Some(input) => SELExecutionResult::from(input),
+            None => SELExecutionResult::new(fallback, None),
+        },
+    };
+}
+
+// only a boolean true runs a match arm
+fn is_true(result: &SELExecutionResult) -> bool {
+    return matches!(result.get_value(), Some(ValueData::Boolean(true)));
+}
+
+fn pop(stack: &mut Vec<SELExecutionResult>) -> SELExecutionResult {
+    return stack
+        .pop()
+        .unwrap_or(SELExecutionResult::new(DataType::Unknown, None));
+}
+
+fn symbol_of<'a>(program: &Program<'a>, function: usize) -> Option<&'a String> {
+    let tree = program.get_tree();
+
+    return tree
+        .get_nodes()
+        .get(function)
+        .and_then(|function_node| tree.get_usize_value_of(function_node))
+        .and_then(|symbol_index| tree.get_symbol_table().get_symbol(symbol_index));
+}
+
+// result replaced by an error when over the context's size limits
+fn checked(
+    node: &SELTreeNode,
+    result: SELExecutionResult,
+    context: &SELExecutionContext,
+) -> SELExecutionResult {
+    return match context.check_size(result.get_sel_value()) {
+        Ok(()) => result,
+        Err(message) => limit_exceeded(node, message),
+    };
+}
+
+// same lookup order as walking the tree
+// mocks first, then registered functions, then named expressions of the program
+// tagged names, i.e. #name(5), try named expressions before registered functions
+fn call(
+    program: &Program,
+    node: &SELTreeNode,
+    name: &String,
+    tagged: bool,
+    argument: SELValue,
+    context: &SELExecutionContext,
+) -> SELExecutionResult {
+    let tree = program.get_tree();
+
+    if let Err(message) = context.check_call_depth() {
+        return limit_exceeded(node, message);
+    }
+
+    if let Some(result) = context.get_mocked_result(name, &argument) {
+        return SELExecutionResult::from(result);
+    }
+
+    let entry = tree
+        .get_symbol_table()
+        .get_value(name)
+        .and_then(|symbol_index| program.get_named_expression(*symbol_index));
+
+    if let Some(func) = context
+        .get_function(name)
+        .filter(|_| !tagged || entry.is_none())
+    {
+        return call_function(func, node, argument, context, tree.get_symbol_table());
+    }
+
+    return match entry {
+        Some(entry) => {
+            let mut expression_context = context.clone();
+            expression_context.set_input(argument);
+            expression_context.enter_call();
+
+            run(program, entry, &mut expression_context)
+        }
+        None => unknown_function(node, name, &argument),
+    };
+}
+
+// lists with any pairs are called as associative lists
+fn group_argument(result: SELExecutionResult) -> SELValue {
+    return match result.get_value().and_then(ValueData::as_list) {
+        Some(list)
+            if list
+                .get_values()
+                .iter()
+                .any(|value| value.get_type() == DataType::Pair) =>
+        {
+            SELValue::new_from_associative_list(AssociativeList::from(list))
+        }
+        _ => result.get_sel_value().to_owned(),
+    };
+}
+
+fn run_expression(
+    program: &Program,
+    result: SELExecutionResult,
+    context: &mut SELExecutionContext,
+) -> SELExecutionResult {
+    let expression = match result.get_value().and_then(ValueData::as_expression) {
+        Some(expression) => expression,
+        None => return result,
+    };
+
+    let tree = program.get_tree();
+
+    return expression
+        .get_root()
+        .and_then(|root_index| tree.get_nodes().get(root_index))
+        .map(|root| get_node_result(tree, root, context))
+        .unwrap_or(SELExecutionResult::from(&SELValue::new()));
+}
+
+// runs code from entry until its return
+pub fn run(
+    program: &Program,
+    entry: usize,
+    context: &mut SELExecutionContext,
+) -> SELExecutionResult {
+    let tree = program.get_tree();
+    let nodes = tree.get_nodes();
+    let code = program.get_code();
+
+    let mut stack: Vec<SELExecutionResult> = vec![];
+    let mut pc = entry;
+
+    loop {
+        let instruction = code[pc];
+        pc += 1;
+
+        // walking the tree takes its own steps and control flow takes none
+        let counted = !matches!(
+            instruction,
+            Instruction::Evaluate(_)
+                | Instruction::RunExpression
+                | Instruction::ShortCircuit { .. }
+                | Instruction::JumpUnless { .. }
+                | Instruction::Jump(_)
+                | Instruction::Return
+        );
+
+        if counted {
+            if let Err(stopped) = context.take_step() {
+                let node = program
+                    .get_node_of(pc - 1)
+                    .map_or(tree.get_root(), |node| &nodes[node]);
+
+                return evaluation_stopped(node, stopped);
+            }
+        }
+
+        match instruction {
+            Instruction::Constant(index) => stack.push(program.get_constants()[index].clone()),
+            Instruction::Identifier(node) => stack.push(
+                identifier_result(tree, &nodes[node], context)
+                    .unwrap_or_else(|| touch_result(tree, &nodes[node])),
+            ),
+            Instruction::Input => stack.push(input_result(context)),
+            Instruction::CurrentResult(fallback) => stack.push(current_result(context, fallback)),
+            Instruction::Binary(node) => {
+                let right = pop(&mut stack);
+                let left = pop(&mut stack);
+
+                stack.push(checked(
+                    &nodes[node],
+                    apply_binary(&nodes[node], left, right),
+                    context,
+                ));
+            }
+            Instruction::Unary(node) => {
+                let result = pop(&mut stack);
+
+                stack.push(checked(
+                    &nodes[node],
+                    apply_unary(&nodes[node], result),
+                    context,
+                ));
+            }
+            Instruction::List(node) => {
+                let right = nodes[node].get_right().map(|_| pop(&mut stack));
+                let left = nodes[node].get_left().map(|_| pop(&mut stack));
+
+                stack.push(checked(
+                    &nodes[node],
+                    list::apply(tree, &nodes[node], left, right),
+                    context,
+                ));
+            }
+            Instruction::Pair(node) => {
+                let right = pop(&mut stack);
+                let left = pop(&mut stack);
+
+                stack.push(checked(
+                    &nodes[node],
+                    pair::apply(tree, &nodes[node], left, right),
+                    context,
+                ));
+            }
+            Instruction::AssociativeList(node) => {
+                let contents = nodes[node].get_right().map(|_| pop(&mut stack));
+
+                stack.push(checked(
+                    &nodes[node],
+                    associative_list::apply(&nodes[node], contents),
+                    context,
+                ));
+            }
+            Instruction::DotAccess(node) => {
+                let left = pop(&mut stack);
+
+                stack.push(checked(
+                    &nodes[node],
+                    access::apply_dot_access(tree, &nodes[node], left),
+                    context,
+                ));
+            }
+            Instruction::InterpretedAccess(node) => {
+                let key = pop(&mut stack);
+                let left = pop(&mut stack);
+
+                stack.push(checked(
+                    &nodes[node],
+                    access::apply_interpreted_access(&nodes[node], left, key),
+                    context,
+                ));
+            }
+            Instruction::ShortCircuit { node, target } => {
+                let decided = stack
+                    .last()
+                    .and_then(|left| short_circuit(&nodes[node], left));
+
+                if let Some(decided) = decided {
+                    stack.pop();
+                    stack.push(decided);
+                    pc = target;
+                }
+            }
+            Instruction::JumpUnless { invert, target } => {
+                let condition = pop(&mut stack);
+
+                if is_true(&condition) == invert {
+                    pc = target;
+                }
+            }
+            Instruction::Jump(target) => pc = target,
+            Instruction::GroupCall { node, function } => {
+                let argument = group_argument(pop(&mut stack));
+
+                let result = match symbol_of(program, function) {
+                    Some(name) => call(
+                        program,
+                        &nodes[node],
+                        name,
+                        nodes[function].is_tagged(),
+                        argument,
+                        context,
+                    ),
+                    // no symbol found is a unit value
+                    None => SELExecutionResult::from(&SELValue::new()),
+                };
+
+                stack.push(checked(&nodes[node], result, context));
+            }
+            Instruction::PipeCall { node, function } => {
+                let value = pop(&mut stack);
+
+                // errors are not piped into functions
+                let result = if value.is_error() {
+                    value
+                } else {
+                    match symbol_of(program, function) {
+                        Some(name) => call(
+                            program,
+                            &nodes[node],
+                            name,
+                            nodes[function].is_tagged(),
+                            value.get_sel_value().clone(),
+                            context,
+                        ),
+                        None => SELExecutionResult::new(DataType::Unknown, None),
+                    }
+                };
+
+                stack.push(checked(&nodes[node], result, context));
+            }
+            Instruction::InfixCall(node) => {
+                let right = pop(&mut stack);
+                let left = pop(&mut stack);
+
+                let result = match symbol_of(program, node) {
+                    Some(name) => match first_error(&[&left, &right]) {
+                        Some(error) => error,
+                        None => {
+                            let mut arguments = List::new();
+                            arguments.push(left.get_sel_value().clone());
+                            arguments.push(right.get_sel_value().clone());
+
+                            call(
+                                program,
+                                &nodes[node],
+                                name,
+                                false,
+                                SELValue::new_from_list(arguments),
+                                context,
+                            )
+                        }
+                    },
+                    None => SELExecutionResult::new(DataType::Unknown, None),
+                };
+
+                stack.push(checked(&nodes[node], result, context));
+            }
+            Instruction::RunExpression => {
+                let result = pop(&mut stack);
+
+                stack.push(run_expression(program, result, context));
+            }
+            Instruction::Evaluate(node) => stack.push(get_node_result(tree, &nodes[node], context)),
+            Instruction::Return => return pop(&mut stack),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+mod lower;
+mod machine;
+
+use crate::context::SELExecutionContext;
+use crate::opexec::execution_result::SELExecutionResult;
+use sel_common::{DataType, SELTree};
+use std::collections::HashMap;
+
+pub use lower::lower_sel_tree;
+
+// instructions operate on a stack of results
+// values are node indexes into the tree the program was lowered from
+// targets are offsets into the program's code
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Instruction {
+    // push a constant from the program's pool
+    Constant(usize),
+    // push value of identifier node
+    // from the execution context, else compiled into the tree
+    Identifier(usize),
+    Input,
+    // push latest result or input
+    // with a unit value of given type if there is neither
+    CurrentResult(DataType),
+    // pop right and left, apply node's operation
+    Binary(usize),
+    Unary(usize),
+    // pop results of the operands the node has
+    // and collect them the way the node's operation does
+    List(usize),
+    Pair(usize),
+    AssociativeList(usize),
+    // pop value and access it by the name or position on the node's right side
+    DotAccess(usize),
+    // pop key and value
+    InterpretedAccess(usize),
+    // leave the decided result of && or || and jump
+    // if the left side on top of the stack decides it
+    ShortCircuit { node: usize, target: usize },
+    // pop condition, jump if it does not run its arm
+    JumpUnless { invert: bool, target: usize },
+    Jump(usize),
+    // pop argument and call the function named by function node
+    GroupCall { node: usize, function: usize },
+    PipeCall { node: usize, function: usize },
+    InfixCall(usize),
+    // run an expression result on top of the stack
+    // used by named expressions whose value is a block
+    RunExpression,
+    // fall back to walking the tree from node
+    // used by operations that run part of the tree with another input or later on
+    // Stream, Collect, CollectInit, Combine, Transform, Expression, PartialApplication and Symbol,
+    // groups calling something other than a name, i.e. {$ * 2}(3),
+    // pipes into something other than a name, i.e. 3 -> {$ * 2},
+    // and nodes missing an operand, so they report the same errors as walking the tree
+    Evaluate(usize),
+    Return,
+}
+
+pub struct Program<'a> {
+    tree: &'a SELTree,
+    code: Vec<Instruction>,
+    nodes: Vec<usize>,
+    constants: Vec<SELExecutionResult>,
+    roots: Vec<usize>,
+    named_expressions: HashMap<usize, usize>,
+}
+
+impl<'a> Program<'a> {
+    pub fn get_tree(&self) -> &'a SELTree {
+        return self.tree;
+    }
+
+    pub fn get_code(&self) -> &Vec<Instruction> {
+        return &self.code;
+    }
+
+    // node the instruction at offset was lowered from
+    // errors that stop the program, i.e. exceeding the step limit, are reported on it
+    pub fn get_node_of(&self, offset: usize) -> Option<usize> {
+        return self.nodes.get(offset).copied();
+    }
+
+    pub fn get_constants(&self) -> &Vec<SELExecutionResult> {
+        return &self.constants;
+    }
+
+    // entry offsets of root and sub roots in execution order
+    pub fn get_roots(&self) -> &Vec<usize> {
+        return &self.roots;
+    }
+
+    // entry offset of the named expression with given symbol index
+    pub fn get_named_expression(&self, symbol_index: usize) -> Option<usize> {
+        return self.named_expressions.get(&symbol_index).copied();
+    }
+}
+
+// runs every root of the program
+// results match execute_sel_tree for the same tree
+pub fn execute_program(
+    program: &Program,
+    context: &SELExecutionContext,
+) -> Vec<SELExecutionResult> {
+    let mut current_context = context.start_evaluation();
+
+    if program.get_roots().is_empty() {
+        current_context.push_result(SELExecutionResult::new(DataType::Unknown, None));
+    }
+
+    for entry in program.get_roots() {
+        let result = machine::run(program, *entry, &mut current_context);
+
+        current_context.push_result(result);
+    }
+
+    return current_context.get_results().clone();
+}
@@ -0,0 +1,71 @@
+// deep enough for recursive named expressions
+// while stopping runaway recursion before it overflows the stack
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 256;
+
+// bounds on the work of a single evaluation
+// None is unlimited, which is the default for all but the call depth
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Limits {
+    max_steps: Option<usize>,
+    max_call_depth: Option<usize>,
+    max_collection_size: Option<usize>,
+    max_string_length: Option<usize>,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        return Limits {
+            max_steps: None,
+            max_call_depth: Some(DEFAULT_MAX_CALL_DEPTH),
+            max_collection_size: None,
+            max_string_length: None,
+        };
+    }
+}
+
+impl Limits {
+    pub fn new() -> Self {
+        return Limits::default();
+    }
+
+    // evaluated nodes, or instructions when running a program
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = Some(max_steps);
+        return self;
+    }
+
+    // nested calls of functions and named expressions
+    // defaults to DEFAULT_MAX_CALL_DEPTH, hosts may raise it
+    pub fn with_max_call_depth(mut self, max_call_depth: usize) -> Self {
+        self.max_call_depth = Some(max_call_depth);
+        return self;
+    }
+
+    // items of lists and associative lists
+    pub fn with_max_collection_size(mut self, max_collection_size: usize) -> Self {
+        self.max_collection_size = Some(max_collection_size);
+        return self;
+    }
+
+    // characters of strings
+    pub fn with_max_string_length(mut self, max_string_length: usize) -> Self {
+        self.max_string_length = Some(max_string_length);
+        return self;
+    }
+
+    pub fn get_max_steps(&self) -> Option<usize> {
+        return self.max_steps;
+    }
+
+    pub fn get_max_call_depth(&self) -> Option<usize> {
+        return self.max_call_depth;
+    }
+
+    pub fn get_max_collection_size(&self) -> Option<usize> {
+        return self.max_collection_size;
+    }
+
+    pub fn get_max_string_length(&self) -> Option<usize> {
+        return self.max_string_length;
+    }
+}
@@ -1,12 +1,48 @@
+mod limits;
+
 use crate::opexec::execution_result::SELExecutionResult;
-use sel_common::{SELContext, SELFunction, SELValue};
+use sel_common::function::{typed_function, Arguments, Parameter};
+use sel_common::sel_types::error::RuntimeErrorKind;
+use sel_common::{CancellationToken, SELContext, SELFunction, SELValue, SymbolTable, ValueData};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+pub use limits::{Limits, DEFAULT_MAX_CALL_DEPTH};
+
+// cancellation and deadline are checked every this many steps
+const CHECK_INTERVAL: usize = 64;
 
+// result used in place of calling a function or named expression
+#[derive(Clone)]
+struct FunctionMock {
+    name: String,
+    result: SELValue,
+    // None matches any argument
+    input: Option<SELValue>,
+}
+
+// state of a single evaluation
+// a compiled tree is never changed by executing it
+// so one tree can be executed from many threads, each with its own context
 #[derive(Clone)]
 pub struct SELExecutionContext {
     input: Option<SELValue>,
     results: Vec<SELExecutionResult>,
     functions: HashMap<String, SELFunction>,
+    // identifier values for this evaluation
+    // used before values compiled into the tree
+    values: HashMap<String, SELValue>,
+    limits: Limits,
+    // shared by contexts cloned during an evaluation
+    steps: Arc<AtomicUsize>,
+    depth: usize,
+    cancellation: CancellationToken,
+    deadline: Option<Instant>,
+    // deadline relative to the start of each evaluation
+    timeout: Option<Duration>,
+    mocks: Vec<FunctionMock>,
 }
 
 impl SELExecutionContext {
@@ -15,6 +51,14 @@ impl SELExecutionContext {
             input: None,
             results: vec![],
             functions: HashMap::new(),
+            values: HashMap::new(),
+            limits: Limits::new(),
+            steps: Arc::new(AtomicUsize::new(0)),
+            depth: 0,
+            cancellation: CancellationToken::new(),
+            deadline: None,
+            timeout: None,
+            mocks: vec![],
         };
     }
 
@@ -23,6 +67,14 @@ impl SELExecutionContext {
             input: None,
             results: vec![],
             functions: context.get_functions().clone(),
+            values: HashMap::new(),
+            limits: Limits::new(),
+            steps: Arc::new(AtomicUsize::new(0)),
+            depth: 0,
+            cancellation: CancellationToken::new(),
+            deadline: None,
+            timeout: None,
+            mocks: vec![],
         };
     }
 
@@ -45,7 +97,203 @@ impl SELExecutionContext {
         return &self.results;
     }
 
+    // functions can also be given at execution
+    // i.e. for trees loaded from bytes
+    pub fn register_function<F>(&mut self, name: &str, func: F)
+    where
+        F: Fn(SELValue, &SymbolTable) -> SELValue + Send + Sync + 'static,
+    {
+        self.register_shared_function(name, Arc::new(func));
+    }
+
+    pub fn register_shared_function(&mut self, name: &str, func: SELFunction) {
+        self.functions.insert(String::from(name), func);
+    }
+
+    pub fn register_typed_function<F>(&mut self, name: &str, parameters: Vec<Parameter>, func: F)
+    where
+        F: Fn(&Arguments) -> SELValue + Send + Sync + 'static,
+    {
+        self.register_shared_function(name, typed_function(parameters, func));
+    }
+
     pub fn get_function(&self, name: &str) -> Option<&SELFunction> {
         return self.functions.get(name);
     }
+
+    // replaces calls of a function or named expression, i.e. while running tests
+    // a mock with an input only applies when called with an equal argument
+    pub fn mock_function(&mut self, name: &str, result: SELValue, input: Option<SELValue>) {
+        self.mocks.push(FunctionMock {
+            name: String::from(name),
+            result,
+            input,
+        });
+    }
+
+    // latest mock matching the argument
+    // falling back to the latest mock of any argument
+    pub fn get_mocked_result(&self, name: &str, argument: &SELValue) -> Option<&SELValue> {
+        let mocks = || self.mocks.iter().rev().filter(|mock| mock.name == name);
+
+        return mocks()
+            .find(|mock| mock.input.as_ref() == Some(argument))
+            .or_else(|| mocks().find(|mock| mock.input.is_none()))
+            .map(|mock| &mock.result);
+    }
+
+    pub fn set_value(&mut self, name: &str, value: SELValue) {
+        self.values.insert(String::from(name), value);
+    }
+
+    pub fn get_value(&self, name: &str) -> Option<&SELValue> {
+        return self.values.get(name);
+    }
+
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
+    pub fn get_limits(&self) -> Limits {
+        return self.limits;
+    }
+
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.cancellation = token;
+    }
+
+    pub fn get_cancellation_token(&self) -> &CancellationToken {
+        return &self.cancellation;
+    }
+
+    // cancellation token carrying the deadline of the evaluation
+    // host functions observe it with CancellationToken::current
+    pub(crate) fn get_interruption_token(&self) -> CancellationToken {
+        return match self.deadline {
+            Some(deadline) => self.cancellation.clone().with_deadline(deadline),
+            None => self.cancellation.clone(),
+        };
+    }
+
+    pub fn set_deadline(&mut self, deadline: Instant) {
+        self.deadline = Some(deadline);
+    }
+
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
+
+    pub fn get_deadline(&self) -> Option<Instant> {
+        return self.deadline;
+    }
+
+    pub fn get_timeout(&self) -> Option<Duration> {
+        return self.timeout;
+    }
+
+    // context for a new evaluation, steps counted from zero
+    // timeout starts now, ending at the deadline if that is sooner
+    pub(crate) fn start_evaluation(&self) -> Self {
+        let mut context = self.clone();
+        context.steps = Arc::new(AtomicUsize::new(0));
+        context.depth = 0;
+
+        if let Some(timeout) = self.timeout {
+            let ends = Instant::now() + timeout;
+
+            context.deadline = Some(self.deadline.map_or(ends, |deadline| deadline.min(ends)));
+        }
+
+        return context;
+    }
+
+    // once over the limit every following step is as well
+    // so the rest of the evaluation is aborted
+    pub(crate) fn take_step(&self) -> Result<(), (RuntimeErrorKind, String)> {
+        let steps = self.steps.fetch_add(1, Ordering::Relaxed) + 1;
+
+        if let Some(max_steps) = self.limits.get_max_steps() {
+            if steps > max_steps {
+                return Err((
+                    RuntimeErrorKind::LimitExceeded,
+                    format!("exceeded limit of {} steps", max_steps),
+                ));
+            }
+        }
+
+        return self.check_periodically(steps - 1);
+    }
+
+    // first of every interval, so an evaluation cancelled before starting does nothing
+    pub(crate) fn check_periodically(
+        &self,
+        count: usize,
+    ) -> Result<(), (RuntimeErrorKind, String)> {
+        return match count % CHECK_INTERVAL {
+            0 => self.check_interrupted(),
+            _ => Ok(()),
+        };
+    }
+
+    pub(crate) fn check_interrupted(&self) -> Result<(), (RuntimeErrorKind, String)> {
+        let token = self.get_interruption_token();
+
+        if token.is_cancelled() {
+            return Err((
+                RuntimeErrorKind::Cancelled,
+                String::from("evaluation was cancelled"),
+            ));
+        }
+
+        if token.is_timed_out() {
+            return Err((
+                RuntimeErrorKind::TimedOut,
+                String::from("evaluation passed its deadline"),
+            ));
+        }
+
+        return Ok(());
+    }
+
+    pub(crate) fn check_call_depth(&self) -> Result<(), String> {
+        return match self.limits.get_max_call_depth() {
+            Some(max_call_depth) if self.depth >= max_call_depth => {
+                Err(format!("exceeded call depth of {}", max_call_depth))
+            }
+            _ => Ok(()),
+        };
+    }
+
+    // context of a named expression is one call deeper than its caller
+    pub(crate) fn enter_call(&mut self) {
+        self.depth += 1;
+    }
+
+    pub(crate) fn check_collection_size(&self, size: usize) -> Result<(), String> {
+        return match self.limits.get_max_collection_size() {
+            Some(max_size) if size > max_size => Err(format!(
+                "collection of {} items exceeds limit of {}",
+                size, max_size
+            )),
+            _ => Ok(()),
+        };
+    }
+
+    pub(crate) fn check_size(&self, value: &SELValue) -> Result<(), String> {
+        return match value.get_value() {
+            Some(ValueData::List(list)) => self.check_collection_size(list.get_values().len()),
+            Some(ValueData::AssociativeList(list)) => {
+                self.check_collection_size(list.get_list().get_values().len())
+            }
+            Some(ValueData::String(string)) => match self.limits.get_max_string_length() {
+                Some(max_length) if string.chars().count() > max_length => Err(format!(
+                    "string of {} characters exceeds limit of {}",
+                    string.chars().count(),
+                    max_length
+                )),
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        };
+    }
 }
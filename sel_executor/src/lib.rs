@@ -1,15 +1,31 @@
+mod bytecode;
 mod context;
 mod opexec;
+mod test_runner;
 
 #[cfg(test)]
 mod tests;
 
-use crate::opexec::execution_result::SELExecutionResult;
-pub use context::SELExecutionContext;
-use sel_common::{DataType, SELTree};
+pub use crate::bytecode::{execute_program, lower_sel_tree, Instruction, Program};
+use crate::opexec::call::call_named_expression;
+pub use crate::opexec::execution_result::SELExecutionResult;
+use crate::opexec::stream::stream_results;
+pub use context::{Limits, SELExecutionContext, DEFAULT_MAX_CALL_DEPTH};
+pub use test_runner::{run_tests, TestResult};
+use sel_common::{DataType, SELContext, SELTree, SELValue};
+
+// compiled trees and contexts are shared across threads
+const _: fn() = || {
+    fn is_send_sync<T: Send + Sync>() {}
+
+    is_send_sync::<SELTree>();
+    is_send_sync::<SELContext>();
+    is_send_sync::<SELExecutionContext>();
+    is_send_sync::<SELExecutionResult>();
+};
 
 pub fn execute_sel_tree(tree: &SELTree, context: &SELExecutionContext) -> Vec<SELExecutionResult> {
-    let mut current_context = context.clone();
+    let mut current_context = context.start_evaluation();
 
     if tree.get_nodes().len() > 0 {
         let result = opexec::get_node_result(tree, tree.get_root(), &mut current_context);
@@ -32,3 +48,40 @@ pub fn execute_sel_tree(tree: &SELTree, context: &SELExecutionContext) -> Vec<SE
 
     return current_context.get_results().clone();
 }
+
+// executes tree with the last expression's result as a stream
+// stream values are evaluated one at a time as the iterator is advanced
+// non stream results are produced once
+pub fn execute_stream<'a>(
+    tree: &'a SELTree,
+    context: &SELExecutionContext,
+) -> impl Iterator<Item = SELExecutionResult> + 'a {
+    let mut results = execute_sel_tree(tree, context);
+
+    // last result is streamed
+    // earlier ones remain available as current result
+    let last = results
+        .pop()
+        .unwrap_or(SELExecutionResult::new(DataType::Unknown, None));
+
+    let mut stream_context = context.start_evaluation();
+
+    for result in results {
+        stream_context.push_result(result);
+    }
+
+    return stream_results(tree, last.get_sel_value(), &stream_context);
+}
+
+// runs a single named expression from the tree with given input
+// None if the tree has no expression with that name
+pub fn execute_named_expression(
+    tree: &SELTree,
+    name: &str,
+    input: SELValue,
+    context: &SELExecutionContext,
+) -> Option<SELExecutionResult> {
+    let symbol_index = tree.get_symbol_table().get_value(&String::from(name))?;
+
+    return call_named_expression(tree, *symbol_index, input, &context.start_evaluation());
+}
@@ -1,10 +1,15 @@
 use super::SELExecutionContext;
 use crate::opexec::execution_result::SELExecutionResult;
 use crate::opexec::get_node_result;
+use crate::opexec::utils::{runtime_error, type_mismatch};
 use sel_common::sel_types::associative_list::AssociativeList;
+use sel_common::sel_types::error::RuntimeErrorKind;
 use sel_common::sel_types::list::List;
-use sel_common::sel_types::pair::Pair;
-use sel_common::{from_byte_vec, DataType, SELTree, SELTreeNode, SELValue};
+use sel_common::sel_types::range::Range;
+use sel_common::sel_types::symbol::Symbol;
+use sel_common::{
+    from_value_data, to_value_data, DataType, SELTree, SELTreeNode, SELValue, ValueData,
+};
 
 fn get_identifier(node: &SELTreeNode, tree: &SELTree) -> String {
     node.get_right()
@@ -44,6 +49,22 @@ fn get_index(node: &SELTreeNode, tree: &SELTree) -> Option<usize> {
         })
 }
 
+fn index_out_of_range(
+    node: &SELTreeNode,
+    left_result: &SELExecutionResult,
+    index: i64,
+    length: usize,
+) -> SELExecutionResult {
+    let index_result = SELExecutionResult::new(DataType::Integer, Some(to_value_data(index)));
+
+    return runtime_error(
+        RuntimeErrorKind::IndexOutOfRange,
+        node,
+        &[left_result, &index_result],
+        format!("index {} is out of range for length {}", index, length),
+    );
+}
+
 pub fn dot_access_operation(
     tree: &SELTree,
     node: &SELTreeNode,
@@ -57,62 +78,303 @@ pub fn dot_access_operation(
         .map(|node| get_node_result(tree, node, context))
         .unwrap_or(SELExecutionResult::new(DataType::Unknown, None));
 
+    return apply_dot_access(tree, node, left_result);
+}
+
+// right side of a dot access is a name or position, never evaluated
+pub fn apply_dot_access(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    left_result: SELExecutionResult,
+) -> SELExecutionResult {
     return match left_result.get_type() {
         DataType::Pair => {
             // get type and raw value of right
             // should be an identifier
             let identifier: String = get_identifier(node, tree);
-            let pair: Pair = from_byte_vec(left_result.get_value().unwrap());
-            match identifier.as_ref() {
-                "left" => SELExecutionResult::from(pair.get_left()),
-                "right" => SELExecutionResult::from(pair.get_right()),
+            let pair = left_result.get_value().and_then(ValueData::as_pair);
+            match (pair, identifier.as_ref()) {
+                (Some(pair), "left") => SELExecutionResult::from(pair.get_left()),
+                (Some(pair), "right") => SELExecutionResult::from(pair.get_right()),
                 _ => SELExecutionResult::new(DataType::Unit, None),
             }
         }
-        DataType::List => match get_index(node, tree) {
-            Some(index) => {
-                let list: List = from_byte_vec(left_result.get_value().unwrap());
-                SELExecutionResult::from(list.get_values().get(index).unwrap_or(&SELValue::new()))
-            }
-            None => SELExecutionResult::new(DataType::Unit, None),
+        DataType::List => match (
+            get_index(node, tree),
+            left_result.get_value().and_then(ValueData::as_list),
+        ) {
+            (Some(index), Some(list)) => list
+                .get_values()
+                .get(index)
+                .map(SELExecutionResult::from)
+                .unwrap_or_else(|| {
+                    index_out_of_range(node, &left_result, index as i64, list.get_values().len())
+                }),
+            _ => SELExecutionResult::new(DataType::Unit, None),
         },
         DataType::AssociativeList => {
-            let associative_list: AssociativeList = from_byte_vec(left_result.get_value().unwrap());
+            let associative_list = match left_result
+                .get_value()
+                .and_then(ValueData::as_associative_list)
+            {
+                Some(associative_list) => associative_list,
+                None => return SELExecutionResult::new(DataType::Unit, None),
+            };
+
             match get_identifier_symbol(node, tree) {
                 Some(symbol_index) => SELExecutionResult::from(
-                    &associative_list
-                        .get_by_association_index(symbol_index)
+                    &associated_value(associative_list, tree, symbol_index)
                         .unwrap_or(SELValue::new()),
                 ),
                 None => match get_index(node, tree) {
-                    Some(index) => SELExecutionResult::from(
-                        &associative_list
-                            .get_by_index(index)
-                            .unwrap_or(SELValue::new()),
-                    ),
+                    Some(index) => associative_list
+                        .get_by_index(index)
+                        .map(|value| SELExecutionResult::from(&value))
+                        .unwrap_or_else(|| {
+                            index_out_of_range(
+                                node,
+                                &left_result,
+                                index as i64,
+                                associative_list.get_list().get_values().len(),
+                            )
+                        }),
                     None => SELExecutionResult::new(DataType::Unit, None),
                 },
             }
         }
+        DataType::Error => left_result,
         _ => SELExecutionResult::new(DataType::Unit, None),
     };
 }
 
+// values that can be accessed by position
+// borrowed from the accessed value, only the accessed part is copied
+enum IndexedValues<'a> {
+    Values(&'a [SELValue]),
+    Characters(&'a str),
+}
+
+impl<'a> IndexedValues<'a> {
+    fn len(&self) -> usize {
+        return match self {
+            IndexedValues::Values(values) => values.len(),
+            IndexedValues::Characters(s) => s.chars().count(),
+        };
+    }
+
+    fn get(&self, index: i64) -> Option<SELValue> {
+        return match self {
+            IndexedValues::Values(values) => {
+                resolve_index(index, values.len()).map(|index| values[index].clone())
+            }
+            IndexedValues::Characters(s) => {
+                // counting characters is only needed from the end
+                let position = if index < 0 {
+                    resolve_index(index, self.len())
+                } else {
+                    Some(index as usize)
+                };
+
+                position
+                    .and_then(|position| s.chars().nth(position))
+                    .map(|c| SELValue::new_from_string(&c.to_string()))
+            }
+        };
+    }
+}
+
+fn get_indexed_values(result: &SELExecutionResult) -> Option<IndexedValues<'_>> {
+    return match result.get_value() {
+        Some(ValueData::List(list)) => Some(IndexedValues::Values(list.get_values())),
+        Some(ValueData::AssociativeList(list)) => {
+            Some(IndexedValues::Values(list.get_list().get_values()))
+        }
+        Some(ValueData::String(s)) => Some(IndexedValues::Characters(s)),
+        _ => None,
+    };
+}
+
+// negative indices count back from the end
+fn resolve_index(index: i64, length: usize) -> Option<usize> {
+    let resolved = if index < 0 {
+        length as i64 + index
+    } else {
+        index
+    };
+
+    return if resolved >= 0 && (resolved as usize) < length {
+        Some(resolved as usize)
+    } else {
+        None
+    };
+}
+
+// slice bounds clamped to length
+// negative bounds count back from the end
+// an upper bound of 0 after a negative lower bound is the end, i.e. -3...-1
+fn resolve_range(range: &Range, length: usize) -> (usize, usize) {
+    let length = length as i64;
+
+    let lower = if range.get_lower() < 0 {
+        length + range.get_lower()
+    } else {
+        range.get_lower()
+    };
+
+    let upper = if range.get_upper() < 0 || (range.get_upper() == 0 && range.get_lower() < 0) {
+        length + range.get_upper()
+    } else {
+        range.get_upper()
+    };
+
+    let lower = lower.max(0).min(length);
+    let upper = upper.max(lower).min(length);
+
+    return (lower as usize, upper as usize);
+}
+
+fn slice(left_result: &SELExecutionResult, values: Vec<SELValue>) -> SELExecutionResult {
+    return match left_result.get_type() {
+        DataType::AssociativeList => {
+            let mut list = AssociativeList::new();
+
+            for value in values {
+                list.push(value);
+            }
+
+            SELExecutionResult::from(&SELValue::new_from_associative_list(list))
+        }
+        _ => {
+            let mut list = List::new();
+
+            for value in values {
+                list.push(value);
+            }
+
+            SELExecutionResult::from(&SELValue::new_from_list(list))
+        }
+    };
+}
+
+// lists made outside the tree, like input, may use other symbol indexes
+// so keys are matched by identifier as well
+fn associated_value(
+    associative_list: &AssociativeList,
+    tree: &SELTree,
+    symbol_index: usize,
+) -> Option<SELValue> {
+    return match tree.get_symbol_table().get_symbol(symbol_index) {
+        Some(identifier) => {
+            associative_list.get_by_symbol(&Symbol::new(identifier.clone(), symbol_index))
+        }
+        None => associative_list.get_by_association_index(symbol_index),
+    };
+}
+
+// associated value of an associative list
+fn get_associated<F>(left_result: &SELExecutionResult, lookup: F) -> SELExecutionResult
+where
+    F: Fn(&AssociativeList) -> Option<SELValue>,
+{
+    return left_result
+        .get_value()
+        .and_then(ValueData::as_associative_list)
+        .and_then(lookup)
+        .map(|value| SELExecutionResult::from(&value))
+        .unwrap_or(SELExecutionResult::new(DataType::Unit, None));
+}
+
+pub fn interpreted_access_operation(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    context: &mut SELExecutionContext,
+) -> SELExecutionResult {
+    let left_result: SELExecutionResult = node
+        .get_left()
+        .and_then(|index| tree.get_nodes().get(index))
+        .map(|node| get_node_result(tree, node, context))
+        .unwrap_or(SELExecutionResult::new(DataType::Unknown, None));
+
+    // key is any expression
+    // evaluated with the current context
+    let key_result: SELExecutionResult = node
+        .get_right()
+        .and_then(|index| tree.get_nodes().get(index))
+        .map(|node| get_node_result(tree, node, context))
+        .unwrap_or(SELExecutionResult::new(DataType::Unit, None));
+
+    return apply_interpreted_access(node, left_result, key_result);
+}
+
+pub fn apply_interpreted_access(
+    node: &SELTreeNode,
+    left_result: SELExecutionResult,
+    key_result: SELExecutionResult,
+) -> SELExecutionResult {
+    let values = match get_indexed_values(&left_result) {
+        Some(values) if !key_result.is_error() => values,
+        _ => return type_mismatch(node, &[&left_result, &key_result]),
+    };
+
+    return match key_result.get_type() {
+        DataType::Integer => {
+            let index: i64 = from_value_data(key_result.get_value().unwrap());
+
+            match values.get(index) {
+                Some(value) => SELExecutionResult::from(&value),
+                None => index_out_of_range(node, &left_result, index, values.len()),
+            }
+        }
+        DataType::Range => {
+            let range: Range = from_value_data(key_result.get_value().unwrap());
+            let (lower, upper) = resolve_range(&range, values.len());
+
+            match values {
+                IndexedValues::Values(values) => slice(&left_result, values[lower..upper].to_vec()),
+                IndexedValues::Characters(s) => {
+                    SELExecutionResult::from(&SELValue::new_from_string(
+                        &s.chars()
+                            .skip(lower)
+                            .take(upper - lower)
+                            .collect::<String>(),
+                    ))
+                }
+            }
+        }
+        DataType::Symbol => match key_result.get_value().and_then(ValueData::as_symbol) {
+            Some(symbol) => get_associated(&left_result, |list| list.get_by_symbol(symbol)),
+            None => SELExecutionResult::new(DataType::Unit, None),
+        },
+        DataType::String => {
+            // strings are keys by name
+            // so never index into lists
+            let key: String = from_value_data(key_result.get_value().unwrap());
+
+            get_associated(&left_result, |list| list.get_by_identifier(&key))
+        }
+        _ => type_mismatch(node, &[&left_result, &key_result]),
+    };
+}
+
 #[cfg(test)]
 mod tests {
-    use super::super::{get_node_result, SELExecutionContext};
+    use super::super::SELExecutionContext;
+    use crate::opexec::execution_result::SELExecutionResult;
+    use crate::tests::{run, run_root};
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::sel_types::list::List;
     use sel_common::sel_types::symbol::Symbol;
-    use sel_common::{from_byte_vec, DataType};
+    use sel_common::{from_value_data, DataType, Operation, SELValue};
     use sel_compiler::Compiler;
 
     #[test]
     fn executes_pair_left_access() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(:my_value = 100).left"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let symbol: Symbol = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let symbol: Symbol = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Symbol);
         assert_eq!(symbol.get_identifier(), &String::from("my_value"));
@@ -122,10 +384,10 @@ mod tests {
     fn executes_pair_right_access() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(:my_value = 100).right"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 100);
@@ -135,9 +397,9 @@ mod tests {
     fn executes_access_of_non_existent_identifier() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(:my_value = 100).center"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
+        let result = run_root(&tree, &execution_context);
 
         assert_eq!(result.get_type(), DataType::Unit);
     }
@@ -146,9 +408,9 @@ mod tests {
     fn executes_access_of_non_existent_value() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("uninitialized.field"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
+        let result = run_root(&tree, &execution_context);
 
         assert_eq!(result.get_type(), DataType::Unit);
     }
@@ -157,9 +419,9 @@ mod tests {
     fn executes_access_of_non_existent_chain() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("uninitialized.next.field"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
+        let result = run_root(&tree, &execution_context);
 
         assert_eq!(result.get_type(), DataType::Unit);
     }
@@ -168,10 +430,10 @@ mod tests {
     fn executes_chain_access() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(:top = :next = 100).right.right"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 100);
@@ -181,10 +443,10 @@ mod tests {
     fn executes_list_index_access() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(100, 200, 300).1"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 200);
@@ -194,11 +456,18 @@ mod tests {
     fn executes_list_index_access_out_of_bounds() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(100, 200, 300).3"));
-        let mut execution_context = SELExecutionContext::new();
-
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-
-        assert_eq!(result.get_type(), DataType::Unit);
+        let execution_context = SELExecutionContext::new();
+
+        let result = run_root(&tree, &execution_context);
+        let error = result.get_error().unwrap();
+
+        assert_eq!(result.get_type(), DataType::Error);
+        assert_eq!(error.get_kind(), RuntimeErrorKind::IndexOutOfRange);
+        assert_eq!(error.get_operation(), Operation::DotAccess);
+        assert_eq!(
+            error.get_operand_types(),
+            &vec![DataType::List, DataType::Integer]
+        );
     }
 
     #[test]
@@ -207,10 +476,10 @@ mod tests {
         let tree = compiler.compile(&String::from(
             "(:name = (:first = \"Panda\", :last = \"Bear\")).right.1.right",
         ));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: String = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: String = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::String);
         assert_eq!(value, String::from("Bear"));
@@ -220,10 +489,10 @@ mod tests {
     fn executes_associative_list_access_single_field() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("[:name = \"Panda\"].name"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: String = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: String = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::String);
         assert_eq!(value, String::from("Panda"));
@@ -235,10 +504,10 @@ mod tests {
         let tree = compiler.compile(&String::from(
             "[:user = [:first_name = \"Panda\", :last_name = \"Bear\"]].user.last_name",
         ));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: String = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: String = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::String);
         assert_eq!(value, String::from("Bear"));
@@ -248,10 +517,10 @@ mod tests {
     fn executes_associative_list_access_single_index() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("[:name = \"Panda\"].0.right"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: String = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: String = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::String);
         assert_eq!(value, String::from("Panda"));
@@ -263,12 +532,172 @@ mod tests {
         let tree = compiler.compile(&String::from(
             "[[:first_name = \"Panda\", :last_name = \"Bear\"]].0.1.right",
         ));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
+
+        let result = run_root(&tree, &execution_context);
+        let value: String = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(result.get_type(), DataType::String);
+        assert_eq!(value, String::from("Bear"));
+    }
+
+    fn interpreted_result(s: &str) -> SELExecutionResult {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from(s));
+        let execution_context = SELExecutionContext::from(tree.get_context());
+
+        return run_root(&tree, &execution_context);
+    }
+
+    fn int_values(result: &SELExecutionResult) -> Vec<i64> {
+        let list: List = from_value_data(result.get_value().unwrap());
+
+        return list
+            .get_values()
+            .iter()
+            .map(|value| from_value_data(value.get_value().unwrap()))
+            .collect();
+    }
+
+    #[test]
+    fn executes_interpreted_list_index() {
+        let result = interpreted_result("(100, 200, 300)[1]");
+        let value: i64 = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(result.get_type(), DataType::Integer);
+        assert_eq!(value, 200);
+    }
+
+    #[test]
+    fn executes_interpreted_negative_index() {
+        let result = interpreted_result("(100, 200, 300)[-1]");
+        let value: i64 = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(value, 300);
+    }
+
+    #[test]
+    fn executes_interpreted_index_out_of_bounds() {
+        let result = interpreted_result("(100, 200, 300)[-4]");
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::IndexOutOfRange);
+        assert_eq!(error.get_operation(), Operation::InterpretedAccess);
+    }
+
+    #[test]
+    fn executes_interpreted_symbol_key() {
+        let result =
+            interpreted_result("[:first_name = \"Panda\", :last_name = \"Bear\"][:last_name]");
+        let value: String = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(result.get_type(), DataType::String);
+        assert_eq!(value, String::from("Bear"));
+    }
+
+    #[test]
+    fn executes_interpreted_string_key() {
+        let result = interpreted_result("[:first_name = \"Panda\"][\"first_name\"]");
+        let value: String = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(value, String::from("Panda"));
+    }
+
+    #[test]
+    fn executes_interpreted_missing_key() {
+        let result = interpreted_result("[:first_name = \"Panda\"][:age]");
+
+        assert_eq!(result.get_type(), DataType::Unit);
+    }
+
+    #[test]
+    fn executes_interpreted_string_key_on_list() {
+        let result = interpreted_result("(100, 200, 300)[\"1\"]");
+
+        assert_eq!(result.get_type(), DataType::Unit);
+    }
+
+    #[test]
+    fn executes_interpreted_range_slice() {
+        let result = interpreted_result("(100, 200, 300, 400)[1..3]");
+
+        assert_eq!(result.get_type(), DataType::List);
+        assert_eq!(int_values(&result), vec![200, 300]);
+    }
+
+    #[test]
+    fn executes_interpreted_negative_range_slice() {
+        let result = interpreted_result("(100, 200, 300, 400)[-2...-1]");
+
+        assert_eq!(int_values(&result), vec![300, 400]);
+    }
+
+    #[test]
+    fn executes_interpreted_range_slice_past_end() {
+        let result = interpreted_result("(100, 200, 300)[1..10]");
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: String = from_byte_vec(result.get_value().unwrap());
+        assert_eq!(int_values(&result), vec![200, 300]);
+    }
+
+    #[test]
+    fn executes_interpreted_string_index() {
+        let result = interpreted_result("\"Panda\"[-1]");
+        let value: String = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::String);
+        assert_eq!(value, String::from("a"));
+    }
+
+    #[test]
+    fn executes_interpreted_string_index_of_wide_characters() {
+        let result = interpreted_result("\"héllo\"[1]");
+        let value: String = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(value, String::from("é"));
+
+        let error = interpreted_result("\"héllo\"[5]")
+            .get_error()
+            .unwrap()
+            .clone();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::IndexOutOfRange);
+    }
+
+    #[test]
+    fn executes_interpreted_string_slice() {
+        let result = interpreted_result("\"Panda Bear\"[6..10]");
+        let value: String = from_value_data(result.get_value().unwrap());
+
         assert_eq!(value, String::from("Bear"));
     }
+
+    #[test]
+    fn executes_interpreted_dynamic_key() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("1\n$[? + 1]"));
+
+        let mut list = List::new();
+        list.push(SELValue::new_from_int(100));
+        list.push(SELValue::new_from_int(200));
+        list.push(SELValue::new_from_int(300));
+
+        let mut execution_context = SELExecutionContext::new();
+        execution_context.set_input(SELValue::new_from_list(list));
+
+        let results = run(&tree, &execution_context);
+        let result = results.last().unwrap();
+        let value: i64 = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(value, 300);
+    }
+
+    #[test]
+    fn executes_interpreted_access_of_non_collection() {
+        let result = interpreted_result("5[0]");
+
+        assert_eq!(
+            result.get_error().unwrap().get_kind(),
+            RuntimeErrorKind::TypeMismatch
+        );
+    }
 }
@@ -1,9 +1,9 @@
-use super::super::context::SELExecutionContext;
 use super::execution_result::SELExecutionResult;
-use super::utils::{get_values_from_results, match_math_ops, OptionOr};
-use sel_common::{to_byte_vec, DataType, FromByteVec, SELTree, SELTreeNode};
+use super::utils::{get_values_from_results, match_math_ops, type_mismatch, OptionOr};
+use sel_common::sel_types::error::RuntimeErrorKind;
+use sel_common::{to_value_data, DataType, FromValueData, SELTreeNode};
 
-fn concat_results<L: FromByteVec + ToString, R: FromByteVec + ToString>(
+fn concat_results<L: FromValueData + ToString, R: FromValueData + ToString>(
     left: &SELExecutionResult,
     right: &SELExecutionResult,
 ) -> SELExecutionResult {
@@ -11,20 +11,20 @@ fn concat_results<L: FromByteVec + ToString, R: FromByteVec + ToString>(
 
     let result = left_val.to_string() + &right_val.to_string();
 
-    SELExecutionResult::new(DataType::String, Some(to_byte_vec(&result)))
+    SELExecutionResult::new(DataType::String, Some(to_value_data(&result)))
 }
 
-pub fn operation(
-    tree: &SELTree,
+pub fn apply(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
     return match match_math_ops(
-        tree,
         node,
-        context,
-        |left, right| left + right,
-        |left, right| left + right,
+        left_result,
+        right_result,
+        |left, right| left.checked_add(right).ok_or(RuntimeErrorKind::Overflow),
+        |left, right| Ok(left + right),
     ) {
         OptionOr::Some(result) => result,
         OptionOr::Or((left_result, right_result)) => {
@@ -33,10 +33,10 @@ pub fn operation(
                     concat_results::<String, String>(&left_result, &right_result)
                 }
                 (DataType::String, DataType::Integer) => {
-                    concat_results::<String, i32>(&left_result, &right_result)
+                    concat_results::<String, i64>(&left_result, &right_result)
                 }
                 (DataType::Integer, DataType::String) => {
-                    concat_results::<i32, String>(&left_result, &right_result)
+                    concat_results::<i64, String>(&left_result, &right_result)
                 }
                 (DataType::String, DataType::Decimal) => {
                     concat_results::<String, f64>(&left_result, &right_result)
@@ -50,7 +50,7 @@ pub fn operation(
                 (DataType::Boolean, DataType::String) => {
                     concat_results::<bool, String>(&left_result, &right_result)
                 }
-                _ => SELExecutionResult::new(DataType::Unknown, Some(vec![])),
+                _ => type_mismatch(node, &[&left_result, &right_result]),
             }
         }
     };
@@ -59,7 +59,8 @@ pub fn operation(
 #[cfg(test)]
 mod tests {
     use super::super::test_utils::result_of_binary_op;
-    use sel_common::{from_byte_vec, DataType, Operation};
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::{from_value_data, DataType, Operation};
 
     #[test]
     fn executes_integer_addition() {
@@ -72,7 +73,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -91,7 +92,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -110,7 +111,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -129,7 +130,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -148,7 +149,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -167,7 +168,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -186,7 +187,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -205,7 +206,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -224,7 +225,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -243,7 +244,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -262,7 +263,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -297,4 +298,40 @@ mod tests {
         assert_eq!(result.get_type(), DataType::Unit);
         assert_eq!(result.get_value(), None);
     }
+
+    #[test]
+    fn executes_integer_addition_overflow() {
+        let result = result_of_binary_op(
+            Operation::Addition,
+            DataType::Integer,
+            "9223372036854775807",
+            DataType::Integer,
+            "1",
+        );
+
+        let error = result.get_error().unwrap();
+
+        assert_eq!(result.get_type(), DataType::Error);
+        assert_eq!(error.get_kind(), RuntimeErrorKind::Overflow);
+        assert_eq!(error.get_operation(), Operation::Addition);
+    }
+
+    #[test]
+    fn executes_addition_type_mismatch() {
+        let result = result_of_binary_op(
+            Operation::Addition,
+            DataType::Integer,
+            "10",
+            DataType::Boolean,
+            "true",
+        );
+
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::TypeMismatch);
+        assert_eq!(
+            error.get_operand_types(),
+            &vec![DataType::Integer, DataType::Boolean]
+        );
+    }
 }
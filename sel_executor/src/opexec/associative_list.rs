@@ -1,17 +1,32 @@
 use super::execution_result::SELExecutionResult;
 use super::{get_node_result, SELExecutionContext};
 use sel_common::sel_types::associative_list::AssociativeList;
-use sel_common::sel_types::list::List;
-use sel_common::{from_byte_vec, to_byte_vec, DataType, SELTree, SELTreeNode, SELValue};
+use sel_common::{to_value_data, DataType, SELTree, SELTreeNode, SELValue};
 
 pub fn operation(
     tree: &SELTree,
     node: &SELTreeNode,
     context: &mut SELExecutionContext,
 ) -> SELExecutionResult {
-    node.get_right()
+    let right_result = node
+        .get_right()
         .and_then(|right_index| tree.get_nodes().get(right_index))
-        .map(|right_node| get_node_result(tree, right_node, context))
+        .map(|right_node| get_node_result(tree, right_node, context));
+
+    return apply(node, right_result);
+}
+
+// result of the bracket's contents, None for empty brackets
+pub fn apply(node: &SELTreeNode, right_result: Option<SELExecutionResult>) -> SELExecutionResult {
+    // empty brackets, []
+    if node.get_right().is_none() {
+        return SELExecutionResult::new(
+            DataType::AssociativeList,
+            Some(to_value_data(AssociativeList::new())),
+        );
+    }
+
+    return right_result
         .and_then(|result: SELExecutionResult| {
             result
                 .get_value()
@@ -28,42 +43,54 @@ pub fn operation(
 
                         Some(SELExecutionResult::new(
                             DataType::AssociativeList,
-                            Some(to_byte_vec(a_list)),
+                            Some(to_value_data(a_list)),
                         ))
                     }
                     DataType::List => {
-                        let list: List = from_byte_vec(value);
-                        let a_list = AssociativeList::from(list);
+                        let a_list = AssociativeList::from(value.as_list()?);
 
                         Some(SELExecutionResult::new(
                             DataType::AssociativeList,
-                            Some(to_byte_vec(a_list)),
+                            Some(to_value_data(a_list)),
                         ))
                     }
                     _ => Some(SELExecutionResult::new(DataType::Unknown, None)),
                 })
         })
-        .unwrap_or(SELExecutionResult::new(DataType::Unknown, None))
+        .unwrap_or(SELExecutionResult::new(DataType::Unknown, None));
 }
 
 #[cfg(test)]
 mod tests {
-    use sel_common::{from_byte_vec, DataType, SELContext, SELValue};
+    use crate::tests::run_root;
+    use sel_common::{from_value_data, DataType, SELContext, SELValue};
     use sel_compiler::Compiler;
 
-    use super::super::super::execute_sel_tree;
     use super::*;
     use sel_common::sel_types::pair::Pair;
     use sel_common::sel_types::symbol::Symbol;
 
+    #[test]
+    fn executes_empty_associative_list() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("[]"));
+        let execution_context = SELExecutionContext::new();
+
+        let result = run_root(&tree, &execution_context);
+        let list: AssociativeList = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(result.get_type(), DataType::AssociativeList);
+        assert_eq!(list.get_list().get_values().len(), 0);
+    }
+
     #[test]
     fn executes_associative_list_from_single_integer_value() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("[100]"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let list: AssociativeList = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let list: AssociativeList = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::AssociativeList);
 
@@ -74,17 +101,20 @@ mod tests {
         let first_value: SELValue = values.get(0).unwrap().to_owned();
 
         assert_eq!(first_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(first_value.get_value().unwrap()), 100);
+        assert_eq!(
+            from_value_data::<i64>(first_value.get_value().unwrap()),
+            100
+        );
     }
 
     #[test]
     fn executes_associative_list_from_single_symbol_integer_pair_value() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("[:max = 100]"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let list: AssociativeList = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let list: AssociativeList = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::AssociativeList);
 
@@ -98,9 +128,9 @@ mod tests {
 
         assert_eq!(first_value.get_type(), DataType::Pair);
 
-        let pair: Pair = from_byte_vec(first_value.get_value().unwrap());
-        let symbol: Symbol = from_byte_vec(pair.get_left().get_value().unwrap());
-        let pair_value: i64 = from_byte_vec(pair.get_right().get_value().unwrap());
+        let pair: Pair = from_value_data(first_value.get_value().unwrap());
+        let symbol: Symbol = from_value_data(pair.get_left().get_value().unwrap());
+        let pair_value: i64 = from_value_data(pair.get_right().get_value().unwrap());
 
         assert_eq!(symbol.get_table_index(), 0);
         assert_eq!(pair_value, 100);
@@ -110,7 +140,7 @@ mod tests {
             .unwrap()
             .to_owned();
 
-        let value: i64 = from_byte_vec(associated_value.get_value().unwrap());
+        let value: i64 = from_value_data(associated_value.get_value().unwrap());
 
         assert_eq!(value, 100);
     }
@@ -120,10 +150,10 @@ mod tests {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("[[:max = 100]]"));
         println!("{:?}", tree);
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let list: AssociativeList = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let list: AssociativeList = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::AssociativeList);
 
@@ -135,18 +165,18 @@ mod tests {
 
         assert_eq!(first_value.get_type(), DataType::AssociativeList);
 
-        let nested_list: AssociativeList = from_byte_vec(first_value.get_value().unwrap());
+        let nested_list: AssociativeList = from_value_data(first_value.get_value().unwrap());
 
         let pair_value: SELValue = nested_list.get_by_index(0).unwrap().to_owned();
-        let pair: Pair = from_byte_vec(pair_value.get_value().unwrap());
+        let pair: Pair = from_value_data(pair_value.get_value().unwrap());
 
         let symbol_value: &SELValue = pair.get_left();
-        let symbol: Symbol = from_byte_vec(symbol_value.get_value().unwrap());
+        let symbol: Symbol = from_value_data(symbol_value.get_value().unwrap());
 
         assert_eq!(symbol.get_identifier(), &String::from("max"));
 
         let integer_value: &SELValue = pair.get_right();
-        let integer: i64 = from_byte_vec(integer_value.get_value().unwrap());
+        let integer: i64 = from_value_data(integer_value.get_value().unwrap());
 
         assert_eq!(integer, 100);
 
@@ -154,7 +184,7 @@ mod tests {
             .get_by_association_index(symbol.get_table_index())
             .unwrap()
             .to_owned();
-        let associated_integer: i64 = from_byte_vec(associated_value.get_value().unwrap());
+        let associated_integer: i64 = from_value_data(associated_value.get_value().unwrap());
 
         assert_eq!(associated_integer, 100);
     }
@@ -163,10 +193,10 @@ mod tests {
     fn executes_associative_list_from_list() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("[100, true]"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let list: AssociativeList = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let list: AssociativeList = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::AssociativeList);
 
@@ -178,11 +208,14 @@ mod tests {
         let second_value: SELValue = values.get(1).unwrap().to_owned();
 
         assert_eq!(first_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(first_value.get_value().unwrap()), 100);
+        assert_eq!(
+            from_value_data::<i64>(first_value.get_value().unwrap()),
+            100
+        );
 
         assert_eq!(second_value.get_type(), DataType::Boolean);
         assert_eq!(
-            from_byte_vec::<bool>(second_value.get_value().unwrap()),
+            from_value_data::<bool>(second_value.get_value().unwrap()),
             true
         );
     }
@@ -193,10 +226,10 @@ mod tests {
         let tree = compiler.compile(&String::from(
             "[:first_name = \"Panda\", :last_name = \"Bear\"]",
         ));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let list: AssociativeList = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let list: AssociativeList = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::AssociativeList);
 
@@ -221,9 +254,9 @@ mod tests {
     ) {
         assert_eq!(value.get_type(), DataType::Pair);
 
-        let pair: Pair = from_byte_vec(value.get_value().unwrap());
-        let symbol: Symbol = from_byte_vec(pair.get_left().get_value().unwrap());
-        let p_value: String = from_byte_vec(pair.get_right().get_value().unwrap());
+        let pair: Pair = from_value_data(value.get_value().unwrap());
+        let symbol: Symbol = from_value_data(pair.get_left().get_value().unwrap());
+        let p_value: String = from_value_data(pair.get_right().get_value().unwrap());
 
         assert_eq!(symbol.get_table_index(), symbol_index);
         assert_eq!(p_value, pair_value);
@@ -233,7 +266,7 @@ mod tests {
             .unwrap()
             .to_owned();
 
-        let associated_value: String = from_byte_vec(associated_value.get_value().unwrap());
+        let associated_value: String = from_value_data(associated_value.get_value().unwrap());
 
         assert_eq!(associated_value, pair_value);
     }
@@ -1,85 +1,108 @@
-use super::{SELExecutionContext, SELExecutionResult};
-use crate::opexec::utils::{get_left_right_results, get_values_from_results};
-use sel_common::{to_byte_vec, DataType, SELTree, SELTreeNode};
+use super::SELExecutionResult;
+use crate::opexec::utils::{arithmetic_error, get_values_from_results, type_mismatch};
+use sel_common::sel_types::error::RuntimeErrorKind;
+use sel_common::{to_value_data, DataType, SELTreeNode};
+use std::convert::TryFrom;
 
 fn match_bitwise_op<F>(
-    tree: &SELTree,
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
     f: F,
 ) -> SELExecutionResult
 where
-    F: Fn(i64, i64) -> i64,
+    F: Fn(i64, i64) -> Option<i64>,
 {
-    let (left_result, right_result) = get_left_right_results(tree, node, context);
-
     return match (left_result.get_type(), right_result.get_type()) {
         (DataType::Integer, DataType::Integer) => {
             let (left_value, right_value) =
                 get_values_from_results::<i64, i64>(&left_result, &right_result);
 
-            let value = f(left_value, right_value);
-
-            return SELExecutionResult::new(DataType::Integer, Some(to_byte_vec(value)));
+            return match f(left_value, right_value) {
+                Some(value) => {
+                    SELExecutionResult::new(DataType::Integer, Some(to_value_data(value)))
+                }
+                None => arithmetic_error(
+                    RuntimeErrorKind::Overflow,
+                    node,
+                    &[&left_result, &right_result],
+                ),
+            };
         }
-        _ => SELExecutionResult::new(DataType::Unknown, None),
+        _ => type_mismatch(node, &[&left_result, &right_result]),
     };
 }
 
-pub fn or_operation(
-    tree: &SELTree,
+pub fn apply_or(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
-    return match_bitwise_op(tree, node, context, |left, right| left | right);
+    return match_bitwise_op(node, left_result, right_result, |left, right| {
+        Some(left | right)
+    });
 }
 
-pub fn and_operation(
-    tree: &SELTree,
+pub fn apply_and(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
-    return match_bitwise_op(tree, node, context, |left, right| left & right);
+    return match_bitwise_op(node, left_result, right_result, |left, right| {
+        Some(left & right)
+    });
 }
 
-pub fn xor_operation(
-    tree: &SELTree,
+pub fn apply_xor(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
-    return match_bitwise_op(tree, node, context, |left, right| left ^ right);
+    return match_bitwise_op(node, left_result, right_result, |left, right| {
+        Some(left ^ right)
+    });
 }
 
-pub fn left_shift_operation(
-    tree: &SELTree,
+pub fn apply_left_shift(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
-    return match_bitwise_op(tree, node, context, |left, right| left << right);
+    return match_bitwise_op(node, left_result, right_result, |left, right| {
+        u32::try_from(right)
+            .ok()
+            .and_then(|shift| left.checked_shl(shift))
+    });
 }
 
-pub fn right_shift_operation(
-    tree: &SELTree,
+pub fn apply_right_shift(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
-    return match_bitwise_op(tree, node, context, |left, right| left >> right);
+    return match_bitwise_op(node, left_result, right_result, |left, right| {
+        u32::try_from(right)
+            .ok()
+            .and_then(|shift| left.checked_shr(shift))
+    });
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::{get_node_result, SELExecutionContext};
-    use sel_common::{from_byte_vec, DataType};
+    use super::super::SELExecutionContext;
+    use crate::tests::run_root;
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::{from_value_data, DataType, Operation};
     use sel_compiler::Compiler;
 
     #[test]
     fn executes_bitwise_or() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("250 | 10928"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 250 | 10928);
@@ -89,10 +112,10 @@ mod tests {
     fn executes_bitwise_and() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("250 & 10928"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 250 & 10928);
@@ -102,10 +125,10 @@ mod tests {
     fn executes_bitwise_xor() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("250 ^ 10928"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 250 ^ 10928);
@@ -115,10 +138,10 @@ mod tests {
     fn executes_bitwise_left_shift() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("250 << 2"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 250 << 2);
@@ -128,12 +151,41 @@ mod tests {
     fn executes_bitwise_right_shift() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("250 >> 2"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 250 >> 2);
     }
+
+    #[test]
+    fn executes_left_shift_overflow() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("1 << 64"));
+        let execution_context = SELExecutionContext::new();
+
+        let result = run_root(&tree, &execution_context);
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::Overflow);
+        assert_eq!(error.get_operation(), Operation::BitwiseLeftShift);
+    }
+
+    #[test]
+    fn executes_bitwise_type_mismatch() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("1 | 3.14"));
+        let execution_context = SELExecutionContext::new();
+
+        let result = run_root(&tree, &execution_context);
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::TypeMismatch);
+        assert_eq!(
+            error.get_operand_types(),
+            &vec![DataType::Integer, DataType::Decimal]
+        );
+    }
 }
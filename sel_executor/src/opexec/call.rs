@@ -0,0 +1,142 @@
+use super::utils::{limit_exceeded, unknown_function};
+use super::{get_node_result, SELExecutionContext, SELExecutionResult};
+use sel_common::sel_types::error::SELRuntimeError;
+use sel_common::{Operation, SELFunction, SELTree, SELTreeNode, SELValue, SymbolTable, ValueData};
+
+// registered functions do not know the node calling them
+// so their errors without an operation are given the caller's
+// the context's cancellation token, with its deadline, is current while they run
+pub fn call_function(
+    func: &SELFunction,
+    node: &SELTreeNode,
+    argument: SELValue,
+    context: &SELExecutionContext,
+    symbol_table: &SymbolTable,
+) -> SELExecutionResult {
+    let result = context
+        .get_interruption_token()
+        .scope(|| func(argument, symbol_table));
+
+    return match result.get_error() {
+        Some(error) if error.get_operation() == Operation::None => {
+            SELExecutionResult::error(SELRuntimeError::new(
+                error.get_kind(),
+                node.get_operation(),
+                error.get_operand_types().clone(),
+                error.get_message().clone(),
+            ))
+        }
+        _ => SELExecutionResult::from(&result),
+    };
+}
+
+// runs named expression with argument as its input
+// None if there is no expression with given symbol
+pub fn call_named_expression(
+    tree: &SELTree,
+    symbol_index: usize,
+    argument: SELValue,
+    context: &SELExecutionContext,
+) -> Option<SELExecutionResult> {
+    let expression_root = tree
+        .get_named_expressions()
+        .get(&symbol_index)
+        .and_then(|named_expression| tree.get_nodes().get(named_expression.get_root()))?;
+
+    let mut expression_context = context.clone();
+    expression_context.set_input(argument);
+    expression_context.enter_call();
+
+    let result = get_node_result(tree, expression_root, &mut expression_context);
+
+    // named block, i.e. #is_even { $ % 2 == 0 }
+    // result is the expression itself, so run it
+    let expression = match result.get_value().and_then(ValueData::as_expression) {
+        Some(expression) => expression,
+        None => return Some(result),
+    };
+
+    return Some(
+        expression
+            .get_root()
+            .and_then(|root_index| tree.get_nodes().get(root_index))
+            .map(|root| get_node_result(tree, root, &mut expression_context))
+            .unwrap_or(SELExecutionResult::from(&SELValue::new())),
+    );
+}
+
+// calls registered function with given name
+// falling back to a named expression in the tree
+// mocks of the name are used instead of either
+// named expressions receive the argument as input
+pub fn call_by_name(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    name: &String,
+    argument: SELValue,
+    context: &SELExecutionContext,
+) -> SELExecutionResult {
+    return call_with_lookup(tree, node, name, false, argument, context);
+}
+
+// calls the function or named expression an identifier node names
+// tagged identifiers, i.e. #name(5), prefer the named expression over a registered function
+// None if the node has no name
+pub fn call_identifier(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    identifier_node: &SELTreeNode,
+    argument: SELValue,
+    context: &SELExecutionContext,
+) -> Option<SELExecutionResult> {
+    return tree
+        .get_usize_value_of(identifier_node)
+        .and_then(|symbol_index| tree.get_symbol_table().get_symbol(symbol_index))
+        .map(|name| {
+            call_with_lookup(
+                tree,
+                node,
+                name,
+                identifier_node.is_tagged(),
+                argument,
+                context,
+            )
+        });
+}
+
+fn call_with_lookup(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    name: &String,
+    tagged: bool,
+    argument: SELValue,
+    context: &SELExecutionContext,
+) -> SELExecutionResult {
+    if let Err(message) = context.check_call_depth() {
+        return limit_exceeded(node, message);
+    }
+
+    if let Some(result) = context.get_mocked_result(name, &argument) {
+        return SELExecutionResult::from(result);
+    }
+
+    let symbol_index = tree.get_symbol_table().get_value(name);
+
+    if tagged {
+        if let Some(result) = symbol_index.and_then(|symbol_index| {
+            call_named_expression(tree, *symbol_index, argument.clone(), context)
+        }) {
+            return result;
+        }
+    }
+
+    if let Some(func) = context.get_function(name) {
+        return call_function(func, node, argument, context, tree.get_symbol_table());
+    }
+
+    return match symbol_index {
+        Some(symbol_index) => call_named_expression(tree, *symbol_index, argument.clone(), context)
+            .unwrap_or_else(|| unknown_function(node, name, &argument)),
+        None => unknown_function(node, name, &argument),
+    };
+}
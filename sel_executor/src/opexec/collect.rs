@@ -0,0 +1,341 @@
+use super::stream::stream_source;
+use super::utils::{evaluation_stopped, limit_exceeded, make_named_list, type_mismatch};
+use super::{get_node_result, SELExecutionContext, SELExecutionResult};
+use sel_common::sel_types::associative_list::AssociativeList;
+use sel_common::sel_types::list::List;
+use sel_common::sel_types::stream::SELStream;
+use sel_common::{from_value_data, DataType, Operation, SELTree, SELTreeNode, SELValue};
+
+fn is_collectable(data_type: DataType) -> bool {
+    return matches!(
+        data_type,
+        DataType::Stream | DataType::List | DataType::AssociativeList | DataType::Range
+    );
+}
+
+// value given to the stream at the very start
+// used when collecting without a seed
+fn innermost_source(value: &SELValue) -> SELValue {
+    return match value.get_type() {
+        DataType::Stream => {
+            let stream: SELStream = from_value_data(value.get_value().unwrap());
+
+            innermost_source(stream.get_source())
+        }
+        _ => value.clone(),
+    };
+}
+
+// inserts values into a seed list
+// pairs with symbol keys are inserted by key into associative lists
+fn insert_into_seed(seed: SELValue, values: Vec<SELValue>) -> SELValue {
+    return match seed.get_type() {
+        DataType::List => {
+            let mut list: List = from_value_data(seed.get_value().unwrap());
+
+            for value in values {
+                list.push(value);
+            }
+
+            SELValue::new_from_list(list)
+        }
+        DataType::AssociativeList => {
+            let mut list: AssociativeList = from_value_data(seed.get_value().unwrap());
+
+            for value in values {
+                list.push(value);
+            }
+
+            SELValue::new_from_associative_list(list)
+        }
+        _ => seed,
+    };
+}
+
+fn collect(
+    tree: &SELTree,
+    collect_node: &SELTreeNode,
+    expression_index: Option<usize>,
+    context: &mut SELExecutionContext,
+) -> SELExecutionResult {
+    let source_result = match collect_node
+        .get_left()
+        .and_then(|left_index| tree.get_nodes().get(left_index))
+    {
+        Some(left_node) => get_node_result(tree, left_node, context),
+        None => return SELExecutionResult::new(DataType::Unknown, None),
+    };
+
+    if source_result.is_error() || !is_collectable(source_result.get_type()) {
+        return type_mismatch(collect_node, &[&source_result]);
+    }
+
+    let seed_node = collect_node
+        .get_right()
+        .and_then(|right_index| tree.get_nodes().get(right_index));
+
+    let mut values = stream_source(tree, source_result.get_sel_value(), context);
+
+    // using ? as the seed takes the first value
+    let seed = match seed_node {
+        Some(seed_node) if seed_node.get_operation() == Operation::CurrentResult => {
+            match values.next() {
+                Some(first) => first,
+                None => return SELExecutionResult::new(DataType::Unit, None),
+            }
+        }
+        Some(seed_node) => get_node_result(tree, seed_node, context)
+            .get_sel_value()
+            .clone(),
+        None => SELValue::new(),
+    };
+
+    if seed.is_error() {
+        return SELExecutionResult::from(&seed);
+    }
+
+    let expression_node = expression_index.and_then(|index| tree.get_nodes().get(index));
+
+    return match expression_node {
+        Some(expression_node) => {
+            let mut result = seed;
+
+            for (index, value) in values.enumerate() {
+                if value.is_error() {
+                    return SELExecutionResult::from(&value);
+                }
+
+                if let Err(stopped) = context.check_periodically(index) {
+                    return evaluation_stopped(collect_node, stopped);
+                }
+
+                let mut expression_context = context.clone();
+                expression_context.set_input(make_named_list(
+                    tree,
+                    vec![("result", result), ("value", value)],
+                ));
+
+                let expression_result =
+                    get_node_result(tree, expression_node, &mut expression_context);
+
+                if expression_result.is_error() {
+                    return expression_result;
+                }
+
+                result = expression_result.get_sel_value().clone();
+            }
+
+            SELExecutionResult::from(&result)
+        }
+        None => {
+            let mut collected: Vec<SELValue> = vec![];
+
+            for value in values {
+                if value.is_error() {
+                    return SELExecutionResult::from(&value);
+                }
+
+                if let Err(stopped) = context.check_periodically(collected.len()) {
+                    return evaluation_stopped(collect_node, stopped);
+                }
+
+                // checked while collecting
+                // so a large range is not collected in full first
+                if let Err(message) = context.check_collection_size(collected.len() + 1) {
+                    return limit_exceeded(collect_node, message);
+                }
+
+                collected.push(value);
+            }
+
+            // no seed passes the stream's input along
+            // stream is still run to completion
+            if seed.get_type() == DataType::Unit {
+                SELExecutionResult::from(&innermost_source(source_result.get_sel_value()))
+            } else {
+                SELExecutionResult::from(&insert_into_seed(seed, collected))
+            }
+        }
+    };
+}
+
+pub fn collect_operation(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    context: &mut SELExecutionContext,
+) -> SELExecutionResult {
+    return collect(tree, node, None, context);
+}
+
+pub fn collect_init_operation(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    context: &mut SELExecutionContext,
+) -> SELExecutionResult {
+    // left side is the collect operation with source and seed
+    // right side is the expression run for each value
+    return match node
+        .get_left()
+        .and_then(|left_index| tree.get_nodes().get(left_index))
+    {
+        Some(left_node) if left_node.get_operation() == Operation::Collect => {
+            collect(tree, left_node, node.get_right(), context)
+        }
+        Some(left_node) => {
+            let left_result = get_node_result(tree, left_node, context);
+
+            type_mismatch(node, &[&left_result])
+        }
+        None => SELExecutionResult::new(DataType::Unknown, None),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::opexec::execution_result::SELExecutionResult;
+    use crate::tests::run;
+    use crate::SELExecutionContext;
+    use sel_common::sel_types::associative_list::AssociativeList;
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::sel_types::list::List;
+    use sel_common::{from_value_data, DataType, SELValue};
+    use sel_compiler::Compiler;
+
+    fn int_list(values: Vec<i64>) -> SELValue {
+        let mut list = List::new();
+
+        for value in values {
+            list.push(SELValue::new_from_int(value));
+        }
+
+        return SELValue::new_from_list(list);
+    }
+
+    fn result_of(s: &str, input: SELValue) -> SELExecutionResult {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from(s));
+
+        let mut context = SELExecutionContext::new();
+        context.set_input(input);
+
+        let results = run(&tree, &context);
+
+        return results.get(0).unwrap().clone();
+    }
+
+    fn int_values(result: &SELExecutionResult) -> Vec<i64> {
+        let values: Vec<SELValue> = match result.get_type() {
+            DataType::List => {
+                let list: List = from_value_data(result.get_value().unwrap());
+                list.get_values().clone()
+            }
+            DataType::AssociativeList => {
+                let list: AssociativeList = from_value_data(result.get_value().unwrap());
+                list.get_list().get_values().clone()
+            }
+            _ => panic!("Expected a list, found {:?}", result.get_type()),
+        };
+
+        return values
+            .iter()
+            .map(|value| from_value_data(value.get_value().unwrap()))
+            .collect();
+    }
+
+    #[test]
+    fn collects_stream_with_seed() {
+        let result = result_of(
+            "$ >>> $.value * 2 >- 0 -< $.value + $.result",
+            int_list(vec![1, 2, 3, 4, 5]),
+        );
+
+        let value: i64 = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(result.get_type(), DataType::Integer);
+        assert_eq!(value, 30);
+    }
+
+    #[test]
+    fn collects_stream_into_list() {
+        let result = result_of("$ >>> $.value * 2 >- [] -<", int_list(vec![1, 2, 3, 4, 5]));
+
+        assert_eq!(int_values(&result), vec![2, 4, 6, 8, 10]);
+    }
+
+    #[test]
+    fn collects_without_seed() {
+        let result = result_of("$ >>> $.value * 2 >- -<", int_list(vec![1, 2, 3]));
+
+        assert_eq!(result.get_type(), DataType::List);
+        assert_eq!(int_values(&result), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn collects_with_first_value_seed() {
+        let result = result_of(
+            "$ >>> $.value * 2 >- ? -< ($.result + $.value) / 2",
+            int_list(vec![1, 2, 3, 4, 5]),
+        );
+
+        let value: i64 = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(value, 8);
+    }
+
+    #[test]
+    fn collects_empty_with_first_value_seed() {
+        let result = result_of("$ >- ? -< $.result + $.value", int_list(vec![]));
+
+        assert_eq!(result.get_type(), DataType::Unit);
+    }
+
+    #[test]
+    fn collects_list_directly() {
+        let result = result_of("$ >- 0 -< $.result + $.value", int_list(vec![1, 2, 3]));
+
+        let value: i64 = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(value, 6);
+    }
+
+    #[test]
+    fn collects_range() {
+        let result = result_of("1..5 >- 0 -< $.result + $.value", SELValue::new());
+
+        let value: i64 = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(value, 10);
+    }
+
+    #[test]
+    fn collects_associative_list() {
+        let result = result_of("[:a = 1, :b = 2] >- [] -<", SELValue::new());
+
+        assert_eq!(result.get_type(), DataType::AssociativeList);
+
+        let list: AssociativeList = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(list.get_list().get_values().len(), 2);
+        assert_eq!(list.get_associations().len(), 2);
+    }
+
+    #[test]
+    fn collects_invalid_source() {
+        let result = result_of("5 >- 0 -< $.result + $.value", SELValue::new());
+
+        assert_eq!(
+            result.get_error().unwrap().get_kind(),
+            RuntimeErrorKind::TypeMismatch
+        );
+    }
+
+    #[test]
+    fn collects_propagates_expression_error() {
+        let result = result_of("$ >- 0 -< $.value / $.result", int_list(vec![1, 2]));
+
+        assert_eq!(
+            result.get_error().unwrap().get_kind(),
+            RuntimeErrorKind::DivideByZero
+        );
+    }
+}
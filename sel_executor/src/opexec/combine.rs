@@ -0,0 +1,221 @@
+use super::stream::stream_source;
+use super::utils::type_mismatch;
+use super::{get_node_result, SELExecutionContext, SELExecutionResult};
+use sel_common::sel_types::list::List;
+use sel_common::sel_types::stream::SELStream;
+use sel_common::{to_value_data, DataType, Operation, SELTree, SELTreeNode, SELValue, ValueData};
+
+// values of combined streams
+// one value is taken from each stream per step
+// ends when any of the streams end
+pub struct CombinedValues<'a> {
+    sources: Vec<Box<dyn Iterator<Item = SELValue> + 'a>>,
+}
+
+impl<'a> CombinedValues<'a> {
+    pub fn new(tree: &'a SELTree, stream: &SELStream, context: &SELExecutionContext) -> Self {
+        let sources = stream
+            .get_source()
+            .get_value()
+            .and_then(ValueData::as_list)
+            .map_or(vec![], |list| {
+                list.get_values()
+                    .iter()
+                    .map(|source| stream_source(tree, source, context))
+                    .collect()
+            });
+
+        return CombinedValues { sources };
+    }
+}
+
+impl<'a> Iterator for CombinedValues<'a> {
+    type Item = SELValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut values = List::new();
+
+        for source in self.sources.iter_mut() {
+            let value = source.next()?;
+
+            if value.is_error() {
+                return Some(value);
+            }
+
+            values.push(value);
+        }
+
+        return Some(SELValue::new_from_list(values));
+    }
+}
+
+fn is_combinable(data_type: DataType) -> bool {
+    return matches!(
+        data_type,
+        DataType::Stream
+            | DataType::List
+            | DataType::AssociativeList
+            | DataType::Range
+            | DataType::String
+    );
+}
+
+// chained combines are flattened
+// so a <> b <> c produces values of 3 items
+// a grouped combine is treated as a single stream
+fn get_sources(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    context: &mut SELExecutionContext,
+    sources: &mut Vec<SELExecutionResult>,
+) {
+    for side in [node.get_left(), node.get_right()].iter() {
+        match side.and_then(|index| tree.get_nodes().get(index)) {
+            Some(side_node) if side_node.get_operation() == Operation::Combine => {
+                get_sources(tree, side_node, context, sources)
+            }
+            Some(side_node) => sources.push(get_node_result(tree, side_node, context)),
+            None => sources.push(SELExecutionResult::new(DataType::Unknown, None)),
+        }
+    }
+}
+
+pub fn operation(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    context: &mut SELExecutionContext,
+) -> SELExecutionResult {
+    let mut sources: Vec<SELExecutionResult> = vec![];
+
+    get_sources(tree, node, context, &mut sources);
+
+    let source_refs: Vec<&SELExecutionResult> = sources.iter().collect();
+
+    if sources
+        .iter()
+        .any(|source| source.is_error() || !is_combinable(source.get_type()))
+    {
+        return type_mismatch(node, &source_refs);
+    }
+
+    let mut list = List::new();
+
+    for source in sources.iter() {
+        list.push(source.get_sel_value().clone());
+    }
+
+    return SELExecutionResult::new(
+        DataType::Stream,
+        Some(to_value_data(SELStream::combined(list))),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{execute_stream, SELExecutionContext};
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::sel_types::list::List;
+    use sel_common::{from_value_data, DataType, SELValue};
+    use sel_compiler::Compiler;
+
+    fn stream_values(s: &str) -> Vec<SELValue> {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from(s));
+
+        return execute_stream(&tree, &SELExecutionContext::new())
+            .map(|result| result.get_sel_value().clone())
+            .collect();
+    }
+
+    fn list_values(value: &SELValue) -> Vec<SELValue> {
+        assert_eq!(value.get_type(), DataType::List);
+
+        let list: List = from_value_data(value.get_value().unwrap());
+
+        return list.get_values().clone();
+    }
+
+    fn int_values(value: &SELValue) -> Vec<i64> {
+        return list_values(value)
+            .iter()
+            .map(|item| from_value_data(item.get_value().unwrap()))
+            .collect();
+    }
+
+    #[test]
+    fn combines_two_streams() {
+        let values = stream_values("(10, 20, 30) <> (40, 50, 60)");
+
+        let values: Vec<Vec<i64>> = values.iter().map(int_values).collect();
+
+        assert_eq!(values, vec![vec![10, 40], vec![20, 50], vec![30, 60]]);
+    }
+
+    #[test]
+    fn combines_three_streams() {
+        let values = stream_values("(10, 20, 30) <> (40, 50, 60) <> (70, 80, 90)");
+
+        let values: Vec<Vec<i64>> = values.iter().map(int_values).collect();
+
+        assert_eq!(
+            values,
+            vec![vec![10, 40, 70], vec![20, 50, 80], vec![30, 60, 90]]
+        );
+    }
+
+    #[test]
+    fn combines_nested_streams() {
+        let values = stream_values("((10, 20, 30) <> (40, 50, 60)) <> (70, 80, 90)");
+
+        assert_eq!(values.len(), 3);
+
+        let first = list_values(values.get(0).unwrap());
+
+        assert_eq!(int_values(first.get(0).unwrap()), vec![10, 40]);
+        assert_eq!(
+            from_value_data::<i64>(first.get(1).unwrap().get_value().unwrap()),
+            70
+        );
+    }
+
+    #[test]
+    fn combines_until_shortest_ends() {
+        let values = stream_values("(10, 20, 30) <> 1..3");
+
+        let values: Vec<Vec<i64>> = values.iter().map(int_values).collect();
+
+        assert_eq!(values, vec![vec![10, 1], vec![20, 2]]);
+    }
+
+    #[test]
+    fn combines_processed_streams() {
+        let values = stream_values("((1, 2, 3) >>> $.value * 10) <> (4, 5, 6) >>> $.value");
+
+        let values: Vec<Vec<i64>> = values.iter().map(int_values).collect();
+
+        assert_eq!(values, vec![vec![10, 4], vec![20, 5], vec![30, 6]]);
+    }
+
+    #[test]
+    fn combined_values_are_processed() {
+        let values = stream_values("(10, 20) <> (30, 40) >>> $.value.1");
+
+        let values: Vec<i64> = values
+            .iter()
+            .map(|value| from_value_data(value.get_value().unwrap()))
+            .collect();
+
+        assert_eq!(values, vec![30, 40]);
+    }
+
+    #[test]
+    fn combines_invalid_source() {
+        let values = stream_values("(10, 20) <> 5");
+
+        assert_eq!(values.len(), 1);
+        assert_eq!(
+            values.get(0).unwrap().get_error().unwrap().get_kind(),
+            RuntimeErrorKind::TypeMismatch
+        );
+    }
+}
@@ -2,9 +2,11 @@ use super::super::context::SELExecutionContext;
 use crate::opexec::execution_result::SELExecutionResult;
 use crate::opexec::get_node_result;
 use crate::opexec::utils::{
-    get_left_right_results, get_value_from_result, get_values_from_results, match_equality_ops,
+    get_left_right_results, get_values_from_results, match_equality_ops,
+};
+use sel_common::{
+    to_value_data, DataType, Operation, SELContext, SELTree, SELTreeNode, ValueData,
 };
-use sel_common::{to_byte_vec, DataType, Operation, SELContext, SELTree, SELTreeNode};
 
 fn run_match(
     tree: &SELTree,
@@ -17,7 +19,8 @@ fn run_match(
         .and_then(|left_index| tree.get_nodes().get(left_index))
         .map(|left_node| get_node_result(tree, left_node, context))
         .map(|left_result| {
-            let run = get_value_from_result::<bool>(&left_result);
+            // only a boolean true runs the arm
+            let run = matches!(left_result.get_value(), Some(ValueData::Boolean(true)));
             if invert {
                 !run
             } else {
@@ -38,7 +41,7 @@ fn get_current_result(context: &SELExecutionContext) -> Option<SELExecutionResul
             Some(input) => Some(SELExecutionResult::new(
                 input.get_type(),
                 match input.get_value() {
-                    Some(value) => Some(std::vec::Vec::from(value.as_slice())),
+                    Some(value) => Some(value.clone()),
                     None => None,
                 },
             )),
@@ -72,7 +75,7 @@ fn match_bool(
                 Some(input) => Some(SELExecutionResult::new(
                     input.get_type(),
                     match input.get_value() {
-                        Some(value) => Some(std::vec::Vec::from(value.as_slice())),
+                        Some(value) => Some(value.clone()),
                         None => None,
                     },
                 )),
@@ -179,9 +182,9 @@ pub fn match_list(
 #[cfg(test)]
 mod tests {
     use super::super::test_utils::result_of_binary_op;
-    use crate::opexec::get_node_result;
+    use crate::tests::run_root;
     use crate::SELExecutionContext;
-    use sel_common::{from_byte_vec, DataType, Operation, SELValue};
+    use sel_common::{from_value_data, DataType, Operation, SELValue};
     use sel_compiler::Compiler;
 
     #[test]
@@ -192,8 +195,8 @@ mod tests {
         let mut execution_context = SELExecutionContext::new();
         execution_context.set_input(SELValue::new_from_int(200));
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 100);
@@ -207,8 +210,8 @@ mod tests {
         let mut execution_context = SELExecutionContext::new();
         execution_context.set_input(SELValue::new_from_int(200));
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 200);
@@ -222,8 +225,8 @@ mod tests {
         let mut execution_context = SELExecutionContext::new();
         execution_context.set_input(SELValue::new_from_int(200));
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 200);
@@ -237,8 +240,8 @@ mod tests {
         let mut execution_context = SELExecutionContext::new();
         execution_context.set_input(SELValue::new_from_int(200));
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 100);
@@ -252,8 +255,8 @@ mod tests {
         let mut execution_context = SELExecutionContext::new();
         execution_context.set_input(SELValue::new_from_boolean(true));
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: String = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: String = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::String);
         assert_eq!(value, String::from("true 1"));
@@ -269,8 +272,8 @@ mod tests {
         let mut execution_context = SELExecutionContext::new();
         execution_context.set_input(SELValue::new_from_boolean(false));
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: String = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: String = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::String);
         assert_eq!(value, String::from("false"));
@@ -286,8 +289,8 @@ mod tests {
         let mut execution_context = SELExecutionContext::new();
         execution_context.set_input(SELValue::new_from_boolean(false));
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, false);
@@ -1,91 +1,55 @@
-use super::super::context::SELExecutionContext;
 use crate::opexec::execution_result::SELExecutionResult;
-use crate::opexec::utils::{
-    get_left_right_results, get_value_from_result, get_values_from_results, match_equality_ops,
-};
-use sel_common::sel_types::associative_list::AssociativeList;
-use sel_common::sel_types::list::List;
-use sel_common::sel_types::pair::Pair;
-use sel_common::{from_byte_vec, to_byte_vec, DataType, SELTree, SELTreeNode};
+use crate::opexec::utils::type_mismatch;
+use sel_common::{to_value_data, DataType, SELTreeNode, ValueData};
 
 fn contains(
-    tree: &SELTree,
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
     invert: bool,
 ) -> SELExecutionResult {
-    let (left_result, right_result) = get_left_right_results(tree, node, context);
-
-    return match left_result.get_type() {
-        DataType::AssociativeList => {
-            let left_value = get_value_from_result::<AssociativeList>(&left_result);
-
-            let mut contains = false;
-
-            for item in left_value.get_list().get_values() {
-                let equal = if item.get_type() == DataType::Pair {
-                    let pair: Pair = from_byte_vec(item.get_value().unwrap());
-                    pair.get_right().get_value() == right_result.get_value()
-                } else {
-                    item.get_value() == right_result.get_value()
-                };
-
-                if equal {
-                    contains = true;
-                    break;
-                }
+    let right_value = right_result.get_value();
+
+    let contains = match left_result.get_value() {
+        // values of pairs are compared
+        Some(ValueData::AssociativeList(list)) => list.get_list().get_values().iter().any(|item| {
+            match item.get_value().and_then(ValueData::as_pair) {
+                Some(pair) => pair.get_right().get_value() == right_value,
+                None => item.get_value() == right_value,
             }
-
-            if invert {
-                contains = !contains;
-            }
-
-            SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(contains)))
-        }
-        DataType::List => {
-            let left_value = get_value_from_result::<List>(&left_result);
-
-            let mut contains = false;
-
-            for item in left_value.get_values() {
-                if item.get_value() == right_result.get_value() {
-                    contains = true;
-                    break;
-                }
-            }
-
-            if invert {
-                contains = !contains;
-            }
-
-            SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(contains)))
-        }
-        _ => SELExecutionResult::new(DataType::Unknown, None),
+        }),
+        Some(ValueData::List(list)) => list
+            .get_values()
+            .iter()
+            .any(|item| item.get_value() == right_value),
+        _ => return type_mismatch(node, &[&left_result, &right_result]),
     };
+
+    return SELExecutionResult::new(DataType::Boolean, Some(to_value_data(contains != invert)));
 }
 
-pub fn contains_operation(
-    tree: &SELTree,
+pub fn apply_contains(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
-    return contains(tree, node, context, false);
+    return contains(node, left_result, right_result, false);
 }
 
-pub fn not_contains_operation(
-    tree: &SELTree,
+pub fn apply_not_contains(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
-    return contains(tree, node, context, true);
+    return contains(node, left_result, right_result, true);
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::test_utils::result_of_binary_op;
-    use crate::opexec::get_node_result;
+    use crate::tests::run_root;
     use crate::SELExecutionContext;
-    use sel_common::{from_byte_vec, DataType, Operation};
+    use sel_common::{from_value_data, DataType, Operation};
     use sel_compiler::Compiler;
 
     #[test]
@@ -94,10 +58,10 @@ mod tests {
         let tree = compiler.compile(&String::from(
             "[:email = \"panda@example.com\", :username = \"panda\"] ~= \"panda\"",
         ));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, true);
@@ -109,10 +73,10 @@ mod tests {
         let tree = compiler.compile(&String::from(
             "[:email = \"panda@example.com\", :username = \"panda\"] ~= \"polar\"",
         ));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, false);
@@ -124,10 +88,10 @@ mod tests {
         let tree = compiler.compile(&String::from(
             "[:email = \"panda@example.com\", :username = \"panda\"] ~!= \"panda\"",
         ));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, false);
@@ -139,10 +103,10 @@ mod tests {
         let tree = compiler.compile(&String::from(
             "[:email = \"panda@example.com\", :username = \"panda\"] ~!= \"polar\"",
         ));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, true);
@@ -152,10 +116,10 @@ mod tests {
     fn executes_list_contains_value_true() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(10, 20, 30) ~= 20"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, true);
@@ -165,10 +129,10 @@ mod tests {
     fn executes_list_contains_value_false() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(10, 20, 30) ~= 40"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, false);
@@ -178,10 +142,10 @@ mod tests {
     fn executes_list_not_contains_value_false() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(10, 20, 30) ~!= 20"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, false);
@@ -191,10 +155,10 @@ mod tests {
     fn executes_list_not_contains_value_true() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(10, 20, 30) ~!= 40"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, true);
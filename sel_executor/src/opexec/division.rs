@@ -1,47 +1,70 @@
-use super::super::context::SELExecutionContext;
-use super::utils::{match_math_ops, OptionOr};
+use super::utils::{match_math_ops, type_mismatch, OptionOr};
 use super::SELExecutionResult;
 use crate::opexec::utils::match_int_math_ops;
-use sel_common::{DataType, SELTree, SELTreeNode};
+use sel_common::sel_types::error::RuntimeErrorKind;
+use sel_common::SELTreeNode;
 
-pub fn division_operation(
-    tree: &SELTree,
+pub fn apply_division(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
     return match match_math_ops(
-        tree,
         node,
-        context,
-        |left, right| left / right,
-        |left, right| left / right,
+        left_result,
+        right_result,
+        |left, right| match right {
+            0 => Err(RuntimeErrorKind::DivideByZero),
+            _ => left.checked_div(right).ok_or(RuntimeErrorKind::Overflow),
+        },
+        |left, right| {
+            if right == 0.0 {
+                Err(RuntimeErrorKind::DivideByZero)
+            } else {
+                Ok(left / right)
+            }
+        },
     ) {
         OptionOr::Some(result) => result,
-        OptionOr::Or(_) => SELExecutionResult::new(DataType::Unknown, Some(vec![])),
+        OptionOr::Or((left_result, right_result)) => {
+            type_mismatch(node, &[&left_result, &right_result])
+        }
     };
 }
 
-pub fn integer_division_operation(
-    tree: &SELTree,
+pub fn apply_integer_division(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
     return match match_int_math_ops(
-        tree,
         node,
-        context,
-        |left, right| left / right,
-        |left, right| (left / right).floor() as i64,
+        left_result,
+        right_result,
+        |left, right| match right {
+            0 => Err(RuntimeErrorKind::DivideByZero),
+            _ => left.checked_div(right).ok_or(RuntimeErrorKind::Overflow),
+        },
+        |left, right| {
+            if right == 0.0 {
+                Err(RuntimeErrorKind::DivideByZero)
+            } else {
+                Ok((left / right).floor() as i64)
+            }
+        },
     ) {
         OptionOr::Some(result) => result,
-        OptionOr::Or(_) => SELExecutionResult::new(DataType::Unknown, Some(vec![])),
+        OptionOr::Or((left_result, right_result)) => {
+            type_mismatch(node, &[&left_result, &right_result])
+        }
     };
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::test_utils::result_of_binary_op;
-    use sel_common::{from_byte_vec, DataType, Operation};
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::{from_value_data, DataType, Operation};
 
     #[test]
     fn executes_implicit_integer_division() {
@@ -54,7 +77,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -73,7 +96,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -92,7 +115,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -111,7 +134,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -158,7 +181,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -177,11 +200,102 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(result_value, Some(2));
     }
+
+    #[test]
+    fn executes_integer_division_by_zero() {
+        let result = result_of_binary_op(
+            Operation::Division,
+            DataType::Integer,
+            "10",
+            DataType::Integer,
+            "0",
+        );
+
+        let error = result.get_error().unwrap();
+
+        assert_eq!(result.get_type(), DataType::Error);
+        assert_eq!(error.get_kind(), RuntimeErrorKind::DivideByZero);
+        assert_eq!(error.get_operation(), Operation::Division);
+        assert_eq!(
+            error.get_operand_types(),
+            &vec![DataType::Integer, DataType::Integer]
+        );
+    }
+
+    #[test]
+    fn executes_decimal_division_by_zero() {
+        let result = result_of_binary_op(
+            Operation::Division,
+            DataType::Decimal,
+            "3.14",
+            DataType::Integer,
+            "0",
+        );
+
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::DivideByZero);
+        assert_eq!(
+            error.get_operand_types(),
+            &vec![DataType::Decimal, DataType::Integer]
+        );
+    }
+
+    #[test]
+    fn executes_integer_division_overflow() {
+        let result = result_of_binary_op(
+            Operation::IntegerDivision,
+            DataType::Integer,
+            "-9223372036854775808",
+            DataType::Integer,
+            "-1",
+        );
+
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::Overflow);
+        assert_eq!(error.get_operation(), Operation::IntegerDivision);
+    }
+
+    #[test]
+    fn executes_integer_division_by_zero_decimal() {
+        let result = result_of_binary_op(
+            Operation::IntegerDivision,
+            DataType::Integer,
+            "10",
+            DataType::Decimal,
+            "0.0",
+        );
+
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::DivideByZero);
+    }
+
+    #[test]
+    fn executes_division_type_mismatch() {
+        let result = result_of_binary_op(
+            Operation::Division,
+            DataType::Integer,
+            "10",
+            DataType::String,
+            "\"value\"",
+        );
+
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::TypeMismatch);
+        assert_eq!(
+            error.get_operand_types(),
+            &vec![DataType::Integer, DataType::String]
+        );
+        assert_eq!(error.get_message(), "cannot apply Division to Integer and String");
+    }
 }
@@ -1,4 +1,5 @@
-use sel_common::{DataType, SELValue};
+use sel_common::sel_types::error::SELRuntimeError;
+use sel_common::{DataType, SELValue, ValueData};
 use std::fmt;
 
 #[derive(Clone)]
@@ -7,7 +8,7 @@ pub struct SELExecutionResult {
 }
 
 impl SELExecutionResult {
-    pub fn new(data_type: DataType, value: Option<Vec<u8>>) -> Self {
+    pub fn new(data_type: DataType, value: Option<ValueData>) -> Self {
         return SELExecutionResult {
             value: SELValue::new_from_raw(data_type, value),
         };
@@ -19,11 +20,25 @@ impl SELExecutionResult {
         };
     }
 
+    pub fn error(error: SELRuntimeError) -> Self {
+        return SELExecutionResult {
+            value: SELValue::new_from_error(error),
+        };
+    }
+
+    pub fn is_error(&self) -> bool {
+        return self.value.is_error();
+    }
+
+    pub fn get_error(&self) -> Option<SELRuntimeError> {
+        return self.value.get_error();
+    }
+
     pub fn get_type(&self) -> DataType {
         return self.value.get_type();
     }
 
-    pub fn get_value(&self) -> Option<&Vec<u8>> {
+    pub fn get_value(&self) -> Option<&ValueData> {
         return match &self.value.get_value() {
             Some(v) => Some(&v),
             None => None,
@@ -50,13 +65,13 @@ impl std::fmt::Display for SELExecutionResult {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use sel_common::to_byte_vec;
+    use sel_common::to_value_data;
 
     #[test]
     fn display_str() {
         let result = SELExecutionResult::new(
             DataType::String,
-            Some(to_byte_vec(&String::from("Hello World"))),
+            Some(to_value_data(&String::from("Hello World"))),
         );
 
         let formatted = format!("{}", result);
@@ -66,7 +81,7 @@ mod tests {
 
     #[test]
     fn display_int() {
-        let result = SELExecutionResult::new(DataType::Integer, Some(to_byte_vec(10)));
+        let result = SELExecutionResult::new(DataType::Integer, Some(to_value_data(10)));
 
         let formatted = format!("{}", result);
 
@@ -75,7 +90,7 @@ mod tests {
 
     #[test]
     fn display_decimal() {
-        let result = SELExecutionResult::new(DataType::Decimal, Some(to_byte_vec(3.14)));
+        let result = SELExecutionResult::new(DataType::Decimal, Some(to_value_data(3.14)));
 
         let formatted = format!("{}", result);
 
@@ -84,7 +99,7 @@ mod tests {
 
     #[test]
     fn display_bool() {
-        let result = SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(false)));
+        let result = SELExecutionResult::new(DataType::Boolean, Some(to_value_data(false)));
 
         let formatted = format!("{}", result);
 
@@ -104,7 +119,7 @@ mod tests {
     fn debug_str() {
         let result = SELExecutionResult::new(
             DataType::String,
-            Some(to_byte_vec(&String::from("Hello World"))),
+            Some(to_value_data(&String::from("Hello World"))),
         );
 
         let formatted = format!("{:?}", result);
@@ -114,7 +129,7 @@ mod tests {
 
     #[test]
     fn debug_int() {
-        let result = SELExecutionResult::new(DataType::Integer, Some(to_byte_vec(10)));
+        let result = SELExecutionResult::new(DataType::Integer, Some(to_value_data(10)));
 
         let formatted = format!("{:?}", result);
 
@@ -123,7 +138,7 @@ mod tests {
 
     #[test]
     fn debug_decimal() {
-        let result = SELExecutionResult::new(DataType::Decimal, Some(to_byte_vec(3.14)));
+        let result = SELExecutionResult::new(DataType::Decimal, Some(to_value_data(3.14)));
 
         let formatted = format!("{:?}", result);
 
@@ -132,7 +147,7 @@ mod tests {
 
     #[test]
     fn debug_bool() {
-        let result = SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(false)));
+        let result = SELExecutionResult::new(DataType::Boolean, Some(to_value_data(false)));
 
         let formatted = format!("{:?}", result);
 
@@ -1,29 +1,51 @@
-use super::super::context::SELExecutionContext;
 use super::execution_result::SELExecutionResult;
-use super::utils::{match_math_ops, OptionOr};
-use sel_common::{DataType, SELTree, SELTreeNode};
+use super::utils::{get_values_from_results, match_math_ops, type_mismatch, OptionOr};
+use sel_common::sel_types::error::RuntimeErrorKind;
+use sel_common::{to_value_data, DataType, SELTreeNode};
+use std::convert::TryFrom;
 
-pub fn operation(
-    tree: &SELTree,
+pub fn apply(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
+    // negative exponents give fractions
+    // so integers are raised to them as decimals
+    if left_result.get_type() == DataType::Integer && right_result.get_type() == DataType::Integer {
+        let (left, right) = get_values_from_results::<i64, i64>(&left_result, &right_result);
+
+        if right < 0 {
+            return SELExecutionResult::new(
+                DataType::Decimal,
+                Some(to_value_data((left as f64).powf(right as f64))),
+            );
+        }
+    }
+
     return match match_math_ops(
-        tree,
         node,
-        context,
-        |left, right| left.pow(right as u32),
-        |left, right| left.powf(right),
+        left_result,
+        right_result,
+        |left, right| {
+            u32::try_from(right)
+                .ok()
+                .and_then(|exponent| left.checked_pow(exponent))
+                .ok_or(RuntimeErrorKind::Overflow)
+        },
+        |left, right| Ok(left.powf(right)),
     ) {
         OptionOr::Some(result) => result,
-        OptionOr::Or(_) => SELExecutionResult::new(DataType::Unknown, Some(vec![])),
+        OptionOr::Or((left_result, right_result)) => {
+            type_mismatch(node, &[&left_result, &right_result])
+        }
     };
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::test_utils::result_of_binary_op;
-    use sel_common::{from_byte_vec, DataType, Operation};
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::{from_value_data, DataType, Operation};
 
     #[test]
     fn executes_integer_exponential() {
@@ -36,7 +58,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -55,7 +77,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -74,7 +96,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -93,7 +115,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -128,4 +150,34 @@ mod tests {
         assert_eq!(result.get_type(), DataType::Unit);
         assert_eq!(result.get_value(), None);
     }
+
+    #[test]
+    fn executes_integer_exponential_overflow() {
+        let result = result_of_binary_op(
+            Operation::Exponential,
+            DataType::Integer,
+            "10",
+            DataType::Integer,
+            "30",
+        );
+
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::Overflow);
+        assert_eq!(error.get_operation(), Operation::Exponential);
+    }
+
+    #[test]
+    fn executes_integer_negative_exponential() {
+        let result = result_of_binary_op(
+            Operation::Exponential,
+            DataType::Integer,
+            "2",
+            DataType::Integer,
+            "-1",
+        );
+
+        assert_eq!(result.get_type(), DataType::Decimal);
+        assert_eq!(from_value_data::<f64>(result.get_value().unwrap()), 0.5);
+    }
 }
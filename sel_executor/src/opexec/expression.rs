@@ -2,7 +2,9 @@ use super::SELExecutionContext;
 use crate::opexec::execution_result::SELExecutionResult;
 use crate::opexec::get_node_result;
 use sel_common::sel_types::expression::Expression;
-use sel_common::{from_byte_vec, to_byte_vec, DataType, Operation, SELTree, SELTreeNode, SELValue};
+use sel_common::{
+    from_value_data, to_value_data, DataType, Operation, SELTree, SELTreeNode, SELValue,
+};
 
 pub fn operation(
     tree: &SELTree,
@@ -13,29 +15,30 @@ pub fn operation(
         // right index of expression operation is the root of the expression
         .map(|right_index| {
             let expr = Expression::new(Some(right_index));
-            SELExecutionResult::new(DataType::Expression, Some(to_byte_vec(expr)))
+            SELExecutionResult::new(DataType::Expression, Some(to_value_data(expr)))
         })
         .unwrap_or(SELExecutionResult::new(
             DataType::Expression,
-            Some(to_byte_vec(Expression::new(None))),
+            Some(to_value_data(Expression::new(None))),
         ))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::{get_node_result, SELExecutionContext};
+    use super::super::SELExecutionContext;
+    use crate::tests::run_root;
     use sel_common::sel_types::expression::Expression;
-    use sel_common::{from_byte_vec, DataType, SELValue};
+    use sel_common::{from_value_data, DataType, SELValue};
     use sel_compiler::Compiler;
 
     #[test]
     fn executes_expression_declaration() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("{ 10 + 5 }"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let result_value: Expression = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let result_value: Expression = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Expression);
         assert_eq!(result_value.get_root(), Some(2));
@@ -46,10 +49,10 @@ mod tests {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("{ }"));
 
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let result_value: Expression = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let result_value: Expression = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Expression);
         assert_eq!(result_value.get_root(), None);
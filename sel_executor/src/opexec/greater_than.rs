@@ -1,17 +1,16 @@
-use super::super::context::SELExecutionContext;
 use super::utils::match_comparison_ops;
 use super::SELExecutionResult;
-use sel_common::{SELTree, SELTreeNode};
+use sel_common::SELTreeNode;
 
-pub fn operation(
-    tree: &SELTree,
+pub fn apply(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
     return match_comparison_ops(
-        tree,
         node,
-        context,
+        left_result,
+        right_result,
         |left, right| left > right,
         |left, right| left > right,
         |left, right| left > right,
@@ -21,7 +20,7 @@ pub fn operation(
 #[cfg(test)]
 mod tests {
     use super::super::test_utils::result_of_binary_op;
-    use sel_common::{from_byte_vec, DataType, Operation};
+    use sel_common::{from_value_data, DataType, Operation};
 
     #[test]
     fn executes_integer() {
@@ -34,7 +33,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -53,7 +52,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -72,7 +71,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -91,7 +90,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -110,7 +109,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
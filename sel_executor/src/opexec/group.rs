@@ -1,9 +1,10 @@
+use super::call::call_identifier;
 use super::execution_result::SELExecutionResult;
+use super::partial_application::{call_partial, get_partial};
 use super::{get_node_result, SELExecutionContext};
 use sel_common::sel_types::associative_list::AssociativeList;
 use sel_common::sel_types::expression::Expression;
-use sel_common::sel_types::list::List;
-use sel_common::{from_byte_vec, DataType, Operation, SELTree, SELTreeNode, SELValue};
+use sel_common::{from_value_data, DataType, Operation, SELTree, SELTreeNode, SELValue, ValueData};
 
 pub fn operation(
     tree: &SELTree,
@@ -26,33 +27,18 @@ pub fn operation(
                         let mut context = context.clone();
                         match left_node.get_operation() {
                             Operation::Touch => {
-                                tree.get_usize_value_of(left_node)
-                                    // get symbol string
-                                    .and_then(|symbol_index| {
-                                        tree.get_symbol_table().get_symbol(symbol_index)
-                                    })
-                                    // get function
-                                    // if we have gotten to this point
-                                    // the identifier should resolve to a function
-                                    .and_then(|symbol| context.get_function(symbol))
-                                    // if no function found map directly to a Unit value
-                                    // else map using the found function
-                                    .map_or(
-                                        Some(SELExecutionResult::from(&SELValue::new())),
-                                        |func| {
-                                            Some(SELExecutionResult::from(&func(
-                                                sel_value,
-                                                tree.get_symbol_table(),
-                                            )))
-                                        },
-                                    )
+                                // if we have gotten to this point
+                                // the identifier should resolve to a function
+                                call_identifier(tree, node, left_node, sel_value.clone(), &context)
+                                    // if no symbol found map directly to a Unit value
+                                    .or_else(|| Some(SELExecutionResult::from(&SELValue::new())))
                             }
                             _ => {
                                 let left_result = get_node_result(tree, left_node, &mut context);
                                 match left_result.get_type() {
                                     DataType::Expression => {
                                         let expr: Expression =
-                                            from_byte_vec(left_result.get_value().unwrap());
+                                            from_value_data(left_result.get_value().unwrap());
 
                                         // make new context for expression execution
                                         let mut expr_context = context.clone();
@@ -70,6 +56,18 @@ pub fn operation(
                                                 )
                                             })
                                     }
+                                    // call partially applied function
+                                    // with group value applied
+                                    DataType::PartialApplication => {
+                                        get_partial(&left_result).map(|partial| {
+                                            call_partial(
+                                                tree,
+                                                node,
+                                                &partial.apply(&sel_value),
+                                                &context,
+                                            )
+                                        })
+                                    }
                                     _ => None,
                                 }
                             }
@@ -97,15 +95,17 @@ pub fn operation(
                 match result.get_type() {
                     DataType::List => {
                         let mut func_sel_value = result.get_sel_value().clone();
-                        let list: List = from_byte_vec(result.get_value().unwrap());
                         // if there are any pairs in list
                         // promote to associative array for function call
-                        for value in list.get_values() {
-                            if value.get_type() == DataType::Pair {
+                        if let Some(list) = result.get_value().and_then(ValueData::as_list) {
+                            if list
+                                .get_values()
+                                .iter()
+                                .any(|value| value.get_type() == DataType::Pair)
+                            {
                                 func_sel_value = SELValue::new_from_associative_list(
                                     AssociativeList::from(list),
                                 );
-                                break;
                             }
                         }
 
@@ -123,25 +123,26 @@ pub fn operation(
 
 #[cfg(test)]
 mod tests {
-    use sel_common::{from_byte_vec, DataType, SELContext, SELValue};
+    use crate::tests::run;
+    use sel_common::{from_value_data, DataType, SELContext, SELValue};
     use sel_compiler::Compiler;
 
-    use super::super::super::execute_sel_tree;
     use super::*;
     use sel_common::sel_types::associative_list::AssociativeList;
+    use sel_common::sel_types::error::RuntimeErrorKind;
 
     #[test]
     fn executes_group() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("5 * (4 + 3)"));
 
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let results = execute_sel_tree(&tree, &mut context);
+        let results = run(&tree, &context);
 
         let first_result = results.get(0).unwrap();
         let first_result_value = match first_result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -157,15 +158,15 @@ mod tests {
             SELValue::new_from_int(10)
         });
 
-        let mut execution_context = SELExecutionContext::from(&context);
+        let execution_context = SELExecutionContext::from(&context);
 
         let tree = compiler.compile_with_context(&String::from("get_vars()"), context);
 
-        let results = execute_sel_tree(&tree, &mut execution_context);
+        let results = run(&tree, &execution_context);
 
         let first_result = results.get(0).unwrap();
         let first_result_value = match first_result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -179,20 +180,20 @@ mod tests {
         let mut context = SELContext::new();
 
         context.register_function("get_vars", |sel_value: SELValue, symbol_table| {
-            let arg: i64 = sel_value.get_value().map_or(0, |val| from_byte_vec(val));
+            let arg: i64 = sel_value.get_value().map_or(0, |val| from_value_data(val));
 
             SELValue::new_from_int(arg * 10)
         });
 
-        let mut execution_context = SELExecutionContext::from(&context);
+        let execution_context = SELExecutionContext::from(&context);
 
         let tree = compiler.compile_with_context(&String::from("get_vars(10)"), context);
 
-        let results = execute_sel_tree(&tree, &mut execution_context);
+        let results = run(&tree, &execution_context);
 
         let first_result = results.get(0).unwrap();
         let first_result_value = match first_result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -206,9 +207,9 @@ mod tests {
         let mut context = SELContext::new();
 
         context.register_function("middle", |sel_value, symbol_table| {
-            let args: AssociativeList = from_byte_vec(sel_value.get_value().unwrap());
+            let args: AssociativeList = from_value_data(sel_value.get_value().unwrap());
 
-            let first_value: i64 = from_byte_vec(
+            let first_value: i64 = from_value_data(
                 args.get_by_association_index(
                     *symbol_table.get_value(&String::from("lower")).unwrap(),
                 )
@@ -217,7 +218,7 @@ mod tests {
                 .unwrap(),
             );
 
-            let second_value: i64 = from_byte_vec(
+            let second_value: i64 = from_value_data(
                 args.get_by_association_index(
                     *symbol_table.get_value(&String::from("upper")).unwrap(),
                 )
@@ -231,15 +232,15 @@ mod tests {
             SELValue::new_from_int(value)
         });
 
-        let mut execution_context = SELExecutionContext::from(&context);
+        let execution_context = SELExecutionContext::from(&context);
 
         let tree = compiler
             .compile_with_context(&String::from("middle(:lower = 10, :upper = 20)"), context);
 
-        let results = execute_sel_tree(&tree, &mut execution_context);
+        let results = run(&tree, &execution_context);
 
         let result = results.get(0).unwrap();
-        let result_value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result_value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(result_value, 15);
@@ -249,30 +250,34 @@ mod tests {
     fn executes_call_with_unregistered_function() {
         let compiler = Compiler::new();
 
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
         let tree = compiler.compile(&String::from("fetch(10)"));
 
-        let results = execute_sel_tree(&tree, &mut execution_context);
+        let results = run(&tree, &execution_context);
 
         let first_result = results.get(0).unwrap();
+        let error = first_result.get_error().unwrap();
 
-        assert_eq!(first_result.get_type(), DataType::Unit);
-        assert_eq!(first_result.get_value(), None);
+        assert_eq!(first_result.get_type(), DataType::Error);
+        assert_eq!(error.get_kind(), RuntimeErrorKind::UnknownFunction);
+        assert_eq!(error.get_operation(), Operation::Group);
+        assert_eq!(error.get_operand_types(), &vec![DataType::Integer]);
+        assert_eq!(error.get_message(), "no function named 'fetch'");
     }
 
     #[test]
     fn executes_call_expression() {
         let compiler = Compiler::new();
 
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
         let tree = compiler.compile(&String::from("{ 5 + 10 }\n?()"));
 
-        let results = execute_sel_tree(&tree, &mut execution_context);
+        let results = run(&tree, &execution_context);
 
         let result = results.get(1).unwrap();
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 15);
@@ -282,14 +287,14 @@ mod tests {
     fn executes_call_expression_with_input() {
         let compiler = Compiler::new();
 
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
         let tree = compiler.compile(&String::from("{ $ + 10 }\n?(5)"));
 
-        let results = execute_sel_tree(&tree, &mut execution_context);
+        let results = run(&tree, &execution_context);
 
         let result = results.get(1).unwrap();
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 15);
@@ -1,17 +1,16 @@
-use super::super::context::SELExecutionContext;
 use super::SELExecutionResult;
 use crate::opexec::utils::match_equality_ops;
-use sel_common::{DataType, SELTree, SELTreeNode};
+use sel_common::{DataType, SELTreeNode};
 
-pub fn operation(
-    tree: &SELTree,
+pub fn apply(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
     return match_equality_ops(
-        tree,
         node,
-        context,
+        left_result,
+        right_result,
         |left, right| left != right,
         |left, right| left != right,
         |left, right| left != right,
@@ -22,7 +21,7 @@ pub fn operation(
 #[cfg(test)]
 mod tests {
     use super::super::test_utils::result_of_binary_op;
-    use sel_common::{from_byte_vec, DataType, Operation};
+    use sel_common::{from_value_data, DataType, Operation};
 
     #[test]
     fn executes_integer() {
@@ -35,7 +34,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -54,7 +53,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -73,7 +72,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -92,7 +91,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -111,7 +110,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -129,7 +128,7 @@ mod tests {
             "()",
         );
 
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, true);
@@ -145,7 +144,7 @@ mod tests {
             "9",
         );
 
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, true);
@@ -161,7 +160,7 @@ mod tests {
             "()",
         );
 
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, false);
@@ -0,0 +1,130 @@
+use super::call::call_by_name;
+use super::utils::{first_error, get_left_right_results};
+use super::{SELExecutionContext, SELExecutionResult};
+use sel_common::sel_types::list::List;
+use sel_common::{DataType, SELTree, SELTreeNode, SELValue};
+
+// left `name` right
+// calls name with left and right as a two item list
+pub fn operation(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    context: &mut SELExecutionContext,
+) -> SELExecutionResult {
+    let (left_result, right_result) = get_left_right_results(tree, node, context);
+
+    return apply(tree, node, left_result, right_result, context);
+}
+
+pub fn apply(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
+    context: &SELExecutionContext,
+) -> SELExecutionResult {
+    let name = match tree
+        .get_usize_value_of(node)
+        .and_then(|symbol_index| tree.get_symbol_table().get_symbol(symbol_index))
+    {
+        Some(name) => name,
+        None => return SELExecutionResult::new(DataType::Unknown, None),
+    };
+
+    if let Some(error) = first_error(&[&left_result, &right_result]) {
+        return error;
+    }
+
+    let mut arguments = List::new();
+    arguments.push(left_result.get_sel_value().clone());
+    arguments.push(right_result.get_sel_value().clone());
+
+    return call_by_name(
+        tree,
+        node,
+        name,
+        SELValue::new_from_list(arguments),
+        context,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::opexec::execution_result::SELExecutionResult;
+    use crate::tests::run;
+    use crate::SELExecutionContext;
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::sel_types::list::List;
+    use sel_common::{from_value_data, DataType, SELContext, SELValue, SymbolTable};
+    use sel_compiler::Compiler;
+
+    fn max(arguments: SELValue, _symbol_table: &SymbolTable) -> SELValue {
+        let list: List = from_value_data(arguments.get_value().unwrap());
+
+        return list
+            .get_values()
+            .iter()
+            .map(|value| from_value_data::<i64>(value.get_value().unwrap()))
+            .max()
+            .map_or(SELValue::new(), SELValue::new_from_int);
+    }
+
+    fn results_of(s: &str) -> Vec<SELExecutionResult> {
+        let compiler = Compiler::new();
+        let mut context = SELContext::new();
+        context.register_function("max", max);
+
+        let tree = compiler.compile_with_context(&String::from(s), context);
+
+        return run(&tree, &SELExecutionContext::from(tree.get_context()));
+    }
+
+    fn int_result(s: &str) -> i64 {
+        let results = results_of(s);
+        let result = results.last().unwrap();
+
+        assert_eq!(result.get_type(), DataType::Integer);
+
+        return from_value_data(result.get_value().unwrap());
+    }
+
+    #[test]
+    fn calls_function() {
+        assert_eq!(int_result("10 `max` 15"), 15);
+    }
+
+    #[test]
+    fn calls_chained_functions() {
+        assert_eq!(int_result("10 `max` 15 `max` 5"), 15);
+    }
+
+    #[test]
+    fn calls_with_expression_operands() {
+        assert_eq!(int_result("10 * 2 `max` 15"), 20);
+    }
+
+    #[test]
+    fn calls_named_expression() {
+        assert_eq!(int_result("#sum $.0 + $.1\n\n10 `sum` 15"), 25);
+    }
+
+    #[test]
+    fn calls_unknown_function() {
+        let results = results_of("10 `other` 15");
+
+        assert_eq!(
+            results.last().unwrap().get_error().unwrap().get_kind(),
+            RuntimeErrorKind::UnknownFunction
+        );
+    }
+
+    #[test]
+    fn passes_operand_error() {
+        let results = results_of("10 / 0 `max` 15");
+
+        assert_eq!(
+            results.last().unwrap().get_error().unwrap().get_kind(),
+            RuntimeErrorKind::DivideByZero
+        );
+    }
+}
@@ -10,7 +10,7 @@ pub fn operation(
         Some(input) => SELExecutionResult::new(
             input.get_type(),
             match input.get_value() {
-                Some(value) => Some(std::vec::Vec::from(value.as_slice())),
+                Some(value) => Some(value.clone()),
                 None => None,
             },
         ),
@@ -20,9 +20,10 @@ pub fn operation(
 
 #[cfg(test)]
 mod tests {
-    use super::super::{get_node_result, SELExecutionContext};
+    use super::super::SELExecutionContext;
+    use crate::tests::run_root;
     use sel_common::{
-        from_byte_vec, DataHeap, DataType, Operation, SELContext, SELTree, SELTreeNode, SELValue,
+        from_value_data, DataHeap, DataType, Operation, SELContext, SELTree, SELTreeNode, SELValue,
     };
     use std::collections::HashMap;
 
@@ -54,10 +55,10 @@ mod tests {
 
         context.set_input(input);
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -87,9 +88,9 @@ mod tests {
             HashMap::new(),
         );
 
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         assert_eq!(result.get_type(), DataType::Unit);
         assert_eq!(result.get_value(), None);
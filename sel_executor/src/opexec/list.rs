@@ -2,13 +2,15 @@ use super::SELExecutionContext;
 use crate::opexec::execution_result::SELExecutionResult;
 use crate::opexec::get_node_result;
 use sel_common::sel_types::list::List;
-use sel_common::{from_byte_vec, to_byte_vec, DataType, Operation, SELTree, SELTreeNode, SELValue};
+use sel_common::{
+    from_value_data, to_value_data, DataType, Operation, SELTree, SELTreeNode, SELValue,
+};
 
 fn add_value_to_list(value: SELValue, list: &mut List) {
     if value.get_type() == DataType::List {
         let sub_list = value
             .get_value()
-            .map_or(List::new(), |value| from_byte_vec(value));
+            .map_or(List::new(), |value| from_value_data(value));
 
         for item in sub_list.get_values() {
             list.push(item.to_owned());
@@ -20,22 +22,16 @@ fn add_value_to_list(value: SELValue, list: &mut List) {
 
 fn add_if_exists(
     index: Option<usize>,
+    result: Option<SELExecutionResult>,
     tree: &SELTree,
-    context: &mut SELExecutionContext,
     list: &mut List,
 ) {
-    let result_info: Option<(SELExecutionResult, bool)> = index
-        .and_then(|index| tree.get_nodes().get(index))
-        .map(|node| {
-            let result: SELExecutionResult = get_node_result(tree, node, context);
-            let nested =
-                result.get_type() == DataType::List && node.get_operation() == Operation::Group;
-
-            (result, nested)
-        });
-
-    if result_info.is_some() {
-        let (result, nested) = result_info.unwrap();
+    let operand = index.and_then(|index| tree.get_nodes().get(index));
+
+    if let (Some(operand), Some(result)) = (operand, result) {
+        let nested =
+            result.get_type() == DataType::List && operand.get_operation() == Operation::Group;
+
         if nested {
             // already checked that value type is list
             // and marked as nested
@@ -51,30 +47,50 @@ pub fn operation(
     tree: &SELTree,
     node: &SELTreeNode,
     context: &mut SELExecutionContext,
+) -> SELExecutionResult {
+    let mut result_of = |index: Option<usize>| {
+        index
+            .and_then(|index| tree.get_nodes().get(index))
+            .map(|operand| get_node_result(tree, operand, context))
+    };
+
+    let left_result = result_of(node.get_left());
+    let right_result = result_of(node.get_right());
+
+    return apply(tree, node, left_result, right_result);
+}
+
+// results of operands the node has
+pub fn apply(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    left_result: Option<SELExecutionResult>,
+    right_result: Option<SELExecutionResult>,
 ) -> SELExecutionResult {
     let mut list = List::new();
 
-    add_if_exists(node.get_left(), tree, context, &mut list);
-    add_if_exists(node.get_right(), tree, context, &mut list);
+    add_if_exists(node.get_left(), left_result, tree, &mut list);
+    add_if_exists(node.get_right(), right_result, tree, &mut list);
 
-    return SELExecutionResult::new(DataType::List, Some(to_byte_vec(list)));
+    return SELExecutionResult::new(DataType::List, Some(to_value_data(list)));
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::{get_node_result, SELExecutionContext};
+    use super::super::SELExecutionContext;
+    use crate::tests::run_root;
     use sel_common::sel_types::list::List;
-    use sel_common::{from_byte_vec, DataType, SELValue};
+    use sel_common::{from_value_data, DataType, SELValue};
     use sel_compiler::Compiler;
 
     #[test]
     fn executes_two_member_list() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("100, true"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let list: List = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let list: List = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::List);
 
@@ -86,11 +102,14 @@ mod tests {
         let second_value: &SELValue = values.get(1).unwrap();
 
         assert_eq!(first_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(first_value.get_value().unwrap()), 100);
+        assert_eq!(
+            from_value_data::<i64>(first_value.get_value().unwrap()),
+            100
+        );
 
         assert_eq!(second_value.get_type(), DataType::Boolean);
         assert_eq!(
-            from_byte_vec::<bool>(second_value.get_value().unwrap()),
+            from_value_data::<bool>(second_value.get_value().unwrap()),
             true
         );
     }
@@ -99,10 +118,10 @@ mod tests {
     fn executes_empty_list() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from(","));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let list: List = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let list: List = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::List);
 
@@ -115,10 +134,10 @@ mod tests {
     fn executes_single_item_list_trailing() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("100,"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let list: List = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let list: List = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::List);
 
@@ -129,17 +148,20 @@ mod tests {
         let first_value: &SELValue = values.get(0).unwrap();
 
         assert_eq!(first_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(first_value.get_value().unwrap()), 100);
+        assert_eq!(
+            from_value_data::<i64>(first_value.get_value().unwrap()),
+            100
+        );
     }
 
     #[test]
     fn executes_single_item_list_leading() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from(",100"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let list: List = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let list: List = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::List);
 
@@ -150,17 +172,20 @@ mod tests {
         let first_value: &SELValue = values.get(0).unwrap();
 
         assert_eq!(first_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(first_value.get_value().unwrap()), 100);
+        assert_eq!(
+            from_value_data::<i64>(first_value.get_value().unwrap()),
+            100
+        );
     }
 
     #[test]
     fn executes_five_member_list() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("100, 200, 300, 400, 500"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let list: List = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let list: List = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::List);
 
@@ -175,29 +200,44 @@ mod tests {
         let fifth_value: &SELValue = values.get(4).unwrap();
 
         assert_eq!(first_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(first_value.get_value().unwrap()), 100);
+        assert_eq!(
+            from_value_data::<i64>(first_value.get_value().unwrap()),
+            100
+        );
 
         assert_eq!(second_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(second_value.get_value().unwrap()), 200);
+        assert_eq!(
+            from_value_data::<i64>(second_value.get_value().unwrap()),
+            200
+        );
 
         assert_eq!(third_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(third_value.get_value().unwrap()), 300);
+        assert_eq!(
+            from_value_data::<i64>(third_value.get_value().unwrap()),
+            300
+        );
 
         assert_eq!(fourth_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(fourth_value.get_value().unwrap()), 400);
+        assert_eq!(
+            from_value_data::<i64>(fourth_value.get_value().unwrap()),
+            400
+        );
 
         assert_eq!(fifth_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(fifth_value.get_value().unwrap()), 500);
+        assert_eq!(
+            from_value_data::<i64>(fifth_value.get_value().unwrap()),
+            500
+        );
     }
 
     #[test]
     fn executes_nested_list() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("100, 200, (300, 400, 500)"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let list: List = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let list: List = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::List);
 
@@ -209,7 +249,7 @@ mod tests {
         let second_value: &SELValue = values.get(1).unwrap();
         let third_value: &SELValue = values.get(2).unwrap();
 
-        let nested_list: List = from_byte_vec(third_value.get_value().unwrap());
+        let nested_list: List = from_value_data(third_value.get_value().unwrap());
         let nested_values = nested_list.get_values();
 
         let nested_first_value: &SELValue = nested_values.get(0).unwrap();
@@ -217,28 +257,34 @@ mod tests {
         let nested_third_value: &SELValue = nested_values.get(2).unwrap();
 
         assert_eq!(first_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(first_value.get_value().unwrap()), 100);
+        assert_eq!(
+            from_value_data::<i64>(first_value.get_value().unwrap()),
+            100
+        );
 
         assert_eq!(second_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(second_value.get_value().unwrap()), 200);
+        assert_eq!(
+            from_value_data::<i64>(second_value.get_value().unwrap()),
+            200
+        );
 
         assert_eq!(third_value.get_type(), DataType::List);
 
         assert_eq!(nested_first_value.get_type(), DataType::Integer);
         assert_eq!(
-            from_byte_vec::<i64>(nested_first_value.get_value().unwrap()),
+            from_value_data::<i64>(nested_first_value.get_value().unwrap()),
             300
         );
 
         assert_eq!(nested_second_value.get_type(), DataType::Integer);
         assert_eq!(
-            from_byte_vec::<i64>(nested_second_value.get_value().unwrap()),
+            from_value_data::<i64>(nested_second_value.get_value().unwrap()),
             400
         );
 
         assert_eq!(nested_third_value.get_type(), DataType::Integer);
         assert_eq!(
-            from_byte_vec::<i64>(nested_third_value.get_value().unwrap()),
+            from_value_data::<i64>(nested_third_value.get_value().unwrap()),
             500
         );
     }
@@ -247,10 +293,10 @@ mod tests {
     fn executes_multiple_nested_list() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("100, 200, (300, (400, 500), 600)"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let list: List = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let list: List = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::List);
 
@@ -262,30 +308,36 @@ mod tests {
         let second_value: &SELValue = values.get(1).unwrap();
         let third_value: &SELValue = values.get(2).unwrap();
 
-        let nested_list: List = from_byte_vec(third_value.get_value().unwrap());
+        let nested_list: List = from_value_data(third_value.get_value().unwrap());
         let nested_values = nested_list.get_values();
 
         let nested_first_value: &SELValue = nested_values.get(0).unwrap();
         let nested_second_value: &SELValue = nested_values.get(1).unwrap();
         let nested_third_value: &SELValue = nested_values.get(2).unwrap();
 
-        let second_nested_list: List = from_byte_vec(nested_second_value.get_value().unwrap());
+        let second_nested_list: List = from_value_data(nested_second_value.get_value().unwrap());
         let second_nested_values = second_nested_list.get_values();
 
         let second_nested_first_value: &SELValue = second_nested_values.get(0).unwrap();
         let second_nested_second_value: &SELValue = second_nested_values.get(1).unwrap();
 
         assert_eq!(first_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(first_value.get_value().unwrap()), 100);
+        assert_eq!(
+            from_value_data::<i64>(first_value.get_value().unwrap()),
+            100
+        );
 
         assert_eq!(second_value.get_type(), DataType::Integer);
-        assert_eq!(from_byte_vec::<i64>(second_value.get_value().unwrap()), 200);
+        assert_eq!(
+            from_value_data::<i64>(second_value.get_value().unwrap()),
+            200
+        );
 
         assert_eq!(third_value.get_type(), DataType::List);
 
         assert_eq!(nested_first_value.get_type(), DataType::Integer);
         assert_eq!(
-            from_byte_vec::<i64>(nested_first_value.get_value().unwrap()),
+            from_value_data::<i64>(nested_first_value.get_value().unwrap()),
             300
         );
 
@@ -293,19 +345,19 @@ mod tests {
 
         assert_eq!(second_nested_first_value.get_type(), DataType::Integer);
         assert_eq!(
-            from_byte_vec::<i64>(second_nested_first_value.get_value().unwrap()),
+            from_value_data::<i64>(second_nested_first_value.get_value().unwrap()),
             400
         );
 
         assert_eq!(second_nested_second_value.get_type(), DataType::Integer);
         assert_eq!(
-            from_byte_vec::<i64>(second_nested_second_value.get_value().unwrap()),
+            from_value_data::<i64>(second_nested_second_value.get_value().unwrap()),
             500
         );
 
         assert_eq!(nested_third_value.get_type(), DataType::Integer);
         assert_eq!(
-            from_byte_vec::<i64>(nested_third_value.get_value().unwrap()),
+            from_value_data::<i64>(nested_third_value.get_value().unwrap()),
             600
         );
     }
@@ -1,23 +1,21 @@
 use super::super::context::SELExecutionContext;
-use super::utils::{get_left_right_results, get_value_from_result, get_values_from_results};
-use super::SELExecutionResult;
-use sel_common::{to_byte_vec, DataType, SELTree, SELTreeNode};
+use super::utils::{get_value_from_result, get_values_from_results, type_mismatch};
+use super::{get_node_result, SELExecutionResult};
+use sel_common::{to_value_data, DataType, Operation, SELTree, SELTreeNode};
 
 fn logical_xor(left: bool, right: bool) -> bool {
     return (left || right) && left != right;
 }
 
 fn match_logical<F>(
-    tree: &SELTree,
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
     f: F,
 ) -> SELExecutionResult
 where
     F: Fn(bool, bool) -> bool,
 {
-    let (left_result, right_result) = get_left_right_results(tree, node, context);
-
     return match (left_result.get_type(), right_result.get_type()) {
         (DataType::Boolean, DataType::Boolean) => {
             let (left_val, right_val) =
@@ -25,32 +23,96 @@ where
 
             let result = f(left_val, right_val);
 
-            SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(result)))
+            SELExecutionResult::new(DataType::Boolean, Some(to_value_data(result)))
         }
         (DataType::Unit, DataType::Boolean) => {
             let right_val: bool = get_value_from_result(&right_result);
 
             let result = f(false, right_val);
 
-            SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(result)))
+            SELExecutionResult::new(DataType::Boolean, Some(to_value_data(result)))
         }
         (DataType::Boolean, DataType::Unit) => {
             let left_val: bool = get_value_from_result(&left_result);
 
             let result = f(left_val, false);
 
-            SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(result)))
+            SELExecutionResult::new(DataType::Boolean, Some(to_value_data(result)))
+        }
+        _ => type_mismatch(node, &[&left_result, &right_result]),
+    };
+}
+
+// result of && and || when the left side alone decides it
+// right side is not evaluated in that case
+pub fn short_circuit(
+    node: &SELTreeNode,
+    left_result: &SELExecutionResult,
+) -> Option<SELExecutionResult> {
+    let decided = match (node.get_operation(), left_result.get_type()) {
+        (Operation::LogicalAnd, DataType::Boolean)
+            if !get_value_from_result::<bool>(left_result) =>
+        {
+            false
         }
-        _ => SELExecutionResult::new(DataType::Unknown, Some(vec![])),
+        // unit is logically false
+        (Operation::LogicalAnd, DataType::Unit) => false,
+        (Operation::LogicalOr, DataType::Boolean) if get_value_from_result::<bool>(left_result) => {
+            true
+        }
+        _ => return None,
     };
+
+    return Some(SELExecutionResult::new(
+        DataType::Boolean,
+        Some(to_value_data(decided)),
+    ));
 }
 
-pub fn xor_operation(
+fn short_circuit_operation<F>(
     tree: &SELTree,
     node: &SELTreeNode,
     context: &mut SELExecutionContext,
+    apply: F,
+) -> SELExecutionResult
+where
+    F: Fn(&SELTreeNode, SELExecutionResult, SELExecutionResult) -> SELExecutionResult,
+{
+    let left = tree.get_nodes().get(node.get_left().unwrap()).unwrap();
+    let left_result = get_node_result(tree, left, context);
+
+    if let Some(result) = short_circuit(node, &left_result) {
+        return result;
+    }
+
+    let right = tree.get_nodes().get(node.get_right().unwrap()).unwrap();
+    let right_result = get_node_result(tree, right, context);
+
+    return apply(node, left_result, right_result);
+}
+
+pub fn apply_xor(
+    node: &SELTreeNode,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
+) -> SELExecutionResult {
+    return match_logical(node, left_result, right_result, logical_xor);
+}
+
+pub fn apply_or(
+    node: &SELTreeNode,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
-    return match_logical(tree, node, context, logical_xor);
+    return match_logical(node, left_result, right_result, |left, right| left || right);
+}
+
+pub fn apply_and(
+    node: &SELTreeNode,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
+) -> SELExecutionResult {
+    return match_logical(node, left_result, right_result, |left, right| left && right);
 }
 
 pub fn or_operation(
@@ -58,7 +120,7 @@ pub fn or_operation(
     node: &SELTreeNode,
     context: &mut SELExecutionContext,
 ) -> SELExecutionResult {
-    return match_logical(tree, node, context, |left, right| left || right);
+    return short_circuit_operation(tree, node, context, apply_or);
 }
 
 pub fn and_operation(
@@ -66,14 +128,15 @@ pub fn and_operation(
     node: &SELTreeNode,
     context: &mut SELExecutionContext,
 ) -> SELExecutionResult {
-    return match_logical(tree, node, context, |left, right| left && right);
+    return short_circuit_operation(tree, node, context, apply_and);
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::test_utils::result_of_binary_op;
     use crate::opexec::logical::logical_xor;
-    use sel_common::{from_byte_vec, DataType, Operation};
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::{from_value_data, DataType, Operation};
 
     #[test]
     fn executes_logical_or() {
@@ -86,7 +149,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -105,7 +168,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -124,7 +187,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -143,7 +206,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -162,7 +225,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -181,7 +244,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -200,7 +263,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -227,4 +290,20 @@ mod tests {
     fn xor_false_false() {
         assert_eq!(logical_xor(false, false), false);
     }
+
+    #[test]
+    fn executes_logical_type_mismatch() {
+        let result = result_of_binary_op(
+            Operation::LogicalAnd,
+            DataType::Boolean,
+            "true",
+            DataType::Integer,
+            "1",
+        );
+
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::TypeMismatch);
+        assert_eq!(error.get_operation(), Operation::LogicalAnd);
+    }
 }
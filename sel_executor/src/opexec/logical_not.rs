@@ -1,45 +1,37 @@
-use super::super::context::SELExecutionContext;
-use super::utils::get_value_from_result;
-use super::{get_node_result, SELExecutionResult};
-use sel_common::{to_byte_vec, DataType, SELTree, SELTreeNode};
-
-pub fn operation(
-    tree: &SELTree,
-    node: &SELTreeNode,
-    context: &mut SELExecutionContext,
-) -> SELExecutionResult {
-    let right = tree.get_nodes().get(node.get_right().unwrap()).unwrap();
-    let result = get_node_result(tree, &right, context);
+use super::utils::{get_value_from_result, type_mismatch};
+use super::SELExecutionResult;
+use sel_common::{to_value_data, DataType, SELTreeNode};
 
+pub fn apply(node: &SELTreeNode, result: SELExecutionResult) -> SELExecutionResult {
     return match result.get_type() {
         DataType::Boolean => {
             let right_val: bool = get_value_from_result(&result);
 
             let val = !right_val;
 
-            SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(val)))
+            SELExecutionResult::new(DataType::Boolean, Some(to_value_data(val)))
         }
         DataType::Integer => {
             let result_value: i64 = get_value_from_result(&result);
             let val = !result_value;
 
-            return SELExecutionResult::new(DataType::Integer, Some(to_byte_vec(val)));
+            return SELExecutionResult::new(DataType::Integer, Some(to_value_data(val)));
         }
         // Unit value is considered logically false
         // so negating it will always result in true
-        DataType::Unit => SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(true))),
-        _ => SELExecutionResult::new(DataType::Unknown, None),
+        DataType::Unit => SELExecutionResult::new(DataType::Boolean, Some(to_value_data(true))),
+        _ => type_mismatch(node, &[&result]),
     };
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::super::context;
-    use super::super::get_node_result;
     use super::super::test_utils::result_of_binary_op;
+    use crate::tests::run_root;
     use crate::SELExecutionContext;
     use sel_common::{
-        from_byte_vec, DataHeap, DataType, Operation, SELContext, SELTree, SELTreeNode,
+        from_value_data, DataHeap, DataType, Operation, SELContext, SELTree, SELTreeNode,
     };
     use sel_compiler::Compiler;
     use std::collections::HashMap;
@@ -79,12 +71,12 @@ mod tests {
             HashMap::new(),
         );
 
-        let mut context = context::SELExecutionContext::new();
+        let context = context::SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -98,7 +90,7 @@ mod tests {
             result_of_binary_op(Operation::Not, DataType::Unknown, "", DataType::Unit, "()");
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -110,10 +102,10 @@ mod tests {
     fn executes_bitwise_not() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("!10928"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, !10928);
@@ -1,7 +1,10 @@
-mod access;
+pub mod access;
 mod addition;
-mod associative_list;
+pub mod associative_list;
 mod bitwise;
+pub mod call;
+mod collect;
+mod combine;
 mod conditional;
 mod contains;
 mod division;
@@ -13,67 +16,80 @@ mod greater_than;
 mod greater_than_equal;
 mod group;
 mod inequality;
+mod infix;
 mod input;
 mod keys_equal;
 mod less_than;
 mod less_than_equal;
-mod list;
-mod logical;
+pub mod list;
+pub mod logical;
 mod logical_not;
 mod modulo;
 mod multiplication;
 mod negation;
-mod pair;
+pub mod pair;
+mod partial_application;
 mod pipe;
 mod range;
 mod result;
-mod stream;
+pub mod stream;
 mod subtraction;
-mod touch;
-mod utils;
+pub mod touch;
+pub mod utils;
 mod values_equal;
 
 use super::context::SELExecutionContext;
 use crate::opexec::execution_result::SELExecutionResult;
+use crate::opexec::utils::{
+    evaluation_stopped, get_left_right_results, get_operand_result, limit_exceeded,
+};
 use sel_common::{DataType, Operation, SELTree, SELTreeNode};
 
+// every evaluated node is a step
+// cancellation and deadline are checked along with steps
+// results are checked against the context's size limits
 pub fn get_node_result(
     tree: &SELTree,
     node: &SELTreeNode,
     context: &mut SELExecutionContext,
+) -> SELExecutionResult {
+    if let Err(stopped) = context.take_step() {
+        return evaluation_stopped(node, stopped);
+    }
+
+    let result = evaluate_node(tree, node, context);
+
+    return match context.check_size(result.get_sel_value()) {
+        Ok(()) => result,
+        Err(message) => limit_exceeded(node, message),
+    };
+}
+
+fn evaluate_node(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    context: &mut SELExecutionContext,
 ) -> SELExecutionResult {
     return match node.get_operation() {
         Operation::Touch => touch::operation(tree, node, context),
         Operation::Input => input::operation(tree, node, context),
         Operation::CurrentResult => result::operation(tree, node, context),
-        Operation::Addition => addition::operation(tree, node, context),
-        Operation::Subtraction => subtraction::operation(tree, node, context),
-        Operation::Multiplication => multiplication::operation(tree, node, context),
-        Operation::Division => division::division_operation(tree, node, context),
-        Operation::IntegerDivision => division::integer_division_operation(tree, node, context),
-        Operation::Modulo => modulo::operation(tree, node, context),
-        Operation::Exponential => exponential::operation(tree, node, context),
-        Operation::Negation => negation::operation(tree, node, context),
+        operation if is_binary_operation(operation) => {
+            let (left_result, right_result) = get_left_right_results(tree, node, context);
+
+            apply_binary(node, left_result, right_result)
+        }
+        Operation::Negation | Operation::Not => {
+            let result = get_operand_result(tree, node, node.get_right(), context);
+
+            apply_unary(node, result)
+        }
         Operation::LogicalOr => logical::or_operation(tree, node, context),
-        Operation::LogicalXOR => logical::xor_operation(tree, node, context),
         Operation::LogicalAnd => logical::and_operation(tree, node, context),
-        Operation::Not => logical_not::operation(tree, node, context),
-        Operation::BitwiseOr => bitwise::or_operation(tree, node, context),
-        Operation::BitwiseAnd => bitwise::and_operation(tree, node, context),
-        Operation::BitwiseXOR => bitwise::xor_operation(tree, node, context),
-        Operation::BitwiseLeftShift => bitwise::left_shift_operation(tree, node, context),
-        Operation::BitwiseRightShift => bitwise::right_shift_operation(tree, node, context),
-        Operation::GreaterThan => greater_than::operation(tree, node, context),
-        Operation::GreaterThanOrEqual => greater_than_equal::operation(tree, node, context),
-        Operation::LessThan => less_than::operation(tree, node, context),
-        Operation::LessThanOrEqual => less_than_equal::operation(tree, node, context),
-        Operation::Equality => equality::operation(tree, node, context),
-        Operation::Inequality => inequality::operation(tree, node, context),
-        Operation::InclusiveRange => range::inclusive_range_operation(tree, node, context),
-        Operation::ExclusiveRange => range::exclusive_range_operation(tree, node, context),
         Operation::Pair => pair::operation(tree, node, context),
         Operation::List => list::operation(tree, node, context),
         Operation::DotAccess => access::dot_access_operation(tree, node, context),
+        Operation::InterpretedAccess => access::interpreted_access_operation(tree, node, context),
         Operation::PipeFirstRight => pipe::pipe_first_right_operation(tree, node, context),
         Operation::PipeFirstLeft => pipe::pipe_first_left_operation(tree, node, context),
         Operation::PipeLastRight => pipe::pipe_last_right_operation(tree, node, context),
@@ -81,16 +97,99 @@ pub fn get_node_result(
         Operation::Group => group::operation(tree, node, context),
         Operation::AssociativeList => associative_list::operation(tree, node, context),
         Operation::Expression => expression::operation(tree, node, context),
-        Operation::KeysEqual => keys_equal::equal_operation(tree, node, context),
-        Operation::KeysNotEqual => keys_equal::not_equal_operation(tree, node, context),
-        Operation::ValuesEqual => values_equal::equal_operation(tree, node, context),
-        Operation::ValuesNotEqual => values_equal::not_equal_operation(tree, node, context),
-        Operation::Contains => contains::contains_operation(tree, node, context),
-        Operation::NotContains => contains::not_contains_operation(tree, node, context),
         Operation::MatchTrue => conditional::match_true(tree, node, context),
         Operation::MatchFalse => conditional::match_false(tree, node, context),
         Operation::MatchList => conditional::match_list(tree, node, context),
         Operation::Stream => stream::operation(tree, node, context),
+        Operation::Collect => collect::collect_operation(tree, node, context),
+        Operation::CollectInit => collect::collect_init_operation(tree, node, context),
+        Operation::Combine => combine::operation(tree, node, context),
+        Operation::PartialApplication => partial_application::operation(tree, node, context),
+        Operation::InfixCall => infix::operation(tree, node, context),
+        _ => SELExecutionResult::new(DataType::Unknown, None),
+    };
+}
+
+// operations whose result only depends on both of their evaluated operands
+// logical and and or are left out since their right side is evaluated lazily
+pub fn is_binary_operation(operation: Operation) -> bool {
+    return matches!(
+        operation,
+        Operation::Addition
+            | Operation::Subtraction
+            | Operation::Multiplication
+            | Operation::Division
+            | Operation::IntegerDivision
+            | Operation::Modulo
+            | Operation::Exponential
+            | Operation::LogicalXOR
+            | Operation::BitwiseOr
+            | Operation::BitwiseAnd
+            | Operation::BitwiseXOR
+            | Operation::BitwiseLeftShift
+            | Operation::BitwiseRightShift
+            | Operation::GreaterThan
+            | Operation::GreaterThanOrEqual
+            | Operation::LessThan
+            | Operation::LessThanOrEqual
+            | Operation::Equality
+            | Operation::Inequality
+            | Operation::InclusiveRange
+            | Operation::ExclusiveRange
+            | Operation::KeysEqual
+            | Operation::KeysNotEqual
+            | Operation::ValuesEqual
+            | Operation::ValuesNotEqual
+            | Operation::Contains
+            | Operation::NotContains
+    );
+}
+
+pub fn apply_binary(
+    node: &SELTreeNode,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
+) -> SELExecutionResult {
+    return match node.get_operation() {
+        Operation::Addition => addition::apply(node, left_result, right_result),
+        Operation::Subtraction => subtraction::apply(node, left_result, right_result),
+        Operation::Multiplication => multiplication::apply(node, left_result, right_result),
+        Operation::Division => division::apply_division(node, left_result, right_result),
+        Operation::IntegerDivision => {
+            division::apply_integer_division(node, left_result, right_result)
+        }
+        Operation::Modulo => modulo::apply(node, left_result, right_result),
+        Operation::Exponential => exponential::apply(node, left_result, right_result),
+        Operation::LogicalOr => logical::apply_or(node, left_result, right_result),
+        Operation::LogicalXOR => logical::apply_xor(node, left_result, right_result),
+        Operation::LogicalAnd => logical::apply_and(node, left_result, right_result),
+        Operation::BitwiseOr => bitwise::apply_or(node, left_result, right_result),
+        Operation::BitwiseAnd => bitwise::apply_and(node, left_result, right_result),
+        Operation::BitwiseXOR => bitwise::apply_xor(node, left_result, right_result),
+        Operation::BitwiseLeftShift => bitwise::apply_left_shift(node, left_result, right_result),
+        Operation::BitwiseRightShift => bitwise::apply_right_shift(node, left_result, right_result),
+        Operation::GreaterThan => greater_than::apply(node, left_result, right_result),
+        Operation::GreaterThanOrEqual => greater_than_equal::apply(node, left_result, right_result),
+        Operation::LessThan => less_than::apply(node, left_result, right_result),
+        Operation::LessThanOrEqual => less_than_equal::apply(node, left_result, right_result),
+        Operation::Equality => equality::apply(node, left_result, right_result),
+        Operation::Inequality => inequality::apply(node, left_result, right_result),
+        Operation::InclusiveRange => range::apply_inclusive(node, left_result, right_result),
+        Operation::ExclusiveRange => range::apply_exclusive(node, left_result, right_result),
+        Operation::KeysEqual => keys_equal::apply_equal(node, left_result, right_result),
+        Operation::KeysNotEqual => keys_equal::apply_not_equal(node, left_result, right_result),
+        Operation::ValuesEqual => values_equal::apply_equal(node, left_result, right_result),
+        Operation::ValuesNotEqual => values_equal::apply_not_equal(node, left_result, right_result),
+        Operation::Contains => contains::apply_contains(node, left_result, right_result),
+        Operation::NotContains => contains::apply_not_contains(node, left_result, right_result),
+        _ => SELExecutionResult::new(DataType::Unknown, None),
+    };
+}
+
+pub fn apply_unary(node: &SELTreeNode, result: SELExecutionResult) -> SELExecutionResult {
+    return match node.get_operation() {
+        Operation::Negation => negation::apply(node, result),
+        Operation::Not => logical_not::apply(node, result),
         _ => SELExecutionResult::new(DataType::Unknown, None),
     };
 }
@@ -99,6 +198,7 @@ pub fn get_node_result(
 pub mod test_utils {
     use super::super::context;
     use super::*;
+    use crate::tests::run_root;
     use sel_common::{DataHeap, DataType, Operation, SELContext, SELTree, SELTreeNode};
     use std::collections::HashMap;
 
@@ -151,8 +251,8 @@ pub mod test_utils {
             HashMap::new(),
         );
 
-        let mut context = context::SELExecutionContext::new();
+        let context = context::SELExecutionContext::new();
 
-        return get_node_result(&tree, tree.get_root(), &mut context);
+        return run_root(&tree, &context);
     }
 }
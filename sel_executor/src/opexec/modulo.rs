@@ -1,29 +1,41 @@
-use super::super::context::SELExecutionContext;
 use super::execution_result::SELExecutionResult;
-use super::utils::{match_math_ops, OptionOr};
-use sel_common::{DataType, SELTree, SELTreeNode};
+use super::utils::{match_math_ops, type_mismatch, OptionOr};
+use sel_common::sel_types::error::RuntimeErrorKind;
+use sel_common::SELTreeNode;
 
-pub fn operation(
-    tree: &SELTree,
+pub fn apply(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
     return match match_math_ops(
-        tree,
         node,
-        context,
-        |left, right| left % right,
-        |left, right| left % right,
+        left_result,
+        right_result,
+        |left, right| match right {
+            0 => Err(RuntimeErrorKind::DivideByZero),
+            _ => left.checked_rem(right).ok_or(RuntimeErrorKind::Overflow),
+        },
+        |left, right| {
+            if right == 0.0 {
+                Err(RuntimeErrorKind::DivideByZero)
+            } else {
+                Ok(left % right)
+            }
+        },
     ) {
         OptionOr::Some(result) => result,
-        OptionOr::Or(_) => SELExecutionResult::new(DataType::Unknown, Some(vec![])),
+        OptionOr::Or((left_result, right_result)) => {
+            type_mismatch(node, &[&left_result, &right_result])
+        }
     };
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::test_utils::result_of_binary_op;
-    use sel_common::{from_byte_vec, DataType, Operation};
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::{from_value_data, DataType, Operation};
 
     #[test]
     fn executes_integer_modulo() {
@@ -36,7 +48,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -55,7 +67,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -74,7 +86,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -93,7 +105,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -128,4 +140,20 @@ mod tests {
         assert_eq!(result.get_type(), DataType::Unit);
         assert_eq!(result.get_value(), None);
     }
+
+    #[test]
+    fn executes_modulo_by_zero() {
+        let result = result_of_binary_op(
+            Operation::Modulo,
+            DataType::Integer,
+            "10",
+            DataType::Integer,
+            "0",
+        );
+
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::DivideByZero);
+        assert_eq!(error.get_operation(), Operation::Modulo);
+    }
 }
@@ -1,29 +1,32 @@
-use super::super::context::SELExecutionContext;
-use super::utils::{match_math_ops, OptionOr};
+use super::utils::{match_math_ops, type_mismatch, OptionOr};
 use super::SELExecutionResult;
-use sel_common::{DataType, SELTree, SELTreeNode};
+use sel_common::sel_types::error::RuntimeErrorKind;
+use sel_common::SELTreeNode;
 
-pub fn operation(
-    tree: &SELTree,
+pub fn apply(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
     return match match_math_ops(
-        tree,
         node,
-        context,
-        |left, right| left * right,
-        |left, right| left * right,
+        left_result,
+        right_result,
+        |left, right| left.checked_mul(right).ok_or(RuntimeErrorKind::Overflow),
+        |left, right| Ok(left * right),
     ) {
         OptionOr::Some(result) => result,
-        OptionOr::Or(_) => SELExecutionResult::new(DataType::Unknown, Some(vec![])),
+        OptionOr::Or((left_result, right_result)) => {
+            type_mismatch(node, &[&left_result, &right_result])
+        }
     };
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::test_utils::result_of_binary_op;
-    use sel_common::{from_byte_vec, DataType, Operation};
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::{from_value_data, DataType, Operation};
 
     #[test]
     fn executes_integer_multiplication() {
@@ -36,7 +39,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -55,7 +58,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -74,7 +77,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -93,7 +96,7 @@ mod tests {
         );
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -128,4 +131,19 @@ mod tests {
         assert_eq!(result.get_type(), DataType::Unit);
         assert_eq!(result.get_value(), None);
     }
+
+    #[test]
+    fn executes_integer_multiplication_overflow() {
+        let result = result_of_binary_op(
+            Operation::Multiplication,
+            DataType::Integer,
+            "9223372036854775807",
+            DataType::Integer,
+            "2",
+        );
+
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::Overflow);
+    }
 }
@@ -1,47 +1,37 @@
-use super::super::context::SELExecutionContext;
-use super::{get_node_result, SELExecutionResult};
-use sel_common::{from_byte_vec, to_byte_vec, DataType, SELTree, SELTreeNode};
-
-pub fn operation(
-    tree: &SELTree,
-    node: &SELTreeNode,
-    context: &mut SELExecutionContext,
-) -> SELExecutionResult {
-    let right = tree.get_nodes().get(node.get_right().unwrap()).unwrap();
-    let result = get_node_result(tree, &right, context);
+use super::utils::{arithmetic_error, get_value_from_result, type_mismatch};
+use super::SELExecutionResult;
+use sel_common::sel_types::error::RuntimeErrorKind;
+use sel_common::{to_value_data, DataType, SELTreeNode};
 
+pub fn apply(node: &SELTreeNode, result: SELExecutionResult) -> SELExecutionResult {
     return match result.get_type() {
         DataType::Integer => {
-            let right_val: Option<i32> = match result.get_value() {
-                Some(value) => Some(from_byte_vec(value)),
-                None => None,
-            };
+            let right_val: i64 = get_value_from_result(&result);
 
-            let val = -(right_val.unwrap());
-
-            SELExecutionResult::new(DataType::Integer, Some(to_byte_vec(val)))
+            match right_val.checked_neg() {
+                Some(val) => SELExecutionResult::new(DataType::Integer, Some(to_value_data(val))),
+                None => arithmetic_error(RuntimeErrorKind::Overflow, node, &[&result]),
+            }
         }
         DataType::Decimal => {
-            let right_val: Option<f64> = match result.get_value() {
-                Some(value) => Some(from_byte_vec(value)),
-                None => None,
-            };
+            let right_val: f64 = get_value_from_result(&result);
 
-            let val = -(right_val.unwrap());
+            let val = -right_val;
 
-            SELExecutionResult::new(DataType::Decimal, Some(to_byte_vec(val)))
+            SELExecutionResult::new(DataType::Decimal, Some(to_value_data(val)))
         }
-        _ => SELExecutionResult::new(DataType::Unit, None),
+        DataType::Unit => SELExecutionResult::new(DataType::Unit, None),
+        _ => type_mismatch(node, &[&result]),
     };
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::get_node_result;
     use super::super::test_utils::result_of_binary_op;
-    use super::*;
+    use super::super::SELExecutionContext;
+    use crate::tests::run_root;
     use sel_common::{
-        from_byte_vec, DataHeap, DataType, Operation, SELContext, SELTree, SELTreeNode,
+        from_value_data, DataHeap, DataType, Operation, SELContext, SELTree, SELTreeNode,
     };
     use std::collections::HashMap;
 
@@ -80,12 +70,12 @@ mod tests {
             HashMap::new(),
         );
 
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -128,12 +118,12 @@ mod tests {
             HashMap::new(),
         );
 
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -1,10 +1,11 @@
-use sel_common::{to_byte_vec, DataType, SELTree, SELTreeNode};
+use sel_common::{to_value_data, DataType, SELTree, SELTreeNode};
 
-use super::utils::get_left_right_results;
+use super::utils::{first_error, get_left_right_results};
 
 use super::super::context::SELExecutionContext;
 use super::execution_result::SELExecutionResult;
 use sel_common::sel_types::pair::Pair;
+use sel_common::sel_types::symbol::Symbol;
 
 pub fn operation(
     tree: &SELTree,
@@ -12,7 +13,44 @@ pub fn operation(
     context: &mut SELExecutionContext,
 ) -> SELExecutionResult {
     let (left_result, right_result) = get_left_right_results(tree, node, context);
-    let bytes = to_byte_vec(Pair::new(
+
+    return apply(tree, node, left_result, right_result);
+}
+
+pub fn apply(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
+) -> SELExecutionResult {
+    if let Some(error) = first_error(&[&left_result, &right_result]) {
+        return error;
+    }
+
+    // unbound identifier on left is a key by name
+    // i.e. (num1 = 7) is the same as (:num1 = 7)
+    // identifiers only evaluate to unit when nothing binds them
+    let left_result = match node
+        .get_left()
+        .and_then(|left_index| tree.get_nodes().get(left_index))
+        .filter(|left_node| left_node.get_data_type() == DataType::Identifier)
+        .filter(|_| left_result.get_type() == DataType::Unit)
+        .and_then(|left_node| tree.get_usize_value_of(left_node))
+    {
+        Some(symbol_index) => SELExecutionResult::new(
+            DataType::Symbol,
+            Some(to_value_data(Symbol::new(
+                tree.get_symbol_table()
+                    .get_symbol(symbol_index)
+                    .cloned()
+                    .unwrap_or_default(),
+                symbol_index,
+            ))),
+        ),
+        None => left_result,
+    };
+
+    let bytes = to_value_data(Pair::new(
         left_result.get_sel_value().clone(),
         right_result.get_sel_value().clone(),
     ));
@@ -21,10 +59,10 @@ pub fn operation(
 
 #[cfg(test)]
 mod tests {
-    use sel_common::{from_byte_vec, DataType, SELContext};
+    use crate::tests::run_root;
+    use sel_common::{from_value_data, DataType, SELContext, SELValue};
     use sel_compiler::Compiler;
 
-    use super::super::get_node_result;
     use super::*;
     use sel_common::sel_types::symbol::Symbol;
 
@@ -33,16 +71,16 @@ mod tests {
         let compiler = Compiler::new();
         let context = SELContext::new();
         let tree = compiler.compile_with_context(&String::from(":value = 10"), context);
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
+        let result = run_root(&tree, &execution_context);
 
         assert_eq!(result.get_type(), DataType::Pair);
 
-        let pair: Pair = from_byte_vec(result.get_value().unwrap());
+        let pair: Pair = from_value_data(result.get_value().unwrap());
 
-        let left_value: Symbol = from_byte_vec(pair.get_left().get_value().unwrap());
-        let right_value: i64 = from_byte_vec(pair.get_right().get_value().unwrap());
+        let left_value: Symbol = from_value_data(pair.get_left().get_value().unwrap());
+        let right_value: i64 = from_value_data(pair.get_right().get_value().unwrap());
 
         assert_eq!(pair.get_left().get_type(), DataType::Symbol);
         assert_eq!(left_value.get_identifier(), &String::from("value"));
@@ -50,4 +88,47 @@ mod tests {
         assert_eq!(pair.get_right().get_type(), DataType::Integer);
         assert_eq!(right_value, 10);
     }
+
+    #[test]
+    fn executes_pair_with_identifier_key() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile_with_context(&String::from("value = 10"), SELContext::new());
+        let execution_context = SELExecutionContext::new();
+
+        let result = run_root(&tree, &execution_context);
+
+        let pair: Pair = from_value_data(result.get_value().unwrap());
+        let left_value: Symbol = from_value_data(pair.get_left().get_value().unwrap());
+
+        assert_eq!(pair.get_left().get_type(), DataType::Symbol);
+        assert_eq!(left_value.get_identifier(), &String::from("value"));
+    }
+
+    #[test]
+    fn executes_pair_with_bound_identifier_key() {
+        let compiler = Compiler::new();
+        let mut context = SELContext::new();
+        context.set_integer_symbol(&String::from("value"), 5);
+
+        let tree = compiler.compile_with_context(&String::from("value = 10"), context);
+        let mut execution_context = SELExecutionContext::new();
+        execution_context.set_value("other", SELValue::new_from_int(6));
+
+        let result = run_root(&tree, &execution_context);
+
+        let pair: Pair = from_value_data(result.get_value().unwrap());
+        let left_value: i64 = from_value_data(pair.get_left().get_value().unwrap());
+
+        assert_eq!(pair.get_left().get_type(), DataType::Integer);
+        assert_eq!(left_value, 5);
+
+        let tree = compiler.compile(&String::from("other = 10"));
+
+        let result = run_root(&tree, &execution_context);
+
+        let pair: Pair = from_value_data(result.get_value().unwrap());
+        let left_value: i64 = from_value_data(pair.get_left().get_value().unwrap());
+
+        assert_eq!(left_value, 6);
+    }
 }
@@ -0,0 +1,223 @@
+use super::call::call_by_name;
+use super::utils::type_mismatch;
+use super::{get_node_result, SELExecutionContext, SELExecutionResult};
+use sel_common::sel_types::partial_application::PartialApplication;
+use sel_common::{from_value_data, DataType, SELTree, SELTreeNode, SELValue};
+
+// calls function of a partial application with its arguments
+pub fn call_partial(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    partial: &PartialApplication,
+    context: &SELExecutionContext,
+) -> SELExecutionResult {
+    return call_by_name(
+        tree,
+        node,
+        partial.get_function(),
+        partial.get_call_value(),
+        context,
+    );
+}
+
+// partial application being applied or called, if result is one
+pub fn get_partial(result: &SELExecutionResult) -> Option<PartialApplication> {
+    return match result.get_type() {
+        DataType::PartialApplication => Some(from_value_data(result.get_value().unwrap())),
+        _ => None,
+    };
+}
+
+pub fn operation(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    context: &mut SELExecutionContext,
+) -> SELExecutionResult {
+    let left_node = match node
+        .get_left()
+        .and_then(|left_index| tree.get_nodes().get(left_index))
+    {
+        Some(left_node) => left_node,
+        None => return SELExecutionResult::new(DataType::Unknown, None),
+    };
+
+    // identifier on left is the function being applied
+    // otherwise left should result in a partial application
+    // i.e. clamp ~ (1) ~ (2)
+    let base = if left_node.get_data_type() == DataType::Identifier {
+        match tree
+            .get_usize_value_of(left_node)
+            .and_then(|symbol_index| tree.get_symbol_table().get_symbol(symbol_index))
+        {
+            Some(symbol) => PartialApplication::new(symbol),
+            None => return SELExecutionResult::new(DataType::Unknown, None),
+        }
+    } else {
+        let left_result = get_node_result(tree, left_node, context);
+
+        match get_partial(&left_result) {
+            Some(partial) => partial,
+            None => return type_mismatch(node, &[&left_result]),
+        }
+    };
+
+    // right side is the group of arguments
+    let arguments = match node
+        .get_right()
+        .and_then(|right_index| tree.get_nodes().get(right_index))
+    {
+        Some(right_node) => get_node_result(tree, right_node, context),
+        None => SELExecutionResult::from(&SELValue::new()),
+    };
+
+    if arguments.is_error() {
+        return arguments;
+    }
+
+    return SELExecutionResult::from(&SELValue::new_from_partial_application(
+        base.apply(arguments.get_sel_value()),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::opexec::execution_result::SELExecutionResult;
+    use crate::tests::run;
+    use crate::SELExecutionContext;
+    use sel_common::sel_types::associative_list::AssociativeList;
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::sel_types::list::List;
+    use sel_common::sel_types::partial_application::PartialApplication;
+    use sel_common::{from_value_data, DataType, SELContext, SELValue, SymbolTable};
+    use sel_compiler::Compiler;
+
+    // clamp value between min and max
+    // given as (value, min, max) or with named min and max
+    fn clamp(arguments: SELValue, symbol_table: &SymbolTable) -> SELValue {
+        let (values, named): (Vec<SELValue>, Option<AssociativeList>) = match arguments.get_type() {
+            DataType::List => {
+                let list: List = from_value_data(arguments.get_value().unwrap());
+                (list.get_values().clone(), None)
+            }
+            DataType::AssociativeList => {
+                let list: AssociativeList = from_value_data(arguments.get_value().unwrap());
+                (list.get_list().get_values().clone(), Some(list))
+            }
+            _ => (vec![arguments], None),
+        };
+
+        let int_at = |index: usize| -> Option<i64> {
+            values
+                .get(index)
+                .filter(|value| value.get_type() == DataType::Integer)
+                .map(|value| from_value_data(value.get_value().unwrap()))
+        };
+
+        let named_int = |name: &str| -> Option<i64> {
+            named.as_ref().and_then(|list| {
+                symbol_table
+                    .get_value(&String::from(name))
+                    .and_then(|symbol_index| list.get_by_association_index(*symbol_index))
+                    .map(|value| from_value_data(value.get_value().unwrap()))
+            })
+        };
+
+        let value = int_at(0).unwrap_or(0);
+        let min = named_int("min").or_else(|| int_at(1)).unwrap_or(i64::MIN);
+        let max = named_int("max").or_else(|| int_at(2)).unwrap_or(i64::MAX);
+
+        return SELValue::new_from_int(value.max(min).min(max));
+    }
+
+    fn results_of(s: &str) -> Vec<SELExecutionResult> {
+        let compiler = Compiler::new();
+        let mut context = SELContext::new();
+        context.register_function("clamp", clamp);
+
+        let tree = compiler.compile_with_context(&String::from(s), context);
+
+        return run(&tree, &SELExecutionContext::from(tree.get_context()));
+    }
+
+    fn int_result(s: &str) -> i64 {
+        let results = results_of(s);
+        let result = results.last().unwrap();
+
+        assert_eq!(result.get_type(), DataType::Integer);
+
+        return from_value_data(result.get_value().unwrap());
+    }
+
+    #[test]
+    fn creates_partial_application() {
+        let results = results_of("clamp ~ (10)");
+        let result = results.get(0).unwrap();
+
+        assert_eq!(result.get_type(), DataType::PartialApplication);
+
+        let partial: PartialApplication = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(partial.get_function(), "clamp");
+        assert_eq!(partial.get_arguments().len(), 1);
+    }
+
+    #[test]
+    fn calls_with_group() {
+        assert_eq!(int_result("clamp ~ (10)\n?(1, 5)"), 5);
+    }
+
+    #[test]
+    fn calls_with_skipped_slot() {
+        assert_eq!(int_result("clamp ~ (, 5, 10)\n?(20)"), 10);
+    }
+
+    #[test]
+    fn calls_with_named_argument() {
+        assert_eq!(int_result("clamp ~ (:min = 5)\n?(1)"), 5);
+    }
+
+    #[test]
+    fn applies_again() {
+        assert_eq!(int_result("clamp ~ (, 5) ~ (, 15)\n?(20)"), 15);
+    }
+
+    #[test]
+    fn calls_with_pipe_first_right() {
+        assert_eq!(int_result("1 -> clamp ~ (, 5, 10)"), 5);
+    }
+
+    #[test]
+    fn calls_with_pipe_first_left() {
+        assert_eq!(int_result("clamp ~ (, 5, 10) <- 20"), 10);
+    }
+
+    #[test]
+    fn calls_with_pipe_last_right() {
+        assert_eq!(int_result("10 |> clamp ~ (1, 5)"), 5);
+    }
+
+    #[test]
+    fn calls_with_pipe_last_left() {
+        assert_eq!(int_result("clamp ~ (1, 5) <| 10"), 5);
+    }
+
+    #[test]
+    fn calls_unknown_function() {
+        let results = results_of("other ~ (10)\n?(1)");
+
+        assert_eq!(
+            results.last().unwrap().get_error().unwrap().get_kind(),
+            RuntimeErrorKind::UnknownFunction
+        );
+    }
+
+    #[test]
+    fn applies_non_partial() {
+        let results = results_of("5 ~ (10)");
+
+        assert_eq!(
+            results.get(0).unwrap().get_error().unwrap().get_kind(),
+            RuntimeErrorKind::TypeMismatch
+        );
+    }
+}
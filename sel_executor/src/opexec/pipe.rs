@@ -1,10 +1,12 @@
 use super::SELExecutionContext;
+use crate::opexec::call::call_identifier;
 use crate::opexec::execution_result::SELExecutionResult;
 use crate::opexec::get_node_result;
+use crate::opexec::partial_application::{call_partial, get_partial};
 use crate::opexec::utils::get_value_from_result;
 use sel_common::sel_types::associative_list::AssociativeList;
 use sel_common::sel_types::list::List;
-use sel_common::{from_byte_vec, DataType, Operation, SELTree, SELTreeNode, SELValue};
+use sel_common::{DataType, Operation, SELTree, SELTreeNode, SELValue, ValueData};
 
 fn pipe_operation(
     tree: &SELTree,
@@ -20,6 +22,11 @@ fn pipe_operation(
         .and_then(|left_index| tree.get_nodes().get(left_index))
         .map(|left_node| get_node_result(tree, left_node, context))
         .map(|left_result| {
+            // errors are not piped into expressions
+            if left_result.is_error() {
+                return left_result;
+            }
+
             let value = left_result.get_sel_value();
 
             // get right node
@@ -46,19 +53,7 @@ fn pipe_operation(
                         }
                         (_, DataType::Identifier) => {
                             // identifier will be of an exposed function
-                            // first get node value
-                            tree.get_usize_value_of(right_node)
-                                .and_then(|function_identifier_index| {
-                                    tree.get_symbol_table()
-                                        .get_symbol(function_identifier_index)
-                                })
-                                .and_then(|function_symbol| context.get_function(function_symbol))
-                                .map(|func| {
-                                    SELExecutionResult::from(&func(
-                                        value.clone(),
-                                        tree.get_symbol_table(),
-                                    ))
-                                })
+                            call_identifier(tree, node, right_node, value.clone(), context)
                         }
                         (Operation::Group, _) => {
                             // first get value of group
@@ -86,8 +81,10 @@ fn pipe_operation(
                                     // we will extend it with arg value
                                     match value.get_type() {
                                         DataType::List => {
-                                            let mut pipe_list: List =
-                                                from_byte_vec(value.get_value().unwrap());
+                                            let mut pipe_list = value
+                                                .get_value()
+                                                .and_then(ValueData::as_list)
+                                                .map_or(List::new(), List::clone);
 
                                             if first {
                                                 pipe_list.append(&mut list);
@@ -105,50 +102,53 @@ fn pipe_operation(
                                             }
                                         }
                                     }
-                                    println!("list {:?}", list);
 
                                     let mut func_sel_value = SELValue::new_from_list(list.clone());
-                                    println!("value {:?}", func_sel_value);
 
                                     // if there are any pairs in list
                                     // promote to associative array for function call
                                     for value in list.get_values() {
                                         if value.get_type() == DataType::Pair {
                                             func_sel_value = SELValue::new_from_associative_list(
-                                                AssociativeList::from(list),
+                                                AssociativeList::from(&list),
                                             );
                                             break;
                                         }
                                     }
 
-                                    println!("value {:?}", func_sel_value);
-
                                     // use list as value to group's left side function
                                     right_node
                                         .get_left()
                                         .and_then(|left_index| tree.get_nodes().get(left_index))
                                         .and_then(|left_node| {
-                                            tree.get_usize_value_of(left_node)
-                                                .and_then(|function_identifier_index| {
-                                                    tree.get_symbol_table()
-                                                        .get_symbol(function_identifier_index)
-                                                })
-                                                .and_then(|function_symbol| {
-                                                    context.get_function(function_symbol)
-                                                })
-                                                .map(|func| {
-                                                    SELExecutionResult::from(&func(
-                                                        func_sel_value,
-                                                        tree.get_symbol_table(),
-                                                    ))
-                                                })
+                                            call_identifier(
+                                                tree,
+                                                node,
+                                                left_node,
+                                                func_sel_value,
+                                                context,
+                                            )
                                         })
                                         .unwrap_or(SELExecutionResult::new(DataType::Unknown, None))
                                 })
                         }
                         _ => {
                             // get result of right node
-                            Some(get_node_result(tree, right_node, &mut pipe_context))
+                            let result = get_node_result(tree, right_node, &mut pipe_context);
+
+                            // partial application results are called with pipe value
+                            match get_partial(&result) {
+                                Some(partial) => {
+                                    let applied = if first {
+                                        partial.apply(value)
+                                    } else {
+                                        partial.apply_last(value)
+                                    };
+
+                                    Some(call_partial(tree, node, &applied, context))
+                                }
+                                None => Some(result),
+                            }
                         }
                     }
                 })
@@ -206,21 +206,21 @@ pub fn pipe_last_left_operation(
 #[cfg(test)]
 mod tests {
     use crate::opexec::execution_result::SELExecutionResult;
-    use crate::opexec::get_node_result;
+    use crate::tests::run_root;
     use crate::SELExecutionContext;
     use sel_common::sel_types::associative_list::AssociativeList;
     use sel_common::sel_types::list::List;
-    use sel_common::{from_byte_vec, to_byte_vec, DataType, SELContext, SELValue};
+    use sel_common::{from_value_data, to_value_data, DataType, SELContext, SELValue};
     use sel_compiler::Compiler;
 
     #[test]
     fn executes_pipe_first_right_raw_expression() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("10 -> $ * 10"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 100);
@@ -231,10 +231,10 @@ mod tests {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("10 -> { $ * 10 }"));
 
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 100);
@@ -248,7 +248,7 @@ mod tests {
         context.register_function("is_even", |sel_value, symbol_table| {
             match sel_value.get_type() {
                 DataType::Integer => {
-                    let value: i64 = from_byte_vec(sel_value.get_value().unwrap());
+                    let value: i64 = from_value_data(sel_value.get_value().unwrap());
                     let result = value % 2 == 0;
 
                     SELValue::new_from_boolean(result)
@@ -257,12 +257,12 @@ mod tests {
             }
         });
 
-        let mut execution_context = SELExecutionContext::from(&context);
+        let execution_context = SELExecutionContext::from(&context);
 
         let tree = compiler.compile_with_context(&String::from("10 -> is_even"), context);
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, true);
@@ -276,13 +276,13 @@ mod tests {
         context.register_function("middle", |sel_value, symbol_table| {
             match sel_value.get_type() {
                 DataType::List => {
-                    let list: List = from_byte_vec(sel_value.get_value().unwrap());
+                    let list: List = from_value_data(sel_value.get_value().unwrap());
 
                     let first_value: i64 =
-                        from_byte_vec(list.get_values().get(0).unwrap().get_value().unwrap());
+                        from_value_data(list.get_values().get(0).unwrap().get_value().unwrap());
 
                     let second_value: i64 =
-                        from_byte_vec(list.get_values().get(1).unwrap().get_value().unwrap());
+                        from_value_data(list.get_values().get(1).unwrap().get_value().unwrap());
 
                     let value: i64 = (second_value - first_value) / 2 + first_value;
 
@@ -292,12 +292,12 @@ mod tests {
             }
         });
 
-        let mut execution_context = SELExecutionContext::from(&context);
+        let execution_context = SELExecutionContext::from(&context);
 
         let tree = compiler.compile_with_context(&String::from("10 -> middle(20)"), context);
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 15);
@@ -311,14 +311,14 @@ mod tests {
         context.register_function("avg", |sel_value, symbol_table| {
             match sel_value.get_type() {
                 DataType::List => {
-                    let list: List = from_byte_vec(sel_value.get_value().unwrap());
+                    let list: List = from_value_data(sel_value.get_value().unwrap());
 
                     let mut result = 1;
 
                     let total = list
                         .get_values()
                         .iter()
-                        .map(|sel_value| from_byte_vec::<i64>(sel_value.get_value().unwrap()))
+                        .map(|sel_value| from_value_data::<i64>(sel_value.get_value().unwrap()))
                         .fold(0 as i64, |result, i| i + result);
 
                     let avg = total / list.get_values().len() as i64;
@@ -329,12 +329,12 @@ mod tests {
             }
         });
 
-        let mut execution_context = SELExecutionContext::from(&context);
+        let execution_context = SELExecutionContext::from(&context);
 
         let tree = compiler.compile_with_context(&String::from("10 -> avg(20, 30)"), context);
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 20);
@@ -348,12 +348,12 @@ mod tests {
         context.register_function("avg", |sel_value, symbol_table| {
             match sel_value.get_type() {
                 DataType::List => {
-                    let list: List = from_byte_vec(sel_value.get_value().unwrap());
+                    let list: List = from_value_data(sel_value.get_value().unwrap());
 
                     let total = list
                         .get_values()
                         .iter()
-                        .map(|sel_value| from_byte_vec::<i64>(sel_value.get_value().unwrap()))
+                        .map(|sel_value| from_value_data::<i64>(sel_value.get_value().unwrap()))
                         .fold(0 as i64, |result, i| i + result);
 
                     let avg = total / list.get_values().len() as i64;
@@ -364,12 +364,12 @@ mod tests {
             }
         });
 
-        let mut execution_context = SELExecutionContext::from(&context);
+        let execution_context = SELExecutionContext::from(&context);
 
         let tree = compiler.compile_with_context(&String::from("10, 20 -> avg(30)"), context);
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 20);
@@ -383,9 +383,9 @@ mod tests {
         context.register_function("middle", |sel_value, symbol_table| {
             match sel_value.get_type() {
                 DataType::AssociativeList => {
-                    let args: AssociativeList = from_byte_vec(sel_value.get_value().unwrap());
+                    let args: AssociativeList = from_value_data(sel_value.get_value().unwrap());
 
-                    let first_value: i64 = from_byte_vec(
+                    let first_value: i64 = from_value_data(
                         args.get_by_association_index(
                             *symbol_table.get_value(&String::from("lower")).unwrap(),
                         )
@@ -394,7 +394,7 @@ mod tests {
                         .unwrap(),
                     );
 
-                    let second_value: i64 = from_byte_vec(
+                    let second_value: i64 = from_value_data(
                         args.get_by_association_index(
                             *symbol_table.get_value(&String::from("upper")).unwrap(),
                         )
@@ -411,13 +411,13 @@ mod tests {
             }
         });
 
-        let mut execution_context = SELExecutionContext::from(&context);
+        let execution_context = SELExecutionContext::from(&context);
 
         let tree = compiler
             .compile_with_context(&String::from(":lower = 10 -> middle(:upper = 20)"), context);
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 15);
@@ -431,13 +431,13 @@ mod tests {
         context.register_function("middle", |sel_value, symbol_table| {
             match sel_value.get_type() {
                 DataType::List => {
-                    let list: List = from_byte_vec(sel_value.get_value().unwrap());
+                    let list: List = from_value_data(sel_value.get_value().unwrap());
 
                     let first_value: i64 =
-                        from_byte_vec(list.get_values().get(0).unwrap().get_value().unwrap());
+                        from_value_data(list.get_values().get(0).unwrap().get_value().unwrap());
 
                     let second_value: i64 =
-                        from_byte_vec(list.get_values().get(1).unwrap().get_value().unwrap());
+                        from_value_data(list.get_values().get(1).unwrap().get_value().unwrap());
 
                     assert_eq!(first_value, 10);
                     assert_eq!(second_value, 20);
@@ -450,12 +450,12 @@ mod tests {
             }
         });
 
-        let mut execution_context = SELExecutionContext::from(&context);
+        let execution_context = SELExecutionContext::from(&context);
 
         let tree = compiler.compile_with_context(&String::from("20 |> middle(10)"), context);
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 15);
@@ -469,12 +469,12 @@ mod tests {
         context.register_function("avg", |sel_value, symbol_table| {
             match sel_value.get_type() {
                 DataType::List => {
-                    let list: List = from_byte_vec(sel_value.get_value().unwrap());
+                    let list: List = from_value_data(sel_value.get_value().unwrap());
 
                     let ints: Vec<i64> = list
                         .get_values()
                         .iter()
-                        .map(|sel_value| from_byte_vec::<i64>(sel_value.get_value().unwrap()))
+                        .map(|sel_value| from_value_data::<i64>(sel_value.get_value().unwrap()))
                         .collect();
 
                     assert_eq!(*ints.get(0).unwrap(), 10);
@@ -491,12 +491,12 @@ mod tests {
             }
         });
 
-        let mut execution_context = SELExecutionContext::from(&context);
+        let execution_context = SELExecutionContext::from(&context);
 
         let tree = compiler.compile_with_context(&String::from("20, 30 |> avg(10)"), context);
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 20);
@@ -510,12 +510,12 @@ mod tests {
         context.register_function("avg", |sel_value, symbol_table| {
             match sel_value.get_type() {
                 DataType::List => {
-                    let list: List = from_byte_vec(sel_value.get_value().unwrap());
+                    let list: List = from_value_data(sel_value.get_value().unwrap());
 
                     let ints: Vec<i64> = list
                         .get_values()
                         .iter()
-                        .map(|sel_value| from_byte_vec::<i64>(sel_value.get_value().unwrap()))
+                        .map(|sel_value| from_value_data::<i64>(sel_value.get_value().unwrap()))
                         .collect();
 
                     assert_eq!(*ints.get(0).unwrap(), 10);
@@ -532,12 +532,12 @@ mod tests {
             }
         });
 
-        let mut execution_context = SELExecutionContext::from(&context);
+        let execution_context = SELExecutionContext::from(&context);
 
         let tree = compiler.compile_with_context(&String::from("avg(30) <- 10, 20"), context);
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 20);
@@ -551,12 +551,12 @@ mod tests {
         context.register_function("avg", |sel_value, symbol_table| {
             match sel_value.get_type() {
                 DataType::List => {
-                    let list: List = from_byte_vec(sel_value.get_value().unwrap());
+                    let list: List = from_value_data(sel_value.get_value().unwrap());
 
                     let ints: Vec<i64> = list
                         .get_values()
                         .iter()
-                        .map(|sel_value| from_byte_vec::<i64>(sel_value.get_value().unwrap()))
+                        .map(|sel_value| from_value_data::<i64>(sel_value.get_value().unwrap()))
                         .collect();
 
                     assert_eq!(*ints.get(0).unwrap(), 10);
@@ -573,12 +573,12 @@ mod tests {
             }
         });
 
-        let mut execution_context = SELExecutionContext::from(&context);
+        let execution_context = SELExecutionContext::from(&context);
 
         let tree = compiler.compile_with_context(&String::from("avg(10) <| 20, 30"), context);
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: i64 = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: i64 = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Integer);
         assert_eq!(value, 20);
@@ -1,19 +1,17 @@
-use sel_common::{to_byte_vec, DataType, SELTree, SELTreeNode};
+use sel_common::{to_value_data, DataType, SELTreeNode};
 
-use crate::opexec::utils::get_values_from_results;
+use crate::opexec::utils::{arithmetic_error, get_values_from_results, type_mismatch};
+use sel_common::sel_types::error::RuntimeErrorKind;
 
 use super::execution_result::SELExecutionResult;
-use super::utils::get_left_right_results;
-use super::SELExecutionContext;
 use sel_common::sel_types::range::Range;
 
 fn range_operation(
-    tree: &SELTree,
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
     inclusive: bool,
 ) -> SELExecutionResult {
-    let (left_result, right_result) = get_left_right_results(tree, node, context);
     return match (left_result.get_type(), right_result.get_type()) {
         (DataType::Integer, DataType::Integer) => {
             let (left_val, right_val) =
@@ -21,38 +19,52 @@ fn range_operation(
 
             // internally, all ranges will be exclusive
             // so we simply add 1 to give upper bound to make inclusive of that number
-            let right_val = if inclusive { right_val + 1 } else { right_val };
-
-            let range = Range::new(left_val, right_val);
-
-            SELExecutionResult::new(DataType::Range, Some(to_byte_vec(range)))
+            let right_val = if inclusive {
+                right_val.checked_add(1)
+            } else {
+                Some(right_val)
+            };
+
+            match right_val {
+                Some(right_val) => {
+                    let range = Range::new(left_val, right_val);
+
+                    SELExecutionResult::new(DataType::Range, Some(to_value_data(range)))
+                }
+                None => arithmetic_error(
+                    RuntimeErrorKind::Overflow,
+                    node,
+                    &[&left_result, &right_result],
+                ),
+            }
         }
-        _ => SELExecutionResult::new(DataType::Unknown, None),
+        _ => type_mismatch(node, &[&left_result, &right_result]),
     };
 }
 
-pub fn exclusive_range_operation(
-    tree: &SELTree,
+pub fn apply_exclusive(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
-    return range_operation(tree, node, context, false);
+    return range_operation(node, left_result, right_result, false);
 }
 
-pub fn inclusive_range_operation(
-    tree: &SELTree,
+pub fn apply_inclusive(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
-    return range_operation(tree, node, context, true);
+    return range_operation(node, left_result, right_result, true);
 }
 
 #[cfg(test)]
 mod tests {
-    use sel_common::{from_byte_vec, DataType};
+    use crate::tests::run;
+    use sel_common::{from_value_data, DataType};
     use sel_compiler::Compiler;
 
-    use super::super::super::execute_sel_tree;
+    use super::super::SELExecutionContext;
     use super::*;
 
     #[test]
@@ -60,12 +72,12 @@ mod tests {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("5..10"));
 
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let results = execute_sel_tree(&tree, &mut context);
+        let results = run(&tree, &context);
 
         let first_result = results.get(0).unwrap();
-        let range: Range = from_byte_vec(first_result.get_value().unwrap());
+        let range: Range = from_value_data(first_result.get_value().unwrap());
 
         assert_eq!(first_result.get_type(), DataType::Range);
         assert_eq!(range.get_lower(), 5);
@@ -77,12 +89,12 @@ mod tests {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("5...10"));
 
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let results = execute_sel_tree(&tree, &mut context);
+        let results = run(&tree, &context);
 
         let first_result = results.get(0).unwrap();
-        let range: Range = from_byte_vec(first_result.get_value().unwrap());
+        let range: Range = from_value_data(first_result.get_value().unwrap());
 
         assert_eq!(first_result.get_type(), DataType::Range);
         assert_eq!(range.get_lower(), 5);
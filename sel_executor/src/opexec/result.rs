@@ -17,7 +17,7 @@ pub fn operation(
             Some(input) => SELExecutionResult::new(
                 input.get_type(),
                 match input.get_value() {
-                    Some(value) => Some(std::vec::Vec::from(value.as_slice())),
+                    Some(value) => Some(value.clone()),
                     None => None,
                 },
             ),
@@ -28,8 +28,9 @@ pub fn operation(
 
 #[cfg(test)]
 mod tests {
-    use super::super::{get_node_result, SELExecutionContext};
-    use sel_common::{from_byte_vec, DataType, SELValue};
+    use super::super::SELExecutionContext;
+    use crate::tests::run_root;
+    use sel_common::{from_value_data, DataType, SELValue};
     use sel_compiler::Compiler;
 
     #[test]
@@ -43,10 +44,10 @@ mod tests {
 
         context.set_input(input);
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -59,9 +60,9 @@ mod tests {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("?"));
 
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         assert_eq!(result.get_type(), DataType::Unit);
         assert_eq!(result.get_value(), None);
@@ -1,10 +1,132 @@
+use crate::opexec::combine::CombinedValues;
 use crate::opexec::execution_result::SELExecutionResult;
 use crate::opexec::get_node_result;
+use crate::opexec::utils::make_named_list;
 use crate::SELExecutionContext;
+use sel_common::sel_types::error::SELRuntimeError;
 use sel_common::sel_types::list::List;
-use sel_common::sel_types::stream::SELStream;
+use sel_common::sel_types::stream::{SELStream, SELStreamIterator};
 use sel_common::sel_types::stream_instruction::StreamInstruction;
-use sel_common::{from_byte_vec, to_byte_vec, DataType, SELTree, SELTreeNode};
+use sel_common::{
+    from_value_data, to_value_data, DataType, Operation, SELTree, SELTreeNode, SELValue,
+};
+
+// values produced by a stream
+// each source value is passed to the processor as [value, index]
+pub struct StreamValues<'a> {
+    tree: &'a SELTree,
+    processor: Option<usize>,
+    source: Box<dyn Iterator<Item = SELValue> + 'a>,
+    context: SELExecutionContext,
+    index: usize,
+    stopped: bool,
+}
+
+impl<'a> StreamValues<'a> {
+    pub fn new(tree: &'a SELTree, stream: &SELStream, context: &SELExecutionContext) -> Self {
+        return StreamValues {
+            tree,
+            processor: stream.get_processor_root(),
+            source: stream_source(tree, stream.get_source(), context),
+            context: context.clone(),
+            index: 0,
+            stopped: false,
+        };
+    }
+}
+
+impl<'a> Iterator for StreamValues<'a> {
+    type Item = SELValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stopped {
+            return None;
+        }
+
+        let value = self.source.next()?;
+        let index = self.index;
+        self.index += 1;
+
+        // a stopped evaluation ends the stream with its error
+        if let Err((kind, message)) = self.context.check_periodically(index) {
+            self.stopped = true;
+
+            return Some(SELValue::new_from_error(SELRuntimeError::new(
+                kind,
+                Operation::Stream,
+                vec![],
+                message,
+            )));
+        }
+
+        // errors end up as stream values
+        // processor isn't run for them
+        if value.is_error() {
+            return Some(value);
+        }
+
+        return match self
+            .processor
+            .and_then(|processor| self.tree.get_nodes().get(processor))
+        {
+            Some(processor_node) => {
+                let input = make_named_list(
+                    self.tree,
+                    vec![
+                        ("value", value),
+                        ("index", SELValue::new_from_int(index as i64)),
+                    ],
+                );
+
+                let mut processor_context = self.context.clone();
+                processor_context.set_input(input);
+
+                Some(
+                    get_node_result(self.tree, processor_node, &mut processor_context)
+                        .get_sel_value()
+                        .clone(),
+                )
+            }
+            None => Some(value),
+        };
+    }
+}
+
+// results of a value as a stream
+// non stream values are produced as a single result
+pub fn stream_results<'a>(
+    tree: &'a SELTree,
+    value: &SELValue,
+    context: &SELExecutionContext,
+) -> Box<dyn Iterator<Item = SELExecutionResult> + 'a> {
+    return match value.get_type() {
+        DataType::Stream => Box::new(
+            stream_source(tree, value, context).map(|value| SELExecutionResult::from(&value)),
+        ),
+        _ => Box::new(std::iter::once(SELExecutionResult::from(value))),
+    };
+}
+
+// iterator over values of a streamable value
+// streams will have their processors run over their source values
+pub fn stream_source<'a>(
+    tree: &'a SELTree,
+    source: &SELValue,
+    context: &SELExecutionContext,
+) -> Box<dyn Iterator<Item = SELValue> + 'a> {
+    return match source.get_type() {
+        DataType::Stream => {
+            let stream: SELStream = from_value_data(source.get_value().unwrap());
+
+            if stream.is_combined() {
+                Box::new(CombinedValues::new(tree, &stream, context))
+            } else {
+                Box::new(StreamValues::new(tree, &stream, context))
+            }
+        }
+        _ => Box::new(SELStreamIterator::new(source.clone())),
+    };
+}
 
 pub fn operation(
     tree: &SELTree,
@@ -21,7 +143,7 @@ pub fn operation(
             node.get_right().map(|right_index| {
                 let stream = SELStream::new(left_result.get_sel_value().to_owned(), right_index);
 
-                SELExecutionResult::new(DataType::Stream, Some(to_byte_vec(stream)))
+                SELExecutionResult::new(DataType::Stream, Some(to_value_data(stream)))
             })
         })
         .unwrap_or(SELExecutionResult::new(DataType::Unknown, None))
@@ -29,26 +151,28 @@ pub fn operation(
 
 #[cfg(test)]
 mod tests {
-    use crate::opexec::get_node_result;
-    use crate::{execute_sel_tree, SELExecutionContext};
+    use crate::tests::run_root;
+    use crate::{execute_stream, SELExecutionContext};
+    use sel_common::sel_types::list::List;
     use sel_common::sel_types::stream::SELStream;
     use sel_common::sel_types::stream_instruction::StreamInstruction;
-    use sel_common::{from_byte_vec, DataType};
+    use sel_common::{from_value_data, DataType, SELContext, SELValue};
     use sel_compiler::Compiler;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[test]
     fn executes_stream_of_list() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("10, 20, 30 >>> $"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
+        let result = run_root(&tree, &execution_context);
 
         assert_eq!(result.get_type(), DataType::Stream);
 
-        let stream: SELStream = from_byte_vec(result.get_value().unwrap());
+        let stream: SELStream = from_value_data(result.get_value().unwrap());
 
-        assert_eq!(stream.get_processor_root(), 7);
+        assert_eq!(stream.get_processor_root(), Some(6));
 
         let mut stream_iter = stream.iter();
 
@@ -57,18 +181,118 @@ mod tests {
         let result_3 = stream_iter.next().unwrap();
         let result_4 = stream_iter.next();
 
-        let value_1: i64 = from_byte_vec(result_1.get_value().unwrap());
+        let value_1: i64 = from_value_data(result_1.get_value().unwrap());
         assert_eq!(result_1.get_type(), DataType::Integer);
         assert_eq!(value_1, 10);
 
-        let value_2: i64 = from_byte_vec(result_2.get_value().unwrap());
+        let value_2: i64 = from_value_data(result_2.get_value().unwrap());
         assert_eq!(result_2.get_type(), DataType::Integer);
         assert_eq!(value_2, 20);
 
-        let value_3: i64 = from_byte_vec(result_3.get_value().unwrap());
+        let value_3: i64 = from_value_data(result_3.get_value().unwrap());
         assert_eq!(result_3.get_type(), DataType::Integer);
         assert_eq!(value_3, 30);
 
         assert!(result_4.is_none());
     }
+
+    fn stream_ints(s: &str, input: SELValue) -> Vec<i64> {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from(s));
+
+        let mut context = SELExecutionContext::new();
+        context.set_input(input);
+
+        return execute_stream(&tree, &context)
+            .map(|result| from_value_data(result.get_value().unwrap()))
+            .collect();
+    }
+
+    #[test]
+    fn stream_processor_receives_value() {
+        assert_eq!(
+            stream_ints("(10, 20, 30) >>> $.value / 10", SELValue::new()),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn stream_processor_receives_index() {
+        assert_eq!(
+            stream_ints("(10, 20, 30) >>> $.index", SELValue::new()),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn streams_range() {
+        assert_eq!(
+            stream_ints("1..4 >>> $.value * $.value", SELValue::new()),
+            vec![1, 4, 9]
+        );
+    }
+
+    #[test]
+    fn streams_associative_list() {
+        assert_eq!(
+            stream_ints("[1, 2, 3] >>> $.value + 1", SELValue::new()),
+            vec![2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn streams_string() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("\"abc\" >>> $.value"));
+
+        let values: Vec<String> = execute_stream(&tree, &SELExecutionContext::new())
+            .map(|result| from_value_data(result.get_value().unwrap()))
+            .collect();
+
+        assert_eq!(values, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn streams_stream() {
+        assert_eq!(
+            stream_ints("$ >>> $.value * 2 >>> $.value + 1", {
+                let mut list = List::new();
+                list.push(SELValue::new_from_int(1));
+                list.push(SELValue::new_from_int(2));
+                SELValue::new_from_list(list)
+            }),
+            vec![3, 5]
+        );
+    }
+
+    #[test]
+    fn streams_lazily() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let compiler = Compiler::new();
+        let mut context = SELContext::new();
+        context.register_function("count", |sel_value, _symbol_table| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            sel_value
+        });
+
+        let execution_context = SELExecutionContext::from(&context);
+        let tree =
+            compiler.compile_with_context(&String::from("1..100 >>> count($.value)"), context);
+
+        let mut results = execute_stream(&tree, &execution_context);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+        let first: i64 = from_value_data(results.next().unwrap().get_value().unwrap());
+        let second: i64 = from_value_data(results.next().unwrap().get_value().unwrap());
+
+        assert_eq!((first, second), (1, 2));
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn streams_non_stream_result_once() {
+        assert_eq!(stream_ints("5 + 10", SELValue::new()), vec![15]);
+    }
 }
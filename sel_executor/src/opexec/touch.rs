@@ -1,13 +1,39 @@
 use super::super::context::SELExecutionContext;
 use super::execution_result::SELExecutionResult;
+use super::utils::missing_operand;
 use sel_common::sel_types::symbol::Symbol;
-use sel_common::{to_byte_vec, DataType, SELTree, SELTreeNode};
+use sel_common::{to_value_data, DataType, SELTree, SELTreeNode};
 
 pub fn operation(
     tree: &SELTree,
     node: &SELTreeNode,
-    _context: &mut SELExecutionContext,
+    context: &mut SELExecutionContext,
 ) -> SELExecutionResult {
+    return identifier_result(tree, node, context).unwrap_or_else(|| touch_result(tree, node));
+}
+
+// value given to the evaluation for an identifier
+// None for other nodes or identifiers without one
+pub fn identifier_result(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    context: &SELExecutionContext,
+) -> Option<SELExecutionResult> {
+    if node.get_data_type() != DataType::Identifier {
+        return None;
+    }
+
+    return tree
+        .get_usize_value_of(node)
+        .and_then(|index| tree.get_symbol_table().get_symbol(index))
+        .and_then(|identifier| context.get_value(identifier))
+        .map(SELExecutionResult::from);
+}
+
+// touched values only depend on the tree
+// so they can be resolved ahead of execution
+// except for identifiers given values by the execution context
+pub fn touch_result(tree: &SELTree, node: &SELTreeNode) -> SELExecutionResult {
     return match node.get_data_type() {
         DataType::Unit => SELExecutionResult::new(DataType::Unit, None),
         DataType::Identifier => tree
@@ -16,17 +42,20 @@ pub fn operation(
             .map_or(SELExecutionResult::new(DataType::Unit, None), |value| {
                 SELExecutionResult::from(value)
             }),
-        DataType::Symbol => {
-            let value = tree.get_usize_value_of(node).unwrap();
-            let identifier = tree.get_symbol_table().get_symbol(value).unwrap();
-
-            SELExecutionResult::new(
+        DataType::Symbol => match tree.get_usize_value_of(node).and_then(|value| {
+            tree.get_symbol_table()
+                .get_symbol(value)
+                .map(|identifier| (value, identifier))
+        }) {
+            Some((value, identifier)) => SELExecutionResult::new(
                 DataType::Symbol,
-                Some(to_byte_vec(Symbol::new(identifier.clone(), value))),
-            )
-        }
+                Some(to_value_data(Symbol::new(identifier.clone(), value))),
+            ),
+            // ':' without a name
+            None => missing_operand(node),
+        },
         DataType::Integer | DataType::Decimal | DataType::String | DataType::Boolean => {
-            SELExecutionResult::new(node.get_data_type(), tree.get_value_bytes_of(node))
+            SELExecutionResult::new(node.get_data_type(), tree.get_value_data_of(node))
         }
         _ => SELExecutionResult::new(DataType::Unknown, None),
     };
@@ -34,11 +63,12 @@ pub fn operation(
 
 #[cfg(test)]
 mod tests {
-    use super::super::get_node_result;
     use super::*;
+    use crate::tests::run_root;
     use sel_common::{
-        from_byte_vec, DataHeap, DataType, Operation, SELContext, SELTree, SELTreeNode,
+        from_value_data, DataHeap, DataType, Operation, SELContext, SELTree, SELTreeNode,
     };
+    use sel_common::sel_types::error::RuntimeErrorKind;
     use sel_compiler::Compiler;
     use std::collections::HashMap;
 
@@ -60,9 +90,9 @@ mod tests {
             HashMap::new(),
         );
 
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         assert_eq!(result.get_type(), DataType::Unit);
         assert_eq!(result.get_value(), None);
@@ -94,12 +124,12 @@ mod tests {
             HashMap::new(),
         );
 
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -133,12 +163,12 @@ mod tests {
             HashMap::new(),
         );
 
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -172,12 +202,12 @@ mod tests {
             HashMap::new(),
         );
 
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -211,12 +241,12 @@ mod tests {
             HashMap::new(),
         );
 
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -228,24 +258,38 @@ mod tests {
     fn executes_symbol_touch() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from(":value"));
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
-        let symbol: Symbol = from_byte_vec(result.get_value().unwrap());
+        let symbol: Symbol = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Symbol);
         assert_eq!(symbol.get_identifier(), &String::from("value"));
         assert_eq!(symbol.get_table_index(), 0);
     }
 
+    #[test]
+    fn executes_symbol_without_name() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from(":"));
+        let context = SELExecutionContext::new();
+
+        let result = run_root(&tree, &context);
+
+        assert_eq!(
+            result.get_error().unwrap().get_kind(),
+            RuntimeErrorKind::MissingOperand
+        );
+    }
+
     #[test]
     fn executes_identifier_touch() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("value"));
-        let mut context = SELExecutionContext::new();
+        let context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut context);
+        let result = run_root(&tree, &context);
 
         // identifiers with no context value always yield unit
         assert_eq!(result.get_type(), DataType::Unit);
@@ -259,12 +303,12 @@ mod tests {
         context.set_integer_symbol(&String::from("value"), 10);
 
         let tree = compiler.compile_with_context(&String::from("value"), context);
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
+        let result = run_root(&tree, &execution_context);
 
         let result_value = match result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
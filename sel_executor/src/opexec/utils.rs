@@ -1,9 +1,14 @@
 use super::{get_node_result, SELExecutionContext, SELExecutionResult};
+use sel_common::sel_types::associative_list::AssociativeList;
+use sel_common::sel_types::error::{RuntimeErrorKind, SELRuntimeError};
+use sel_common::sel_types::pair::Pair;
+use sel_common::sel_types::symbol::Symbol;
 use sel_common::{
-    from_byte_vec, to_byte_vec, DataType, FromByteVec, SELTree, SELTreeNode, ToByteVec,
+    from_value_data, to_value_data, DataType, FromValueData, SELTree, SELTreeNode, SELValue,
+    ToValueData,
 };
 
-pub fn get_values_from_results<L: FromByteVec, R: FromByteVec>(
+pub fn get_values_from_results<L: FromValueData, R: FromValueData>(
     left: &SELExecutionResult,
     right: &SELExecutionResult,
 ) -> (L, R) {
@@ -12,9 +17,9 @@ pub fn get_values_from_results<L: FromByteVec, R: FromByteVec>(
     return (left_val, right_val);
 }
 
-pub fn get_value_from_result<T: FromByteVec>(result: &SELExecutionResult) -> T {
+pub fn get_value_from_result<T: FromValueData>(result: &SELExecutionResult) -> T {
     let val: Option<T> = match result.get_value() {
-        Some(value) => Some(from_byte_vec(value)),
+        Some(value) => Some(from_value_data(value)),
         None => None,
     };
 
@@ -26,24 +31,147 @@ pub fn get_left_right_results(
     node: &SELTreeNode,
     context: &mut SELExecutionContext,
 ) -> (SELExecutionResult, SELExecutionResult) {
-    let left = tree.get_nodes().get(node.get_left().unwrap()).unwrap();
-    let right = tree.get_nodes().get(node.get_right().unwrap()).unwrap();
-
     return (
-        get_node_result(tree, &left, context),
-        get_node_result(tree, &right, context),
+        get_operand_result(tree, node, node.get_left(), context),
+        get_operand_result(tree, node, node.get_right(), context),
     );
 }
 
+// operand left out of a malformed tree is an error instead of a panic
+pub fn get_operand_result(
+    tree: &SELTree,
+    node: &SELTreeNode,
+    operand: Option<usize>,
+    context: &mut SELExecutionContext,
+) -> SELExecutionResult {
+    return match operand.and_then(|index| tree.get_nodes().get(index)) {
+        Some(operand_node) => get_node_result(tree, operand_node, context),
+        None => missing_operand(node),
+    };
+}
+
+pub fn missing_operand(node: &SELTreeNode) -> SELExecutionResult {
+    return runtime_error(
+        RuntimeErrorKind::MissingOperand,
+        node,
+        &[],
+        format!("{:?} is missing an operand", node.get_operation()),
+    );
+}
+
+// errors in operands are passed along as is
+// instead of creating a new error for the current operation
+pub fn first_error(results: &[&SELExecutionResult]) -> Option<SELExecutionResult> {
+    return results
+        .iter()
+        .find(|result| result.is_error())
+        .map(|result| (*result).clone());
+}
+
+pub fn runtime_error(
+    kind: RuntimeErrorKind,
+    node: &SELTreeNode,
+    results: &[&SELExecutionResult],
+    message: String,
+) -> SELExecutionResult {
+    let operand_types: Vec<DataType> = results.iter().map(|result| result.get_type()).collect();
+
+    return SELExecutionResult::error(SELRuntimeError::new(
+        kind,
+        node.get_operation(),
+        operand_types,
+        message,
+    ));
+}
+
+// evaluation is aborted by a limit of the execution context
+pub fn limit_exceeded(node: &SELTreeNode, message: String) -> SELExecutionResult {
+    return runtime_error(RuntimeErrorKind::LimitExceeded, node, &[], message);
+}
+
+// evaluation is aborted by its step limit, cancellation or deadline
+pub fn evaluation_stopped(
+    node: &SELTreeNode,
+    (kind, message): (RuntimeErrorKind, String),
+) -> SELExecutionResult {
+    return runtime_error(kind, node, &[], message);
+}
+
+pub fn arithmetic_error(
+    kind: RuntimeErrorKind,
+    node: &SELTreeNode,
+    results: &[&SELExecutionResult],
+) -> SELExecutionResult {
+    let message = match kind {
+        RuntimeErrorKind::DivideByZero => String::from("cannot divide by zero"),
+        RuntimeErrorKind::Overflow => String::from("integer overflow"),
+        _ => format!("{}", kind),
+    };
+
+    return runtime_error(kind, node, results, message);
+}
+
+pub fn unknown_function(
+    node: &SELTreeNode,
+    name: &String,
+    argument: &SELValue,
+) -> SELExecutionResult {
+    return runtime_error(
+        RuntimeErrorKind::UnknownFunction,
+        node,
+        &[&SELExecutionResult::from(argument)],
+        format!("no function named '{}'", name),
+    );
+}
+
+pub fn type_mismatch(node: &SELTreeNode, results: &[&SELExecutionResult]) -> SELExecutionResult {
+    return first_error(results).unwrap_or_else(|| {
+        let types: Vec<String> = results
+            .iter()
+            .map(|result| format!("{}", result.get_type()))
+            .collect();
+
+        runtime_error(
+            RuntimeErrorKind::TypeMismatch,
+            node,
+            results,
+            format!(
+                "cannot apply {:?} to {}",
+                node.get_operation(),
+                types.join(" and ")
+            ),
+        )
+    });
+}
+
+// makes an associative list with values keyed by given names
+// names not found in the tree's symbol table were never referenced
+// so their values are only accessible by index
+pub fn make_named_list(tree: &SELTree, values: Vec<(&str, SELValue)>) -> SELValue {
+    let mut list = AssociativeList::new();
+
+    for (name, value) in values {
+        match tree.get_symbol_table().get_value(&String::from(name)) {
+            Some(symbol_index) => list.push(SELValue::new_from_pair(Pair::new(
+                SELValue::new_from_symbol(Symbol::new(String::from(name), *symbol_index)),
+                value,
+            ))),
+            None => list.push(value),
+        }
+    }
+
+    return SELValue::new_from_associative_list(list);
+}
+
 pub enum OptionOr<T, V> {
     Some(T),
     Or(V),
 }
 
 fn match_int_dec_ops<FI, FF, RI, RF, FU, RU>(
-    tree: &SELTree,
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
     integer_func: FI,
     float_func: FF,
     integer_type: DataType,
@@ -51,65 +179,62 @@ fn match_int_dec_ops<FI, FF, RI, RF, FU, RU>(
     unit_func: FU,
 ) -> OptionOr<SELExecutionResult, (SELExecutionResult, SELExecutionResult)>
 where
-    FI: Fn(i64, i64) -> RI,
-    FF: Fn(f64, f64) -> RF,
-    RI: ToByteVec,
-    RF: ToByteVec,
+    FI: Fn(i64, i64) -> Result<RI, RuntimeErrorKind>,
+    FF: Fn(f64, f64) -> Result<RF, RuntimeErrorKind>,
+    RI: ToValueData,
+    RF: ToValueData,
     FU: Fn(bool, bool) -> (DataType, Option<RU>),
-    RU: ToByteVec,
+    RU: ToValueData,
 {
-    let (left_result, right_result) = get_left_right_results(tree, node, context);
+    // let caller propagate errors
+    if left_result.is_error() || right_result.is_error() {
+        return OptionOr::Or((left_result, right_result));
+    }
+
+    let operands = [&left_result, &right_result];
 
     return match (left_result.get_type(), right_result.get_type()) {
         (DataType::Integer, DataType::Integer) => {
             let (left_val, right_val) =
                 get_values_from_results::<i64, i64>(&left_result, &right_result);
 
-            let result = integer_func(left_val, right_val);
-
-            OptionOr::Some(SELExecutionResult::new(
-                integer_type,
-                Some(to_byte_vec(result)),
-            ))
+            OptionOr::Some(match integer_func(left_val, right_val) {
+                Ok(result) => SELExecutionResult::new(integer_type, Some(to_value_data(result))),
+                Err(kind) => arithmetic_error(kind, node, &operands),
+            })
         }
         (DataType::Integer, DataType::Decimal) => {
             let (left_val, right_val) =
                 get_values_from_results::<i64, f64>(&left_result, &right_result);
 
-            let result = float_func(left_val as f64, right_val);
-
-            OptionOr::Some(SELExecutionResult::new(
-                float_type,
-                Some(to_byte_vec(result)),
-            ))
+            OptionOr::Some(match float_func(left_val as f64, right_val) {
+                Ok(result) => SELExecutionResult::new(float_type, Some(to_value_data(result))),
+                Err(kind) => arithmetic_error(kind, node, &operands),
+            })
         }
         (DataType::Decimal, DataType::Integer) => {
             let (left_val, right_val) =
                 get_values_from_results::<f64, i64>(&left_result, &right_result);
 
-            let result = float_func(left_val, right_val as f64);
-
-            OptionOr::Some(SELExecutionResult::new(
-                float_type,
-                Some(to_byte_vec(result)),
-            ))
+            OptionOr::Some(match float_func(left_val, right_val as f64) {
+                Ok(result) => SELExecutionResult::new(float_type, Some(to_value_data(result))),
+                Err(kind) => arithmetic_error(kind, node, &operands),
+            })
         }
         (DataType::Decimal, DataType::Decimal) => {
             let (left_val, right_val) =
                 get_values_from_results::<f64, f64>(&left_result, &right_result);
 
-            let result = float_func(left_val, right_val);
-
-            OptionOr::Some(SELExecutionResult::new(
-                float_type,
-                Some(to_byte_vec(result)),
-            ))
+            OptionOr::Some(match float_func(left_val, right_val) {
+                Ok(result) => SELExecutionResult::new(float_type, Some(to_value_data(result))),
+                Err(kind) => arithmetic_error(kind, node, &operands),
+            })
         }
         (DataType::Unit, DataType::Unit) => {
             let (data_type, value) = unit_func(true, true);
 
             let bytes = match value {
-                Some(v) => Some(to_byte_vec(v)),
+                Some(v) => Some(to_value_data(v)),
                 None => None,
             };
 
@@ -119,7 +244,7 @@ where
             let (data_type, value) = unit_func(false, true);
 
             let bytes = match value {
-                Some(v) => Some(to_byte_vec(v)),
+                Some(v) => Some(to_value_data(v)),
                 None => None,
             };
 
@@ -129,7 +254,7 @@ where
             let (data_type, value) = unit_func(true, false);
 
             let bytes = match value {
-                Some(v) => Some(to_byte_vec(v)),
+                Some(v) => Some(to_value_data(v)),
                 None => None,
             };
 
@@ -140,22 +265,22 @@ where
 }
 
 pub fn match_math_ops<FI, FF, RI, RF>(
-    tree: &SELTree,
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
     integer_func: FI,
     float_func: FF,
 ) -> OptionOr<SELExecutionResult, (SELExecutionResult, SELExecutionResult)>
 where
-    FI: Fn(i64, i64) -> RI,
-    FF: Fn(f64, f64) -> RF,
-    RI: ToByteVec,
-    RF: ToByteVec,
+    FI: Fn(i64, i64) -> Result<RI, RuntimeErrorKind>,
+    FF: Fn(f64, f64) -> Result<RF, RuntimeErrorKind>,
+    RI: ToValueData,
+    RF: ToValueData,
 {
     return match_int_dec_ops(
-        tree,
         node,
-        context,
+        left_result,
+        right_result,
         integer_func,
         float_func,
         DataType::Integer,
@@ -165,21 +290,21 @@ where
 }
 
 pub fn match_int_math_ops<FI, FF, R>(
-    tree: &SELTree,
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
     integer_func: FI,
     float_func: FF,
 ) -> OptionOr<SELExecutionResult, (SELExecutionResult, SELExecutionResult)>
 where
-    FI: Fn(i64, i64) -> R,
-    FF: Fn(f64, f64) -> R,
-    R: ToByteVec,
+    FI: Fn(i64, i64) -> Result<R, RuntimeErrorKind>,
+    FF: Fn(f64, f64) -> Result<R, RuntimeErrorKind>,
+    R: ToValueData,
 {
     return match_int_dec_ops(
-        tree,
         node,
-        context,
+        left_result,
+        right_result,
         integer_func,
         float_func,
         DataType::Integer,
@@ -189,9 +314,9 @@ where
 }
 
 pub fn match_comparison_ops<FI, FF, FS>(
-    tree: &SELTree,
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
     integer_func: FI,
     float_func: FF,
     string_func: FS,
@@ -202,11 +327,11 @@ where
     FS: Fn(&String, &String) -> bool,
 {
     return match match_int_dec_ops(
-        tree,
         node,
-        context,
-        integer_func,
-        float_func,
+        left_result,
+        right_result,
+        |left, right| Ok(integer_func(left, right)),
+        |left, right| Ok(float_func(left, right)),
         DataType::Boolean,
         DataType::Boolean,
         |_l, _r| -> (DataType, Option<bool>) { (DataType::Unit, None) },
@@ -219,17 +344,17 @@ where
 
                 let result = string_func(&left_val, &right_val);
 
-                SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(result)))
+                SELExecutionResult::new(DataType::Boolean, Some(to_value_data(result)))
             }
-            _ => SELExecutionResult::new(DataType::Unknown, Some(vec![])),
+            _ => type_mismatch(node, &[&left, &right]),
         },
     };
 }
 
 pub fn match_equality_ops<FI, FF, FS, FU>(
-    tree: &SELTree,
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
     integer_func: FI,
     float_func: FF,
     string_func: FS,
@@ -242,11 +367,11 @@ where
     FU: Fn(bool, bool) -> (DataType, Option<bool>),
 {
     return match match_int_dec_ops(
-        tree,
         node,
-        context,
-        integer_func,
-        float_func,
+        left_result,
+        right_result,
+        |left, right| Ok(integer_func(left, right)),
+        |left, right| Ok(float_func(left, right)),
         DataType::Boolean,
         DataType::Boolean,
         unit_func,
@@ -259,9 +384,9 @@ where
 
                 let result = string_func(&left_val, &right_val);
 
-                SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(result)))
+                SELExecutionResult::new(DataType::Boolean, Some(to_value_data(result)))
             }
-            _ => SELExecutionResult::new(DataType::Unknown, Some(vec![])),
+            _ => type_mismatch(node, &[&left, &right]),
         },
     };
 }
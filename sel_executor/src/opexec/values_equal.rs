@@ -1,17 +1,14 @@
-use super::super::context::SELExecutionContext;
 use crate::opexec::execution_result::SELExecutionResult;
-use crate::opexec::utils::{get_left_right_results, get_values_from_results, match_equality_ops};
+use crate::opexec::utils::{get_values_from_results, type_mismatch};
 use sel_common::sel_types::associative_list::AssociativeList;
-use sel_common::{to_byte_vec, DataType, SELTree, SELTreeNode, SELValue};
+use sel_common::{to_value_data, DataType, SELTreeNode, SELValue};
 
 fn values_equal_operation(
-    tree: &SELTree,
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
     invert: bool,
 ) -> SELExecutionResult {
-    let (left_result, right_result) = get_left_right_results(tree, node, context);
-
     return match (left_result.get_type(), right_result.get_type()) {
         (DataType::List, DataType::List) => {
             let mut equal = left_result.get_value() == right_result.get_value();
@@ -20,7 +17,7 @@ fn values_equal_operation(
                 equal = !equal;
             }
 
-            SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(equal)))
+            SELExecutionResult::new(DataType::Boolean, Some(to_value_data(equal)))
         }
         (DataType::AssociativeList, DataType::AssociativeList) => {
             let (left_value, right_value) = get_values_from_results::<
@@ -66,34 +63,34 @@ fn values_equal_operation(
                 all_equal = !all_equal;
             }
 
-            SELExecutionResult::new(DataType::Boolean, Some(to_byte_vec(all_equal)))
+            SELExecutionResult::new(DataType::Boolean, Some(to_value_data(all_equal)))
         }
-        _ => SELExecutionResult::new(DataType::Unknown, None),
+        _ => type_mismatch(node, &[&left_result, &right_result]),
     };
 }
 
-pub fn equal_operation(
-    tree: &SELTree,
+pub fn apply_equal(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
-    return values_equal_operation(tree, node, context, false);
+    return values_equal_operation(node, left_result, right_result, false);
 }
 
-pub fn not_equal_operation(
-    tree: &SELTree,
+pub fn apply_not_equal(
     node: &SELTreeNode,
-    context: &mut SELExecutionContext,
+    left_result: SELExecutionResult,
+    right_result: SELExecutionResult,
 ) -> SELExecutionResult {
-    return values_equal_operation(tree, node, context, true);
+    return values_equal_operation(node, left_result, right_result, true);
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::test_utils::result_of_binary_op;
-    use crate::opexec::get_node_result;
+    use crate::tests::run_root;
     use crate::SELExecutionContext;
-    use sel_common::{from_byte_vec, to_byte_vec, DataType, Operation};
+    use sel_common::{from_value_data, to_value_data, DataType, Operation};
     use sel_compiler::Compiler;
 
     #[test]
@@ -102,10 +99,10 @@ mod tests {
         let tree = compiler.compile(&String::from(
             "[:email = \"panda@example.com\", :username = \"panda\"] $= [:email = \"panda@example.com\", :username = \"panda\"]",
         ));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, true);
@@ -117,10 +114,10 @@ mod tests {
         let tree = compiler.compile(&String::from(
             "[:email = \"panda@example.com\", :username = \"panda\"] $= [:email = \"polar@example.com\", :username = \"polar\"]",
         ));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, false);
@@ -132,10 +129,10 @@ mod tests {
         let tree = compiler.compile(&String::from(
             "[:email = \"panda@example.com\", :username = \"panda\"] $!= [:email = \"panda@example.com\", :username = \"panda\"]",
         ));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, false);
@@ -147,10 +144,10 @@ mod tests {
         let tree = compiler.compile(&String::from(
             "[:email = \"panda@example.com\", :username = \"panda\"] $!= [:email = \"polar@example.com\", :username = \"polar\"]",
         ));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, true);
@@ -160,10 +157,10 @@ mod tests {
     fn executes_list_values_equal_true() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(10, 20, 30) $= (10, 20, 30)"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, true);
@@ -173,10 +170,10 @@ mod tests {
     fn executes_list_values_equal_false() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(10, 30, 40) $= (10, 20, 30)"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, false);
@@ -186,10 +183,10 @@ mod tests {
     fn executes_list_values_not_equal_false() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(10, 20, 30) $!= (10, 20, 30)"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, false);
@@ -199,10 +196,10 @@ mod tests {
     fn executes_list_values_not_equal_true() {
         let compiler = Compiler::new();
         let tree = compiler.compile(&String::from("(10, 30, 40) $!= (10, 20, 30)"));
-        let mut execution_context = SELExecutionContext::new();
+        let execution_context = SELExecutionContext::new();
 
-        let result = get_node_result(&tree, tree.get_root(), &mut execution_context);
-        let value: bool = from_byte_vec(result.get_value().unwrap());
+        let result = run_root(&tree, &execution_context);
+        let value: bool = from_value_data(result.get_value().unwrap());
 
         assert_eq!(result.get_type(), DataType::Boolean);
         assert_eq!(value, true);
@@ -0,0 +1,121 @@
+use crate::context::SELExecutionContext;
+use crate::execute_sel_tree;
+use crate::opexec::execution_result::SELExecutionResult;
+use crate::opexec::get_node_result;
+use sel_common::test_case::TestCase;
+use sel_common::{DataType, SELTree, SELValue, Span, ValueData};
+
+// outcome of a single @Test expression
+#[derive(Clone, Debug)]
+pub struct TestResult {
+    span: Span,
+    result: SELExecutionResult,
+}
+
+impl TestResult {
+    // span of the test's annotation
+    pub fn get_span(&self) -> Span {
+        return self.span;
+    }
+
+    pub fn get_result(&self) -> &SELExecutionResult {
+        return &self.result;
+    }
+
+    // only a boolean true passes
+    pub fn is_passed(&self) -> bool {
+        return matches!(self.result.get_value(), Some(ValueData::Boolean(true)));
+    }
+}
+
+// context with the test's mocks, used for every evaluation of the test
+// an error result if arguments of a mock are an error
+// arguments that are not a name, result and input list mock a unit result
+fn mocked_context(
+    tree: &SELTree,
+    test: &TestCase,
+    context: &SELExecutionContext,
+) -> Result<SELExecutionContext, SELExecutionResult> {
+    let mut mocked = context.clone();
+
+    for mock in test
+        .get_mocks()
+        .iter()
+        .filter_map(|index| tree.get_mocks().get(*index))
+    {
+        let arguments = match tree.get_nodes().get(mock.get_arguments()) {
+            Some(node) => get_node_result(tree, node, &mut context.start_evaluation()),
+            None => continue,
+        };
+
+        if arguments.is_error() {
+            return Err(arguments);
+        }
+
+        // name, result and optional input
+        let values: Vec<SELValue> = match arguments.get_value() {
+            Some(ValueData::List(list)) => list.get_values().clone(),
+            _ => vec![],
+        };
+
+        mocked.mock_function(
+            mock.get_name(),
+            values.get(1).cloned().unwrap_or_else(SELValue::new),
+            values.get(2).cloned(),
+        );
+    }
+
+    return Ok(mocked);
+}
+
+fn run_test(tree: &SELTree, test: &TestCase, context: &SELExecutionContext) -> SELExecutionResult {
+    let context = match mocked_context(tree, test, context) {
+        Ok(context) => context,
+        Err(error) => return error,
+    };
+
+    let mut test_context = context.start_evaluation();
+
+    // value given to the annotation is input of the file's expressions
+    // and their last result is the input of the test
+    if let Some(input) = test
+        .get_input()
+        .and_then(|input| tree.get_nodes().get(input))
+    {
+        let input = get_node_result(tree, input, &mut context.start_evaluation());
+
+        if input.is_error() {
+            return input;
+        }
+
+        let mut file_context = context.clone();
+        file_context.set_input(input.get_sel_value().clone());
+
+        let output = execute_sel_tree(tree, &file_context)
+            .pop()
+            .unwrap_or(SELExecutionResult::new(DataType::Unit, None));
+
+        if output.is_error() {
+            return output;
+        }
+
+        test_context.set_input(output.get_sel_value().clone());
+    }
+
+    return match tree.get_nodes().get(test.get_root()) {
+        Some(root) => get_node_result(tree, root, &mut test_context),
+        None => SELExecutionResult::new(DataType::Unknown, None),
+    };
+}
+
+// runs every test of the tree in order of the file
+pub fn run_tests(tree: &SELTree, context: &SELExecutionContext) -> Vec<TestResult> {
+    return tree
+        .get_tests()
+        .iter()
+        .map(|test| TestResult {
+            span: test.get_span(),
+            result: run_test(tree, test, context),
+        })
+        .collect();
+}
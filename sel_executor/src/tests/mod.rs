@@ -1,7 +1,38 @@
+use crate::opexec::execution_result::SELExecutionResult;
+use crate::{execute_program, execute_sel_tree, lower_sel_tree, SELExecutionContext};
+use sel_common::{DataType, SELTree, ValueData};
+
+// executor tests run through here so their assertions hold for both backends
+pub fn run(tree: &SELTree, context: &SELExecutionContext) -> Vec<SELExecutionResult> {
+    let walked = execute_sel_tree(tree, context);
+    let run = execute_program(&lower_sel_tree(tree), context);
+
+    assert_eq!(
+        values_of(&run),
+        values_of(&walked),
+        "bytecode results differ from tree results"
+    );
+
+    return walked;
+}
+
+// result of the tree's first expression on both backends
+pub fn run_root(tree: &SELTree, context: &SELExecutionContext) -> SELExecutionResult {
+    return run(tree, context).remove(0);
+}
+
+fn values_of(results: &[SELExecutionResult]) -> Vec<(DataType, Option<ValueData>)> {
+    return results
+        .iter()
+        .map(|result| (result.get_type(), result.get_value().cloned()))
+        .collect();
+}
+
 mod tree_execution {
-    use super::super::execute_sel_tree;
+    use super::super::execute_stream;
     use super::super::SELExecutionContext;
-    use sel_common::{from_byte_vec, DataHeap, DataType, SELContext, SELTree};
+    use super::run;
+    use sel_common::{from_value_data, DataHeap, DataType, SELContext, SELTree};
     use sel_compiler::Compiler;
     use std::collections::HashMap;
 
@@ -22,7 +53,7 @@ mod tree_execution {
 
         let context = SELExecutionContext::new();
 
-        let results = execute_sel_tree(&tree, &context);
+        let results = run(&tree, &context);
 
         let result = results.get(0).unwrap();
 
@@ -37,11 +68,11 @@ mod tree_execution {
 
         let context = SELExecutionContext::new();
 
-        let results = execute_sel_tree(&tree, &context);
+        let results = run(&tree, &context);
 
         let first_result = results.get(0).unwrap();
         let first_result_value = match first_result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -50,7 +81,7 @@ mod tree_execution {
 
         let second_result = results.get(1).unwrap();
         let second_result_value = match second_result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -65,11 +96,11 @@ mod tree_execution {
 
         let context = SELExecutionContext::new();
 
-        let results = execute_sel_tree(&tree, &context);
+        let results = run(&tree, &context);
 
         let first_result = results.get(0).unwrap();
         let first_result_value = match first_result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
@@ -78,11 +109,1222 @@ mod tree_execution {
 
         let second_result = results.get(1).unwrap();
         let second_result_value = match second_result.get_value() {
-            Some(value) => Some(from_byte_vec(value)),
+            Some(value) => Some(from_value_data(value)),
             None => None,
         };
 
         assert_eq!(second_result.get_type(), DataType::Integer);
         assert_eq!(second_result_value, Some(35));
     }
+
+    #[test]
+    fn stream_last_expression() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("5 + 10\n(1, 2, 3) >>> $.value + ?"));
+
+        let context = SELExecutionContext::new();
+
+        let values: Vec<i64> = execute_stream(&tree, &context)
+            .map(|result| from_value_data(result.get_value().unwrap()))
+            .collect();
+
+        assert_eq!(values, vec![16, 17, 18]);
+    }
+}
+
+mod runtime_errors {
+    use super::super::SELExecutionContext;
+    use super::run;
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::{DataType, Operation};
+    use sel_compiler::Compiler;
+
+    #[test]
+    fn error_propagates_through_operations() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("(10 / 0) * 2 + 5"));
+
+        let context = SELExecutionContext::new();
+
+        let results = run(&tree, &context);
+
+        let result = results.get(0).unwrap();
+        let error = result.get_error().unwrap();
+
+        assert_eq!(result.get_type(), DataType::Error);
+        assert_eq!(error.get_kind(), RuntimeErrorKind::DivideByZero);
+        assert_eq!(error.get_operation(), Operation::Division);
+    }
+
+    #[test]
+    fn error_propagates_through_current_result() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("10 + \"a\" - 1\n? * 2"));
+
+        let context = SELExecutionContext::new();
+
+        let results = run(&tree, &context);
+
+        let second_result = results.get(1).unwrap();
+        let error = second_result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::TypeMismatch);
+        assert_eq!(error.get_operation(), Operation::Subtraction);
+        assert_eq!(
+            error.get_operand_types(),
+            &vec![DataType::String, DataType::Integer]
+        );
+    }
+
+    #[test]
+    fn pipe_to_unknown_function() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("10 -> fetch"));
+
+        let context = SELExecutionContext::new();
+
+        let results = run(&tree, &context);
+
+        let result = results.get(0).unwrap();
+        let error = result.get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::UnknownFunction);
+        assert_eq!(error.get_operation(), Operation::PipeFirstRight);
+        assert_eq!(error.get_message(), "no function named 'fetch'");
+    }
+
+    #[test]
+    fn unit_is_not_an_error() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("() + 5"));
+
+        let context = SELExecutionContext::new();
+
+        let results = run(&tree, &context);
+
+        let result = results.get(0).unwrap();
+
+        assert_eq!(result.get_type(), DataType::Unit);
+        assert!(!result.is_error());
+    }
+
+    #[test]
+    fn error_display() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("5 // 0"));
+
+        let context = SELExecutionContext::new();
+
+        let results = run(&tree, &context);
+
+        assert_eq!(
+            format!("{}", results.get(0).unwrap()),
+            "divide by zero in IntegerDivision(Integer, Integer): cannot divide by zero"
+        );
+    }
+}
+
+mod named_expressions {
+    use super::super::execute_named_expression;
+    use super::super::SELExecutionContext;
+    use super::run;
+    use crate::opexec::execution_result::SELExecutionResult;
+    use sel_common::{from_value_data, DataType, SELContext, SELValue};
+    use sel_compiler::Compiler;
+
+    fn last_result(s: &str) -> SELExecutionResult {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from(s));
+
+        let context = SELExecutionContext::new();
+
+        let results = run(&tree, &context);
+
+        return results.last().unwrap().clone();
+    }
+
+    fn int_result(s: &str) -> i64 {
+        let result = last_result(s);
+
+        assert_eq!(result.get_type(), DataType::Integer);
+
+        return from_value_data(result.get_value().unwrap());
+    }
+
+    #[test]
+    fn calls_with_value() {
+        assert_eq!(int_result("#plus_five $ + 5\n\n#plus_five 10"), 15);
+    }
+
+    #[test]
+    fn calls_with_group() {
+        assert_eq!(int_result("#plus_five $ + 5\n\n#plus_five(10)"), 15);
+    }
+
+    #[test]
+    fn calls_with_pipe() {
+        assert_eq!(int_result("#plus_five $ + 5\n\n10 -> #plus_five"), 15);
+    }
+
+    #[test]
+    fn calls_block() {
+        let result = last_result("#is_even {\n    $ % 2 == 0\n}\n\n#is_even(4)");
+        let value: bool = from_value_data(result.get_value().unwrap());
+
+        assert_eq!(result.get_type(), DataType::Boolean);
+        assert!(value);
+    }
+
+    #[test]
+    fn calls_with_multiple_inputs() {
+        assert_eq!(int_result("#sum $[0] + $[1]\n\n#sum(7, 3)"), 10);
+    }
+
+    #[test]
+    fn calls_with_named_inputs() {
+        assert_eq!(
+            int_result("#sum $.num1 + $.num2 * 2\n\n#sum(num1 = 7, num2 = 3)"),
+            13
+        );
+    }
+
+    #[test]
+    fn executes_from_host() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("#plus_five $ + 5\n\n#times_two $ * 2"));
+
+        let context = SELExecutionContext::new();
+
+        let plus_five =
+            execute_named_expression(&tree, "plus_five", SELValue::new_from_int(10), &context)
+                .unwrap();
+        let times_two =
+            execute_named_expression(&tree, "times_two", SELValue::new_from_int(10), &context)
+                .unwrap();
+
+        assert_eq!(from_value_data::<i64>(plus_five.get_value().unwrap()), 15);
+        assert_eq!(from_value_data::<i64>(times_two.get_value().unwrap()), 20);
+    }
+
+    #[test]
+    fn executes_unknown_from_host() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("#plus_five $ + 5"));
+
+        let context = SELExecutionContext::new();
+
+        assert!(execute_named_expression(&tree, "other", SELValue::new(), &context).is_none());
+    }
+
+    #[test]
+    fn tagged_calls_prefer_named_expressions() {
+        let compiler = Compiler::new();
+        let mut context = SELContext::new();
+        context.register_function("plus_five", |_value, _symbol_table| {
+            SELValue::new_from_int(0)
+        });
+
+        let execution_context = SELExecutionContext::from(&context);
+        let tree = compiler.compile_with_context(
+            &String::from("#plus_five $ + 5\n\n#plus_five(10)\n10 -> #plus_five\nplus_five(10)"),
+            context,
+        );
+
+        let values: Vec<i64> = run(&tree, &execution_context)
+            .iter()
+            .map(|result| from_value_data(result.get_value().unwrap()))
+            .collect();
+
+        assert_eq!(values, vec![15, 15, 0]);
+    }
+}
+
+mod tree_artifacts {
+    use super::super::execute_named_expression;
+    use super::super::SELExecutionContext;
+    use super::run;
+    use sel_common::artifact::ArtifactErrorKind;
+    use sel_common::{from_value_data, DataType, SELContext, SELTree, SELValue, SymbolTable};
+    use sel_compiler::Compiler;
+
+    fn double(value: SELValue, _symbol_table: &SymbolTable) -> SELValue {
+        let num: i64 = from_value_data(value.get_value().unwrap());
+
+        return SELValue::new_from_int(num * 2);
+    }
+
+    fn loaded(s: &str) -> SELTree {
+        let compiler = Compiler::new();
+        let mut context = SELContext::new();
+        context.register_function("double", double);
+
+        let tree = compiler.compile_with_context(&String::from(s), context);
+
+        return SELTree::from_bytes(&tree.to_bytes().unwrap()).unwrap();
+    }
+
+    fn results_as_strings(tree: &SELTree, context: &SELExecutionContext) -> Vec<String> {
+        return run(tree, context)
+            .iter()
+            .map(|result| format!("{:?}", result))
+            .collect();
+    }
+
+    #[test]
+    fn loaded_tree_matches_compiled() {
+        let s = "5 + $ * 8 - 3\n'Result is: ' + ?\n:name = (:first = \"Panda\", :last = \"Bear\")\n(1, 2, 3) >>> $.value * 2 >- [] -<";
+
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from(s));
+
+        let mut context = SELExecutionContext::new();
+        context.set_input(SELValue::new_from_int(4));
+
+        assert_eq!(
+            results_as_strings(&loaded(s), &context),
+            results_as_strings(&tree, &context)
+        );
+    }
+
+    #[test]
+    fn loaded_tree_keeps_named_expressions() {
+        let tree = loaded("#add_one { $ + 1 }");
+
+        let context = SELExecutionContext::new();
+
+        let result =
+            execute_named_expression(&tree, "add_one", SELValue::new_from_int(9), &context)
+                .unwrap();
+
+        assert_eq!(from_value_data::<i64>(result.get_value().unwrap()), 10);
+    }
+
+    #[test]
+    fn loaded_tree_uses_registered_functions() {
+        let tree = loaded("5 -> double");
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.register_function("double", double);
+
+        let results = run(&tree, &context);
+        let result = results.get(0).unwrap();
+
+        assert_eq!(result.get_type(), DataType::Integer);
+        assert_eq!(from_value_data::<i64>(result.get_value().unwrap()), 10);
+    }
+
+    #[test]
+    fn loaded_tree_does_not_keep_functions() {
+        let tree = loaded("5 -> double");
+
+        assert!(tree.get_context().get_functions().is_empty());
+    }
+
+    #[test]
+    fn rejects_truncated_tree() {
+        let compiler = Compiler::new();
+        let tree = compiler.compile(&String::from("5 + 10"));
+
+        let bytes = tree.to_bytes().unwrap();
+
+        let error = SELTree::from_bytes(&bytes[..bytes.len() / 2]).unwrap_err();
+
+        assert_eq!(error.get_kind(), ArtifactErrorKind::InvalidData);
+    }
+}
+
+mod bytecode_conformance {
+    use super::super::SELExecutionContext;
+    use super::super::{execute_program, lower_sel_tree, Instruction};
+    use super::run;
+    use sel_common::sel_types::list::List;
+    use sel_common::{from_value_data, DataType, SELContext, SELTree, SELValue, SymbolTable};
+    use sel_compiler::Compiler;
+
+    fn ints_of(value: &SELValue) -> Vec<i64> {
+        return match value.get_type() {
+            DataType::List => {
+                let list: List = from_value_data(value.get_value().unwrap());
+
+                list.get_values()
+                    .iter()
+                    .map(|item| from_value_data::<i64>(item.get_value().unwrap()))
+                    .collect()
+            }
+            _ => vec![value.get_value().map_or(0, from_value_data)],
+        };
+    }
+
+    fn int_list(values: Vec<i64>) -> SELValue {
+        let mut list = List::new();
+
+        for value in values {
+            list.push(SELValue::new_from_int(value));
+        }
+
+        return SELValue::new_from_list(list);
+    }
+
+    fn avg(value: SELValue, _symbol_table: &SymbolTable) -> SELValue {
+        let values = ints_of(&value);
+
+        return SELValue::new_from_int(values.iter().sum::<i64>() / values.len() as i64);
+    }
+
+    fn max(value: SELValue, _symbol_table: &SymbolTable) -> SELValue {
+        return ints_of(&value)
+            .into_iter()
+            .max()
+            .map_or(SELValue::new(), SELValue::new_from_int);
+    }
+
+    fn is_even(value: SELValue, _symbol_table: &SymbolTable) -> SELValue {
+        return SELValue::new_from_boolean(ints_of(&value)[0] % 2 == 0);
+    }
+
+    fn compiled(s: &str) -> SELTree {
+        let compiler = Compiler::new();
+        let mut context = SELContext::new();
+        context.register_function("avg", avg);
+        context.register_function("max", max);
+        context.register_function("is_even", is_even);
+
+        return compiler.compile_with_context(&String::from(s), context);
+    }
+
+    #[test]
+    fn empty_tree() {
+        let tree = compiled("");
+
+        let results = run(&tree, &SELExecutionContext::from(tree.get_context()));
+
+        assert_eq!(results[0].get_type(), DataType::Unknown);
+    }
+
+    #[test]
+    fn executes_lowered_calls() {
+        let tree = compiled("$ -> avg\n$ -> max\n$[1] -> is_even\n(1 `max` 3) > 2 && !false");
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.set_input(int_list(vec![1, 2, 3, 4]));
+
+        let results = run(&tree, &context);
+
+        assert_eq!(from_value_data::<i64>(results[0].get_value().unwrap()), 2);
+        assert_eq!(from_value_data::<i64>(results[1].get_value().unwrap()), 4);
+        assert!(from_value_data::<bool>(results[2].get_value().unwrap()));
+        assert!(from_value_data::<bool>(results[3].get_value().unwrap()));
+    }
+
+    #[test]
+    fn short_circuit_skips_right_side() {
+        let tree = compiled("false && (10 -> fetch)");
+        let program = lower_sel_tree(&tree);
+
+        let results = execute_program(&program, &SELExecutionContext::from(tree.get_context()));
+        let result = results.get(0).unwrap();
+
+        assert_eq!(result.get_type(), DataType::Boolean);
+        assert!(!from_value_data::<bool>(result.get_value().unwrap()));
+    }
+
+    #[test]
+    fn lowers_without_tree_walking() {
+        let tree = compiled(
+            "$ > 5 => avg($)\n$ -> is_even\n(1 `max` 3) > 2 && !false\n[:a = (1, 2), 3].a.1 + $[1]",
+        );
+        let program = lower_sel_tree(&tree);
+
+        assert!(!program
+            .get_code()
+            .iter()
+            .any(|instruction| matches!(instruction, Instruction::Evaluate(_))));
+    }
+
+    #[test]
+    fn walks_tree_for_operations_with_other_input() {
+        let tree = compiled("(1, 2) >>> $.value * 2 >- [] -<");
+        let program = lower_sel_tree(&tree);
+
+        assert!(program
+            .get_code()
+            .iter()
+            .any(|instruction| matches!(instruction, Instruction::Evaluate(_))));
+    }
+}
+
+mod value_conversion {
+    use super::super::SELExecutionContext;
+    use super::run;
+    use sel_common::{from_sel_value, to_sel_value, to_sel_value_with_context, SELContext};
+    use sel_compiler::Compiler;
+    use std::collections::BTreeMap;
+
+    fn animal() -> BTreeMap<&'static str, i64> {
+        let mut animal = BTreeMap::new();
+        animal.insert("legs", 4);
+        animal.insert("age", 10);
+
+        return animal;
+    }
+
+    #[test]
+    fn converted_input_is_accessed_by_key() {
+        // keys are symbols of their own table
+        // so indexes do not match the compiled tree
+        let tree = Compiler::new().compile(&String::from(
+            "$.legs + $.age\n$[\"legs\"]\n$[:age]\n$.other",
+        ));
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.set_input(to_sel_value(&animal()).unwrap());
+
+        let results = run(&tree, &context);
+
+        assert_eq!(from_sel_value::<i64>(results[0].get_sel_value()), Ok(14));
+        assert_eq!(from_sel_value::<i64>(results[1].get_sel_value()), Ok(4));
+        assert_eq!(from_sel_value::<i64>(results[2].get_sel_value()), Ok(10));
+        assert_eq!(from_sel_value::<()>(results[3].get_sel_value()), Ok(()));
+    }
+
+    #[test]
+    fn converts_results() {
+        let mut sel_context = SELContext::new();
+        let input = to_sel_value_with_context(&animal(), &mut sel_context).unwrap();
+
+        let tree = Compiler::new().compile_with_context(
+            &String::from("[:total = $.legs + $.age, :legs = $.legs]"),
+            sel_context,
+        );
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.set_input(input);
+
+        let results = run(&tree, &context);
+
+        let mut expected: BTreeMap<String, i64> = BTreeMap::new();
+        expected.insert(String::from("total"), 14);
+        expected.insert(String::from("legs"), 4);
+
+        assert_eq!(from_sel_value(results[0].get_sel_value()), Ok(expected));
+    }
+}
+
+mod host_functions {
+    use super::super::{execute_sel_tree, SELExecutionContext};
+    use super::run;
+    use crate::opexec::execution_result::SELExecutionResult;
+    use sel_common::function::Parameter;
+    use sel_common::sel_types::associative_list::AssociativeList;
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::sel_types::list::List;
+    use sel_common::{from_value_data, DataType, Operation, SELContext, SELValue};
+    use sel_compiler::Compiler;
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use std::sync::Arc;
+
+    fn with_clamp() -> SELContext {
+        let mut context = SELContext::new();
+        context.register_typed_function(
+            "clamp",
+            vec![
+                Parameter::new("num", DataType::Integer),
+                Parameter::new("min", DataType::Integer),
+                Parameter::new("max", DataType::Integer),
+            ],
+            |arguments| {
+                let num: i64 = arguments.get("num").unwrap();
+                let min: i64 = arguments.get("min").unwrap();
+                let max: i64 = arguments.get("max").unwrap();
+
+                SELValue::new_from_int(num.max(min).min(max))
+            },
+        );
+
+        return context;
+    }
+
+    fn with_collections() -> SELContext {
+        let mut context = SELContext::new();
+        context.register_typed_function(
+            "alen",
+            vec![Parameter::new("items", DataType::AssociativeList)],
+            |arguments| {
+                let items: AssociativeList = arguments.get("items").unwrap();
+
+                SELValue::new_from_int(items.get_list().get_values().len() as i64)
+            },
+        );
+        context.register_typed_function(
+            "count",
+            vec![Parameter::new("items", DataType::List)],
+            |arguments| {
+                let items: List = arguments.get("items").unwrap();
+
+                SELValue::new_from_int(items.get_values().len() as i64)
+            },
+        );
+
+        return context;
+    }
+
+    fn results_of(s: &str, context: SELContext) -> Vec<SELExecutionResult> {
+        let tree = Compiler::new().compile_with_context(&String::from(s), context);
+
+        return run(&tree, &SELExecutionContext::from(tree.get_context()));
+    }
+
+    fn int_results(s: &str) -> Vec<i64> {
+        return results_of(s, with_clamp())
+            .iter()
+            .map(|result| from_value_data(result.get_value().unwrap()))
+            .collect();
+    }
+
+    #[test]
+    fn closures_keep_state() {
+        let total = Arc::new(AtomicI64::new(0));
+        let counted = total.clone();
+
+        let mut context = SELContext::new();
+        context.register_function("add", move |value, _symbol_table| {
+            let num: i64 = from_value_data(value.get_value().unwrap());
+
+            SELValue::new_from_int(counted.fetch_add(num, Ordering::SeqCst) + num)
+        });
+
+        // the state would carry over from one backend to the other
+        let tree = Compiler::new()
+            .compile_with_context(&String::from("add(5)\n10 -> add\nadd(1)"), context);
+        let results = execute_sel_tree(&tree, &SELExecutionContext::from(tree.get_context()));
+
+        assert_eq!(from_value_data::<i64>(results[2].get_value().unwrap()), 16);
+        assert_eq!(total.load(Ordering::SeqCst), 16);
+    }
+
+    #[test]
+    fn binds_positional_and_named_arguments() {
+        assert_eq!(
+            int_results("clamp(3, 10, 20)\nclamp(num=3, min=10, max=20)\nclamp(max=20, num=30, min=10)\nclamp(25, max=20, min=10)"),
+            vec![10, 10, 20, 20]
+        );
+    }
+
+    #[test]
+    fn binds_piped_arguments() {
+        assert_eq!(
+            int_results("30 -> clamp(10, 20)\n3 -> clamp(min=10, max=20)\n15 |> clamp(num=30, min=10)\nclamp(10, 20) <- 5"),
+            vec![20, 10, 15, 10]
+        );
+    }
+
+    #[test]
+    fn binds_collections_to_collection_parameters() {
+        let results: Vec<i64> = results_of(
+            "[1, 2, 3] -> alen\nalen([:a = 1, :b = 2])\ncount(items = [1, 2])\n[1, 2, 3, 4] -> count\ncount((1, 2))",
+            with_collections(),
+        )
+        .iter()
+        .map(|result| from_value_data(result.get_value().unwrap()))
+        .collect();
+
+        assert_eq!(results, vec![3, 2, 2, 4, 2]);
+    }
+
+    #[test]
+    fn binds_partial_application_arguments() {
+        let results = results_of("clamp ~ (min = 10, max = 20)\n?(30)", with_clamp());
+
+        assert_eq!(from_value_data::<i64>(results[1].get_value().unwrap()), 20);
+    }
+
+    #[test]
+    fn mismatched_arguments_are_errors() {
+        let results = results_of(
+            "clamp(3, 'ten', 20)\n3 -> clamp\nclamp(num=3, low=1, max=4)",
+            with_clamp(),
+        );
+
+        let errors: Vec<(RuntimeErrorKind, Operation, String)> = results
+            .iter()
+            .map(|result| {
+                let error = result.get_error().unwrap();
+
+                (
+                    error.get_kind(),
+                    error.get_operation(),
+                    error.get_message().clone(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    RuntimeErrorKind::ArgumentMismatch,
+                    Operation::Group,
+                    String::from("parameter 'min' expects Integer, found String")
+                ),
+                (
+                    RuntimeErrorKind::ArgumentMismatch,
+                    Operation::PipeFirstRight,
+                    String::from("missing argument 'min'")
+                ),
+                (
+                    RuntimeErrorKind::ArgumentMismatch,
+                    Operation::Group,
+                    String::from("no parameter named 'low'")
+                ),
+            ]
+        );
+    }
+}
+
+mod concurrent_execution {
+    use super::super::{execute_program, execute_sel_tree, lower_sel_tree, SELExecutionContext};
+    use sel_common::{from_value_data, to_sel_value, SELContext, SELTree, SELValue};
+    use sel_compiler::Compiler;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    fn last_int(results: Vec<super::super::SELExecutionResult>) -> i64 {
+        return from_value_data(results.last().unwrap().get_value().unwrap());
+    }
+
+    #[test]
+    fn execution_values_shadow_compiled_values() {
+        let mut context = SELContext::new();
+        context.set_integer_symbol(&String::from("rate"), 2);
+
+        let tree = Compiler::new().compile_with_context(&String::from("$ * rate"), context);
+        let program = lower_sel_tree(&tree);
+
+        let mut compiled = SELExecutionContext::from(tree.get_context());
+        compiled.set_input(SELValue::new_from_int(5));
+
+        let mut given = compiled.clone();
+        given.set_value("rate", SELValue::new_from_int(3));
+
+        assert_eq!(last_int(execute_sel_tree(&tree, &compiled)), 10);
+        assert_eq!(last_int(execute_sel_tree(&tree, &given)), 15);
+        assert_eq!(last_int(execute_program(&program, &compiled)), 10);
+        assert_eq!(last_int(execute_program(&program, &given)), 15);
+    }
+
+    #[test]
+    fn executes_one_tree_from_many_threads() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counted = calls.clone();
+
+        let mut context = SELContext::new();
+        context.register_function("offset", move |value, _symbol_table| {
+            counted.fetch_add(1, Ordering::SeqCst);
+
+            let num: i64 = from_value_data(value.get_value().unwrap());
+
+            SELValue::new_from_int(num + 10)
+        });
+
+        let tree: Arc<SELTree> = Arc::new(Compiler::new().compile_with_context(
+            &String::from("#scale { $ * factor }\n$.value -> scale -> offset"),
+            context,
+        ));
+
+        let threads: Vec<thread::JoinHandle<Vec<i64>>> = (0..8)
+            .map(|worker| {
+                let tree = tree.clone();
+
+                thread::spawn(move || {
+                    let mut input = HashMap::new();
+                    input.insert("value", worker);
+
+                    let mut context = SELExecutionContext::from(tree.get_context());
+                    context.set_input(to_sel_value(&input).unwrap());
+                    context.set_value("factor", SELValue::new_from_int(worker));
+
+                    let program = lower_sel_tree(&tree);
+
+                    (0..25)
+                        .flat_map(|_| {
+                            vec![
+                                last_int(execute_sel_tree(&tree, &context)),
+                                last_int(execute_program(&program, &context)),
+                            ]
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+
+        for (worker, handle) in threads.into_iter().enumerate() {
+            let worker = worker as i64;
+
+            assert!(handle
+                .join()
+                .unwrap()
+                .iter()
+                .all(|result| *result == worker * worker + 10));
+        }
+
+        assert_eq!(calls.load(Ordering::SeqCst), 8 * 25 * 2);
+    }
+}
+
+mod execution_limits {
+    use super::super::{
+        execute_program, execute_sel_tree, lower_sel_tree, Limits, SELExecutionContext,
+        DEFAULT_MAX_CALL_DEPTH,
+    };
+    use super::run;
+    use crate::opexec::execution_result::SELExecutionResult;
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::{from_value_data, Operation, SELTree};
+    use sel_compiler::Compiler;
+
+    fn limited(s: &str, limits: Limits) -> (Vec<SELExecutionResult>, Vec<SELExecutionResult>) {
+        let tree: SELTree = Compiler::new().compile(&String::from(s));
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.set_limits(limits);
+
+        return (
+            execute_sel_tree(&tree, &context),
+            execute_program(&lower_sel_tree(&tree), &context),
+        );
+    }
+
+    fn assert_exceeded(results: &[SELExecutionResult], message: &str) {
+        let error = results.last().unwrap().get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::LimitExceeded);
+        assert_eq!(error.get_message(), message);
+    }
+
+    #[test]
+    fn steps_and_sizes_unlimited_by_default() {
+        let limits = SELExecutionContext::new().get_limits();
+
+        assert_eq!(limits, Limits::new());
+        assert_eq!(limits.get_max_steps(), None);
+        assert_eq!(limits.get_max_collection_size(), None);
+        assert_eq!(limits.get_max_string_length(), None);
+
+        let (walked, run) = limited("1..5 >- 0 -< $.result + $.value", Limits::new());
+
+        assert_eq!(from_value_data::<i64>(walked[0].get_value().unwrap()), 10);
+        assert_eq!(from_value_data::<i64>(run[0].get_value().unwrap()), 10);
+    }
+
+    #[test]
+    fn limits_call_depth_by_default() {
+        assert_eq!(
+            Limits::new().get_max_call_depth(),
+            Some(DEFAULT_MAX_CALL_DEPTH)
+        );
+
+        let tree: SELTree = Compiler::new().compile(&String::from("#f #f $\n\n#f 3"));
+
+        let results = run(&tree, &SELExecutionContext::from(tree.get_context()));
+
+        assert_exceeded(&results, "exceeded call depth of 256");
+
+        // hosts may raise it
+        let (walked, run) = limited(
+            "#forever { $ -> forever }\n\n1 -> forever",
+            Limits::new().with_max_call_depth(300),
+        );
+
+        assert_exceeded(&walked, "exceeded call depth of 300");
+        assert_exceeded(&run, "exceeded call depth of 300");
+    }
+
+    #[test]
+    fn stops_after_max_steps() {
+        let (walked, run) = limited("1 + 2 + 3 + 4\n5", Limits::new().with_max_steps(4));
+
+        assert_exceeded(&walked[..1], "exceeded limit of 4 steps");
+        assert_exceeded(&walked, "exceeded limit of 4 steps");
+        assert_exceeded(&run, "exceeded limit of 4 steps");
+    }
+
+    #[test]
+    fn reports_node_that_ran_out_of_steps() {
+        let operation_of = |results: &[SELExecutionResult]| -> Operation {
+            return results[0].get_error().unwrap().get_operation();
+        };
+
+        let (walked, run) = limited(
+            "1..1000000000 >- 0 -< $.result + $.value",
+            Limits::new().with_max_steps(1000),
+        );
+
+        assert_eq!(operation_of(&walked), Operation::Addition);
+        assert_eq!(operation_of(&run), Operation::Addition);
+
+        let (_, run) = limited("1 + 2 + 3 + 4", Limits::new().with_max_steps(4));
+
+        assert_eq!(operation_of(&run), Operation::Addition);
+    }
+
+    #[test]
+    fn steps_are_counted_per_execution() {
+        let tree: SELTree = Compiler::new().compile(&String::from("1 + 2"));
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.set_limits(Limits::new().with_max_steps(3));
+
+        for _ in 0..3 {
+            assert!(!execute_sel_tree(&tree, &context)[0].is_error());
+        }
+    }
+
+    #[test]
+    fn stops_recursion_at_max_call_depth() {
+        let (walked, run) = limited(
+            "#forever { $ -> forever }\n\n1 -> forever",
+            Limits::new().with_max_call_depth(50),
+        );
+
+        assert_exceeded(&walked, "exceeded call depth of 50");
+        assert_exceeded(&run, "exceeded call depth of 50");
+    }
+
+    #[test]
+    fn limits_collection_size() {
+        let (walked, run) = limited(
+            "1..1000000 >- [] -<",
+            Limits::new().with_max_collection_size(100),
+        );
+
+        assert_exceeded(&walked, "collection of 101 items exceeds limit of 100");
+        assert_exceeded(&run, "collection of 101 items exceeds limit of 100");
+
+        let (walked, _) = limited("1, 2, 3, 4", Limits::new().with_max_collection_size(3));
+
+        assert_exceeded(&walked, "collection of 4 items exceeds limit of 3");
+    }
+
+    #[test]
+    fn limits_string_length() {
+        let (walked, run) = limited("\"abc\" + \"def\"", Limits::new().with_max_string_length(5));
+
+        assert_exceeded(&walked, "string of 6 characters exceeds limit of 5");
+        assert_exceeded(&run, "string of 6 characters exceeds limit of 5");
+    }
+}
+
+mod evaluation_interruption {
+    use super::super::{
+        execute_program, execute_sel_tree, execute_stream, lower_sel_tree, SELExecutionContext,
+    };
+    use crate::opexec::execution_result::SELExecutionResult;
+    use sel_common::cancellation::{cancelled_value, timed_out_value};
+    use sel_common::sel_types::error::RuntimeErrorKind;
+    use sel_common::{CancellationToken, Operation, SELContext, SELTree};
+    use sel_compiler::Compiler;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    fn both(tree: &SELTree, context: &SELExecutionContext) -> Vec<SELExecutionResult> {
+        let mut results = execute_sel_tree(tree, context);
+        results.append(&mut execute_program(&lower_sel_tree(tree), context));
+
+        return results;
+    }
+
+    fn kind_of(result: &SELExecutionResult) -> RuntimeErrorKind {
+        return result.get_error().unwrap().get_kind();
+    }
+
+    #[test]
+    fn cancelled_before_start() {
+        let tree = Compiler::new().compile(&String::from("1 + 2"));
+
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.set_cancellation_token(token);
+
+        for result in both(&tree, &context) {
+            assert_eq!(kind_of(&result), RuntimeErrorKind::Cancelled);
+        }
+    }
+
+    #[test]
+    fn past_deadline() {
+        let tree = Compiler::new().compile(&String::from("1 + 2"));
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.set_deadline(Instant::now());
+
+        for result in both(&tree, &context) {
+            assert_eq!(kind_of(&result), RuntimeErrorKind::TimedOut);
+        }
+    }
+
+    #[test]
+    fn timeout_stops_long_collect() {
+        let tree =
+            Compiler::new().compile(&String::from("1..1000000000 >- 0 -< $.result + $.value"));
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.set_timeout(Duration::from_millis(20));
+
+        let results = execute_sel_tree(&tree, &context);
+
+        assert_eq!(kind_of(&results[0]), RuntimeErrorKind::TimedOut);
+
+        // each evaluation has the full timeout
+        let tree = Compiler::new().compile(&String::from("1..10 >- 0 -< $.result + $.value"));
+
+        assert!(!execute_sel_tree(&tree, &context)[0].is_error());
+    }
+
+    #[test]
+    fn cancels_stream() {
+        let tree = Compiler::new().compile(&String::from("1..1000000 >>> $.value * 2"));
+
+        let token = CancellationToken::new();
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.set_cancellation_token(token.clone());
+
+        let mut stream = execute_stream(&tree, &context);
+
+        assert!(!stream.next().unwrap().is_error());
+
+        token.cancel();
+
+        let last = stream.last().unwrap();
+
+        assert_eq!(kind_of(&last), RuntimeErrorKind::Cancelled);
+    }
+
+    #[test]
+    fn host_functions_observe_cancellation() {
+        let mut functions = SELContext::new();
+        functions.register_function("wait", |_value, _symbol_table| {
+            while !CancellationToken::current().is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+
+            cancelled_value()
+        });
+
+        let tree = Compiler::new().compile_with_context(&String::from("wait()"), functions);
+
+        let token = CancellationToken::new();
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.set_cancellation_token(token.clone());
+
+        let cancel = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            token.cancel();
+        });
+
+        let results = execute_sel_tree(&tree, &context);
+        cancel.join().unwrap();
+
+        let error = results[0].get_error().unwrap();
+
+        assert_eq!(error.get_kind(), RuntimeErrorKind::Cancelled);
+        assert_eq!(error.get_operation(), Operation::Group);
+        assert!(!CancellationToken::current().is_cancelled());
+    }
+
+    #[test]
+    fn host_functions_observe_timeout() {
+        let mut functions = SELContext::new();
+        functions.register_function("wait", |_value, _symbol_table| {
+            let token = CancellationToken::current();
+
+            while !token.is_interrupted() {
+                thread::sleep(Duration::from_millis(1));
+            }
+
+            if token.is_cancelled() {
+                return cancelled_value();
+            }
+
+            return timed_out_value();
+        });
+
+        let tree = Compiler::new().compile_with_context(&String::from("wait()"), functions);
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.set_timeout(Duration::from_millis(10));
+
+        for result in both(&tree, &context) {
+            let error = result.get_error().unwrap();
+
+            assert_eq!(error.get_kind(), RuntimeErrorKind::TimedOut);
+            assert_eq!(error.get_operation(), Operation::Group);
+        }
+
+        assert_eq!(CancellationToken::current().get_deadline(), None);
+    }
+}
+
+mod test_annotations {
+    use super::super::{
+        execute_program, execute_sel_tree, lower_sel_tree, run_tests, SELExecutionContext,
+        SELExecutionResult, TestResult,
+    };
+    use super::run;
+    use sel_common::{from_value_data, SELContext, SELTree, SELValue};
+    use sel_compiler::Compiler;
+
+    fn compile(s: &str) -> SELTree {
+        return Compiler::new().compile(&String::from(s));
+    }
+
+    fn tested(tree: &SELTree) -> Vec<TestResult> {
+        return run_tests(tree, &SELExecutionContext::from(tree.get_context()));
+    }
+
+    #[test]
+    fn tests_are_not_results() {
+        let tree = compile("5 + 5\n\n@Test\n1 == 1\n\n? * 2");
+
+        let results: Vec<i64> = run(&tree, &SELExecutionContext::new())
+            .iter()
+            .map(|result| from_value_data(result.get_value().unwrap()))
+            .collect();
+
+        assert_eq!(results, vec![10, 20]);
+        assert_eq!(tree.get_tests().len(), 1);
+    }
+
+    #[test]
+    fn tests_file_with_input() {
+        let tree = compile("$.0 + $.1\n\n@Test((1, 2))\n$ == 3\n\n@Test((5, 5))\n$ == 3");
+
+        let results = tested(&tree);
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_passed());
+        assert!(!results[1].is_passed());
+        assert_eq!(results[1].get_span().get_start().get_line(), 6);
+    }
+
+    #[test]
+    fn tests_named_expressions() {
+        let tree = compile(
+            "#sum $.0 + $.1\n#twice $ * 2\n\n@Test\n#sum(1, 2) == 3\n\n@Test\n#twice(4) == 8",
+        );
+
+        assert!(tested(&tree).iter().all(|result| result.is_passed()));
+    }
+
+    #[test]
+    fn only_true_passes() {
+        let tree = compile("$ * 2\n\n@Test(2)\n$\n\n@Test(2)\n$ // 0");
+
+        let results = tested(&tree);
+
+        assert!(!results[0].is_passed());
+        assert!(!results[1].is_passed());
+        assert!(results[1].get_result().is_error());
+    }
+
+    fn with_get_user() -> SELContext {
+        let mut context = SELContext::new();
+        context.register_function("get_user", |value, _symbol_table| {
+            let id: String = from_value_data(value.get_value().unwrap());
+
+            SELValue::new_from_string(&format!("real {}", id))
+        });
+
+        return context;
+    }
+
+    #[test]
+    fn mocks_apply_to_following_tests() {
+        let tree = Compiler::new().compile_with_context(
+            &String::from(
+                "\
+get_user($)
+
+@Test(\"first\")
+$ == \"real first\"
+
+@Mock(get_user, \"Panda\")
+@Mock(get_user, (), \"unknown\")
+
+@MockOnce(get_user, \"Bear\", \"unknown\")
+@Test(\"unknown\")
+$ == \"Bear\"
+
+@Test(\"unknown\")
+$ == ()
+
+@Test(\"other\")
+$ == \"Panda\"
+",
+            ),
+            with_get_user(),
+        );
+
+        let results = tested(&tree);
+
+        assert_eq!(results.len(), 4);
+        assert!(results.iter().all(|result| result.is_passed()));
+    }
+
+    #[test]
+    fn mocked_input_falls_back_to_function() {
+        let tree = Compiler::new().compile_with_context(
+            &String::from(
+                "get_user($)\n\n@Mock(get_user, \"Panda\", \"id\")\n@Test(\"other\")\n$ == \"real other\"",
+            ),
+            with_get_user(),
+        );
+
+        assert!(tested(&tree)[0].is_passed());
+    }
+
+    #[test]
+    fn mocks_named_expressions() {
+        let tree = compile(
+            "#double $ * 2\n#quadruple #double(#double($))\n\n@Mock(double, 5, 3)\n@Test\n#quadruple(3) == 10",
+        );
+
+        assert!(tested(&tree)[0].is_passed());
+    }
+
+    #[test]
+    fn mocks_host_calls() {
+        let tree = Compiler::new().compile_with_context(
+            &String::from("get_user(\"id\")\nget_user(\"other\")"),
+            with_get_user(),
+        );
+
+        let mut context = SELExecutionContext::from(tree.get_context());
+        context.mock_function(
+            "get_user",
+            SELValue::new_from_string(&String::from("Panda")),
+            Some(SELValue::new_from_string(&String::from("id"))),
+        );
+
+        let strings = |results: Vec<SELExecutionResult>| -> Vec<String> {
+            results
+                .iter()
+                .map(|result| from_value_data(result.get_value().unwrap()))
+                .collect()
+        };
+
+        assert_eq!(
+            strings(execute_sel_tree(&tree, &context)),
+            vec!["Panda", "real other"]
+        );
+        assert_eq!(
+            strings(execute_program(&lower_sel_tree(&tree), &context)),
+            vec!["Panda", "real other"]
+        );
+    }
 }
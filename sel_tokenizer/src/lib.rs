@@ -11,6 +11,7 @@ pub use tokenizer::Tokenizer;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use sel_common::Location;
     use token::Token;
     use token_type::TokenType;
 
@@ -651,6 +652,12 @@ mod tests {
         assert_token(tokens.get(0).unwrap(), TokenType::SeedCollect, "-<");
     }
 
+    #[test]
+    fn tokenize_combine() {
+        let tokens = tokens_from_str("<>");
+        assert_token(tokens.get(0).unwrap(), TokenType::Combine, "<>");
+    }
+
     #[test]
     fn tokenize_back_tick() {
         let tokens = tokens_from_str("`");
@@ -740,6 +747,88 @@ mod tests {
         assert_eq!(tokens.len(), 39);
     }
 
+    #[test]
+    fn span_of_binary_operation() {
+        let tokens = tokens_from_str("5 + 10");
+
+        assert_span(tokens.get(0).unwrap(), (0, 1, 1), (1, 1, 2));
+        assert_span(tokens.get(1).unwrap(), (2, 1, 3), (3, 1, 4));
+        assert_span(tokens.get(2).unwrap(), (4, 1, 5), (6, 1, 7));
+    }
+
+    #[test]
+    fn span_of_string_includes_quotes() {
+        let tokens = tokens_from_str("'Hello' + \"World\"");
+
+        assert_span(tokens.get(0).unwrap(), (0, 1, 1), (7, 1, 8));
+        assert_span(tokens.get(2).unwrap(), (10, 1, 11), (17, 1, 18));
+    }
+
+    #[test]
+    fn span_across_lines() {
+        let tokens = tokens_from_str("5 + 10\n  15 * 20");
+
+        assert_token(tokens.get(3).unwrap(), TokenType::LineEnd, "\n");
+        assert_span(tokens.get(3).unwrap(), (6, 1, 7), (7, 2, 1));
+
+        assert_token(tokens.get(4).unwrap(), TokenType::Integer, "15");
+        assert_span(tokens.get(4).unwrap(), (9, 2, 3), (11, 2, 5));
+        assert_span(tokens.get(6).unwrap(), (14, 2, 8), (16, 2, 10));
+    }
+
+    #[test]
+    fn span_of_integer_range() {
+        let tokens = tokens_from_str("1..10 1...10");
+
+        assert_span(tokens.get(0).unwrap(), (0, 1, 1), (1, 1, 2));
+        assert_span(tokens.get(1).unwrap(), (1, 1, 2), (3, 1, 4));
+        assert_span(tokens.get(2).unwrap(), (3, 1, 4), (5, 1, 6));
+        assert_span(tokens.get(3).unwrap(), (6, 1, 7), (7, 1, 8));
+        assert_span(tokens.get(4).unwrap(), (7, 1, 8), (10, 1, 11));
+        assert_span(tokens.get(5).unwrap(), (10, 1, 11), (12, 1, 13));
+    }
+
+    #[test]
+    fn span_of_decimal() {
+        let tokens = tokens_from_str("3.14");
+
+        assert_span(tokens.get(0).unwrap(), (0, 1, 1), (4, 1, 5));
+    }
+
+    #[test]
+    fn span_of_multi_byte_string() {
+        let tokens = tokens_from_str("\"héllo\" + 1");
+
+        assert_span(tokens.get(0).unwrap(), (0, 1, 1), (8, 1, 8));
+        assert_span(tokens.get(1).unwrap(), (9, 1, 9), (10, 1, 10));
+    }
+
+    #[test]
+    fn unterminated_string_is_recorded() {
+        let input = String::from("5 + 'Hello");
+        let mut tokenizer = Tokenizer::new(&input);
+        let tokens: Vec<Token> = tokenizer.by_ref().collect();
+
+        assert_token(
+            tokens.get(2).unwrap(),
+            TokenType::SingleQuotedString,
+            "Hello",
+        );
+
+        let span = tokenizer.get_unterminated_string().unwrap();
+        assert_eq!(span.get_start(), Location::new(4, 1, 5));
+        assert_eq!(span.get_end(), Location::new(10, 1, 11));
+    }
+
+    #[test]
+    fn terminated_string_is_not_recorded() {
+        let input = String::from("5 + 'Hello'");
+        let mut tokenizer = Tokenizer::new(&input);
+        let _tokens: Vec<Token> = tokenizer.by_ref().collect();
+
+        assert!(tokenizer.get_unterminated_string().is_none());
+    }
+
     // Test utils
     fn tokens_from_str(s: &str) -> Vec<Token> {
         let input = String::from(s);
@@ -752,6 +841,22 @@ mod tests {
         assert_eq!(token.get_token_str(), token_str);
     }
 
+    fn assert_span(token: &Token, start: (usize, usize, usize), end: (usize, usize, usize)) {
+        let span = token.get_span();
+        assert_eq!(
+            span.get_start(),
+            Location::new(start.0, start.1, start.2),
+            "start of {:?}",
+            token
+        );
+        assert_eq!(
+            span.get_end(),
+            Location::new(end.0, end.1, end.2),
+            "end of {:?}",
+            token
+        );
+    }
+
     fn assert_4_5_binary_operation(op: &str, op_token_type: TokenType) {
         let tokens: Vec<Token> =
             tokens_from_str(&("4".to_owned() + &op.to_owned() + &"5".to_owned()));
@@ -1,16 +1,19 @@
 use super::token_type::TokenType;
+use sel_common::Span;
 
 #[derive(PartialEq, Debug, Clone)]
 pub struct Token {
     token_type: TokenType,
     token_str: String,
+    span: Span,
 }
 
 impl Token {
-    pub fn new(token_type: TokenType, token_str: String) -> Token {
+    pub fn new(token_type: TokenType, token_str: String, span: Span) -> Token {
         return Token {
             token_type: token_type,
             token_str: token_str,
+            span,
         };
     }
 
@@ -21,4 +24,8 @@ impl Token {
     pub fn get_token_str(&self) -> String {
         return self.token_str.clone();
     }
+
+    pub fn get_span(&self) -> Span {
+        return self.span;
+    }
 }
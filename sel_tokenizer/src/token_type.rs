@@ -64,6 +64,7 @@ pub enum TokenType {
     Stream,
     Collect,
     SeedCollect,
+    Combine,
     TaggedIdentifier,
     Unknown,
 }
@@ -3,6 +3,7 @@ use super::symbol_tree::{SymbolTree, SymbolTreeNode};
 use super::token::Token;
 use super::token_type::TokenType;
 use crate::parse_state::ParseState::ParsingNamespace;
+use sel_common::{Location, Span};
 
 pub struct Tokenizer<'a> {
     current_token: String,
@@ -14,6 +15,14 @@ pub struct Tokenizer<'a> {
     input: String,
     next_index: usize,
     token_type_history: Vec<TokenType>,
+    // location of character currently being processed
+    char_location: Location,
+    // location of character processed before current one
+    previous_location: Location,
+    // location directly after character currently being processed
+    next_location: Location,
+    token_start: Location,
+    unterminated_string: Option<Span>,
 }
 
 impl<'a> Tokenizer<'a> {
@@ -75,6 +84,7 @@ impl<'a> Tokenizer<'a> {
         symbol_tree.attach(">>>", TokenType::Stream);
         symbol_tree.attach(">-", TokenType::Collect);
         symbol_tree.attach("-<", TokenType::SeedCollect);
+        symbol_tree.attach("<>", TokenType::Combine);
 
         return Tokenizer {
             current_token: String::new(),
@@ -86,10 +96,19 @@ impl<'a> Tokenizer<'a> {
             input: input.clone(),
             next_index: 0,
             token_type_history: vec![],
+            char_location: Location::start(),
+            previous_location: Location::start(),
+            next_location: Location::start(),
+            token_start: Location::start(),
+            unterminated_string: None,
         };
     }
 
     fn start_new_token(&mut self, c: char) {
+        if !c.is_whitespace() || c == '\n' {
+            self.token_start = self.char_location;
+        }
+
         if c == '\n' {
             // special check here to catch before whitespace check
             self.current_token.push(c);
@@ -156,9 +175,13 @@ impl<'a> Tokenizer<'a> {
         }
     }
 
-    fn make_current_token(&mut self) -> Option<Token> {
+    fn make_current_token(&mut self, end: Location) -> Option<Token> {
         return if self.current_token.len() > 0 {
-            let token = Token::new(self.current_token_type, self.current_token.clone());
+            let token = Token::new(
+                self.current_token_type,
+                self.current_token.clone(),
+                Span::new(self.token_start, end),
+            );
 
             self.current_token = String::new();
             self.current_token_type = TokenType::Unknown;
@@ -172,11 +195,18 @@ impl<'a> Tokenizer<'a> {
 
     fn end_current_token(&mut self, c: char) -> Option<Token> {
         self.token_type_history.push(self.current_token_type);
-        let token = self.make_current_token();
+        // current character is not part of the token
+        // so it marks the end
+        let token = self.make_current_token(self.char_location);
         self.start_new_token(c);
         return token;
     }
 
+    // span of a string that was still open when input ran out
+    pub fn get_unterminated_string(&self) -> Option<Span> {
+        return self.unterminated_string;
+    }
+
     fn nth_token_history_is(&self, n: usize, token_types: &[TokenType]) -> bool {
         if (self.token_type_history.len() as i64) - (n as i64) < 0 {
             return false;
@@ -199,6 +229,10 @@ impl<'a> Iterator for Tokenizer<'a> {
             self.next_index += 1;
             match self.chars.next() {
                 Some(c) => {
+                    self.previous_location = self.char_location;
+                    self.char_location = self.next_location;
+                    self.next_location = self.char_location.advance(c);
+
                     match self.parse_state {
                         ParseState::NoToken => {
                             self.start_new_token(c);
@@ -246,10 +280,12 @@ impl<'a> Iterator for Tokenizer<'a> {
 
                                 // generate current token
                                 // and store to be returned later
-                                let integer_token = self.make_current_token();
+                                // integer ends at the first dot
+                                let integer_token = self.make_current_token(self.previous_location);
 
                                 // start with single dot (one we just removed)
                                 self.start_new_token('.');
+                                self.token_start = self.previous_location;
                                 // add current one as well
                                 self.current_token.push(c);
 
@@ -277,7 +313,7 @@ impl<'a> Iterator for Tokenizer<'a> {
 
                                 // this is furthest this token can go
                                 // end token
-                                return self.make_current_token();
+                                return self.make_current_token(self.next_location);
                             } else {
                                 // not an inclusive range
                                 // end with exclusive range token
@@ -467,7 +503,18 @@ impl<'a> Iterator for Tokenizer<'a> {
                     };
                 }
                 // will return None if there is not a last token
-                None => return self.make_current_token(),
+                None => {
+                    if self.parse_state == ParseState::ParsingSingleQuotedString
+                        || self.parse_state == ParseState::ParsingDoubleQuotedString
+                        || self.parse_state == ParseState::EscapeCharacter
+                    {
+                        // input ended before closing quote
+                        self.unterminated_string =
+                            Some(Span::new(self.token_start, self.next_location));
+                    }
+
+                    return self.make_current_token(self.next_location);
+                }
             }
         }
     }
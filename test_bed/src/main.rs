@@ -1,4 +1,4 @@
-use sel_common::{from_byte_vec, DataType, SELContext, SELValue};
+use sel_common::{from_value_data, DataType, SELContext, SELValue};
 use sel_compiler;
 use sel_executor;
 use std::f64::consts::*;
@@ -55,13 +55,13 @@ fn main() {
             match sel_value.get_type() {
                 DataType::Integer => sel_value
                     .get_value()
-                    .map(|bytes| from_byte_vec::<i64>(bytes))
+                    .map(|bytes| from_value_data::<i64>(bytes))
                     .map_or(SELValue::new(), |val| {
                         SELValue::new_from_decimal(f64::sin(val as f64))
                     }),
                 DataType::Decimal => sel_value
                     .get_value()
-                    .map(|bytes| from_byte_vec::<f64>(bytes))
+                    .map(|bytes| from_value_data::<f64>(bytes))
                     .map_or(SELValue::new(), |val| {
                         SELValue::new_from_decimal(f64::sin(val))
                     }),
@@ -73,13 +73,13 @@ fn main() {
             match sel_value.get_type() {
                 DataType::Integer => sel_value
                     .get_value()
-                    .map(|bytes| from_byte_vec::<i64>(bytes))
+                    .map(|bytes| from_value_data::<i64>(bytes))
                     .map_or(SELValue::new(), |val| {
                         SELValue::new_from_decimal(f64::cos(val as f64))
                     }),
                 DataType::Decimal => sel_value
                     .get_value()
-                    .map(|bytes| from_byte_vec::<f64>(bytes))
+                    .map(|bytes| from_value_data::<f64>(bytes))
                     .map_or(SELValue::new(), |val| {
                         SELValue::new_from_decimal(f64::cos(val))
                     }),
@@ -91,13 +91,13 @@ fn main() {
             match sel_value.get_type() {
                 DataType::Integer => sel_value
                     .get_value()
-                    .map(|bytes| from_byte_vec::<i64>(bytes))
+                    .map(|bytes| from_value_data::<i64>(bytes))
                     .map_or(SELValue::new(), |val| {
                         SELValue::new_from_decimal(f64::tan(val as f64))
                     }),
                 DataType::Decimal => sel_value
                     .get_value()
-                    .map(|bytes| from_byte_vec::<f64>(bytes))
+                    .map(|bytes| from_value_data::<f64>(bytes))
                     .map_or(SELValue::new(), |val| {
                         SELValue::new_from_decimal(f64::tan(val))
                     }),